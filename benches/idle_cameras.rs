@@ -0,0 +1,90 @@
+//! Measures frame time with many `PanCam` cameras while only one is used,
+//! compared to all of them handling the same input
+//!
+//! Run with `cargo bench --bench idle_cameras`.
+
+use std::time::{Duration, Instant};
+
+use bevy::{
+    input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+    prelude::*,
+    render::camera::RenderTarget,
+    window::{PrimaryWindow, WindowRef, WindowResolution},
+};
+use bevy_pancam::{PanCam, PanCamPlugin};
+
+const CAMERAS: usize = 500;
+const FRAMES: u32 = 1000;
+
+/// Runs `FRAMES` frames of scrolling and holding a move key in the primary
+/// window, returning the average frame time
+///
+/// The idle cameras render to an unfocused window that doesn't receive any of
+/// the input, so they are skipped early, unless `idle_cameras_get_input` is
+/// set, in which case they render to the primary window and handle it too.
+fn run(idle_cameras_get_input: bool) -> Duration {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, PanCamPlugin))
+        .init_resource::<ButtonInput<MouseButton>>()
+        .init_resource::<ButtonInput<KeyCode>>()
+        .add_event::<MouseWheel>()
+        .add_event::<MouseMotion>()
+        .add_event::<bevy::window::WindowResized>();
+
+    let mut primary = Window {
+        resolution: WindowResolution::new(800., 600.),
+        ..default()
+    };
+    primary.set_cursor_position(Some(Vec2::new(400., 300.)));
+    let window = app.world_mut().spawn((primary, PrimaryWindow)).id();
+    let idle_window = app
+        .world_mut()
+        .spawn(Window {
+            focused: false,
+            ..default()
+        })
+        .id();
+    let idle_target = if idle_cameras_get_input {
+        WindowRef::Primary
+    } else {
+        WindowRef::Entity(idle_window)
+    };
+
+    app.world_mut().spawn((Camera2d, PanCam::default()));
+    for _ in 0..CAMERAS {
+        app.world_mut().spawn((
+            Camera2d,
+            Camera {
+                target: RenderTarget::Window(idle_target),
+                ..default()
+            },
+            PanCam::default(),
+        ));
+    }
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ArrowRight);
+    let mut total = Duration::ZERO;
+    for frame in 0..FRAMES {
+        app.world_mut().send_event(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.,
+            y: if frame % 2 == 0 { 1. } else { -1. },
+            window,
+        });
+        let start = Instant::now();
+        app.update();
+        total += start.elapsed();
+    }
+    total / FRAMES
+}
+
+fn main() {
+    println!("{CAMERAS} idle cameras, 1 used: {:?} per frame", run(false));
+    println!(
+        "{CAMERAS} cameras handling the same input: {:?} per frame",
+        run(true)
+    );
+}
@@ -8,7 +8,7 @@ use rand::random;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, PanCamPlugin::default(), EguiPlugin))
+        .add_plugins((DefaultPlugins, PanCamPlugin, EguiPlugin))
         .add_systems(Update, egui_ui)
         .add_systems(Startup, setup)
         .run();
@@ -33,7 +33,7 @@ fn egui_ui(mut contexts: EguiContexts) {
 }
 
 fn setup(mut commands: Commands) {
-    commands.spawn((Camera2dBundle::default(), PanCam::default()));
+    commands.spawn((Camera2d, PanCam::default()));
 
     let n = 20;
     let spacing = 50.;
@@ -44,15 +44,14 @@ fn setup(mut commands: Commands) {
             let x = x as f32 * spacing - offset;
             let y = y as f32 * spacing - offset;
             let color = Color::hsl(240., random::<f32>() * 0.3, random::<f32>() * 0.3);
-            commands.spawn(SpriteBundle {
-                sprite: Sprite {
+            commands.spawn((
+                Sprite {
                     color,
                     custom_size,
                     ..default()
                 },
-                transform: Transform::from_xyz(x, y, 0.),
-                ..default()
-            });
+                Transform::from_xyz(x, y, 0.),
+            ));
         }
     }
 }
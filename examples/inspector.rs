@@ -7,7 +7,7 @@ fn main() {
     App::new()
         .add_plugins((
             DefaultPlugins,
-            PanCamPlugin::default(),
+            PanCamPlugin,
             WorldInspectorPlugin::default(),
         ))
         .add_systems(Startup, setup)
@@ -15,26 +15,38 @@ fn main() {
 }
 
 fn setup(mut commands: Commands) {
-    commands.spawn((Camera2dBundle::default(), PanCam::default()));
-
     let n = 20;
     let spacing = 50.;
     let offset = spacing * n as f32 / 2.;
+
+    // Every `PanCam` field can be edited in the inspector, and its
+    // `PanCamState`, `PanCamInteraction` and `BoundaryContact` components show
+    // what the camera is doing
+    commands.spawn((
+        Camera2d,
+        PanCam {
+            min_x: -offset - spacing,
+            max_x: offset + spacing,
+            min_y: -offset - spacing,
+            max_y: offset + spacing,
+            ..default()
+        },
+    ));
+
     let custom_size = Some(Vec2::new(spacing, spacing));
     for x in 0..n {
         for y in 0..n {
             let x = x as f32 * spacing - offset;
             let y = y as f32 * spacing - offset;
             let color = Color::hsl(240., random::<f32>() * 0.3, random::<f32>() * 0.3);
-            commands.spawn(SpriteBundle {
-                sprite: Sprite {
+            commands.spawn((
+                Sprite {
                     color,
                     custom_size,
                     ..default()
                 },
-                transform: Transform::from_xyz(x, y, 0.),
-                ..default()
-            });
+                Transform::from_xyz(x, y, 0.),
+            ));
         }
     }
 }
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use bevy_pancam::{PanCam, PanCamPerspective, PanCamPlugin};
+use rand::prelude::random;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, PanCamPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        Camera3d::default(),
+        Transform::from_xyz(0., 0., 800.),
+        PanCam::default(),
+        PanCamPerspective::new(100., 2000.),
+    ));
+
+    // Tiles at different depths, which move at different speeds when panning
+    let n = 20;
+    let spacing = 50.;
+    let offset = spacing * n as f32 / 2.;
+    let mesh = meshes.add(Rectangle::new(spacing, spacing));
+    for x in 0..n {
+        for y in 0..n {
+            let x = x as f32 * spacing - offset;
+            let y = y as f32 * spacing - offset;
+            let z = -random::<f32>() * 200.;
+            let color = Color::hsl(240., random::<f32>() * 0.3, random::<f32>() * 0.3);
+            commands.spawn((
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: color,
+                    unlit: true,
+                    ..default()
+                })),
+                Transform::from_xyz(x, y, z),
+            ));
+        }
+    }
+}
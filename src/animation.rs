@@ -0,0 +1,398 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    base_world_size, camera_window, fit::fit_view, max_zoom_scale, Easing, PanCam, PanCamSettings,
+    PanCamSnapshot,
+};
+
+/// What a [`PanCamCommand`] does
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum PanCamAction {
+    /// Centers the view on a world position, keeping the zoom
+    MoveTo(Vec2),
+    /// Zooms to a projection scale, keeping the center of the view
+    ZoomTo(f32),
+    /// Zooms to a projection scale, keeping a world position in place on
+    /// screen, e.g. to zoom in on a selected object
+    ZoomAt {
+        /// The world position to keep in place
+        point: Vec2,
+        /// The projection scale
+        scale: f32,
+    },
+    /// Returns to the camera's `home` view, or else to the world origin at
+    /// scale 1
+    Reset,
+    /// Centers the view on a world position at a projection scale, e.g. to
+    /// restore a saved view
+    SetView {
+        /// The world position to center on
+        translation: Vec2,
+        /// The projection scale
+        scale: f32,
+    },
+    /// Frames a world rectangle, e.g. for "show selection" or "show whole map"
+    /// buttons
+    FitRect {
+        /// The rectangle to frame
+        rect: Rect,
+        /// Margin to keep around the rectangle, in world units
+        padding: f32,
+    },
+}
+
+/// Animates a `PanCam` camera to a new view over `duration` seconds
+///
+/// The new view is kept within the camera's bounds and scale limits. A new
+/// command replaces the camera's current animation. While animating, the
+/// animation drives the view, overriding user input. [`PanCamCommandDone`] is
+/// sent once it finishes.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct PanCamCommand {
+    /// The camera entity
+    pub entity: Entity,
+    /// The view to animate to
+    pub action: PanCamAction,
+    /// How long the animation takes, in seconds. `0.` changes the view
+    /// immediately.
+    pub duration: f32,
+    /// How the animation progresses over its duration
+    pub easing: Easing,
+}
+
+impl PanCamCommand {
+    /// Animates `entity` with `action` over `duration` seconds
+    pub fn new(entity: Entity, action: PanCamAction, duration: f32) -> Self {
+        Self {
+            entity,
+            action,
+            duration,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Animates with `easing` instead of the default `Easing::SmoothStep`
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+/// Sent when the animation started by a [`PanCamCommand`] finishes, unless it
+/// was replaced by another command first
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct PanCamCommandDone {
+    /// The camera entity
+    pub entity: Entity,
+    /// The action of the finished command
+    pub action: PanCamAction,
+}
+
+/// The animation a camera is playing, if any
+#[derive(Component, Default)]
+pub struct AnimationState {
+    current: Option<Animation>,
+}
+
+impl AnimationState {
+    /// Whether an animation is playing
+    pub(crate) fn is_animating(&self) -> bool {
+        self.current.is_some()
+    }
+
+    /// The scale the playing animation ends at
+    pub(crate) fn target_scale(&self) -> Option<f32> {
+        self.current.as_ref().map(|animation| animation.end_scale)
+    }
+}
+
+struct Animation {
+    action: PanCamAction,
+    duration: f32,
+    easing: Easing,
+    elapsed: f32,
+    start: PanCamSnapshot,
+    end_translation: Vec2,
+    end_scale: f32,
+    /// The world position kept in place while zooming, if any
+    anchor: Option<Vec2>,
+}
+
+/// The translation that keeps `anchor` in place on screen when a camera at
+/// `translation` zooms from `old_scale` to `new_scale`
+fn anchored_translation(anchor: Vec2, translation: Vec2, old_scale: f32, new_scale: f32) -> Vec2 {
+    anchor + (translation - anchor) * new_scale / old_scale
+}
+
+/// The view at the end of `action`, within the camera's limits
+fn end_view(
+    pan_cam: &PanCam,
+    proj: &OrthographicProjection,
+    transform: &Transform,
+    view_size: Vec2,
+    action: PanCamAction,
+) -> (Vec2, f32) {
+    let (translation, scale) = match action {
+        PanCamAction::MoveTo(translation) => (translation, proj.scale),
+        PanCamAction::ZoomTo(scale) | PanCamAction::ZoomAt { scale, .. } => {
+            (transform.translation.truncate(), scale)
+        }
+        PanCamAction::Reset => pan_cam.home.map_or((Vec2::ZERO, 1.), |home| {
+            (home.translation.truncate(), home.scale)
+        }),
+        PanCamAction::SetView { translation, scale } => (translation, scale),
+        PanCamAction::FitRect { rect, padding } => fit_view(rect, padding, proj, view_size),
+    };
+    let max_scale = max_zoom_scale(pan_cam, proj, view_size).max(pan_cam.min_scale);
+    let scale = scale.clamp(pan_cam.min_scale, max_scale);
+    let translation = match action {
+        PanCamAction::ZoomAt { point, .. } => {
+            anchored_translation(point, translation, proj.scale, scale)
+        }
+        _ => translation,
+    };
+    let area_size = base_world_size(proj, view_size) * scale;
+    (
+        pan_cam.clamp_position(translation, translation, area_size),
+        scale,
+    )
+}
+
+/// Starts animations for [`PanCamCommand`]s and plays them
+pub fn animate_cameras(
+    mut query: Query<(
+        Entity,
+        &PanCam,
+        &Camera,
+        &mut AnimationState,
+        &mut OrthographicProjection,
+        &mut Transform,
+    )>,
+    mut commands: EventReader<PanCamCommand>,
+    mut done: EventWriter<PanCamCommandDone>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    time: Res<Time>,
+    settings: Res<PanCamSettings>,
+) {
+    let primary_window = primary_window.get_single().ok();
+
+    for command in commands.read() {
+        let Ok((_, pan_cam, camera, mut state, proj, transform)) = query.get_mut(command.entity)
+        else {
+            continue;
+        };
+        let Some(window) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+        let view_size = pan_cam.view_rect(camera, window).size();
+        let (end_translation, end_scale) =
+            end_view(pan_cam, &proj, &transform, view_size, command.action);
+        state.current = Some(Animation {
+            action: command.action,
+            duration: command.duration,
+            easing: command.easing,
+            elapsed: 0.,
+            start: PanCamSnapshot::capture(&transform, &proj),
+            end_translation,
+            end_scale,
+            anchor: match command.action {
+                PanCamAction::ZoomAt { point, .. } => Some(point),
+                _ => None,
+            },
+        });
+    }
+
+    for (entity, _, _, mut state, mut proj, mut transform) in &mut query {
+        let Some(animation) = &mut state.current else {
+            continue;
+        };
+        animation.elapsed += time.delta_secs();
+        let t = if animation.duration > 0. && !settings.reduced_motion {
+            (animation.elapsed / animation.duration).min(1.)
+        } else {
+            1.
+        };
+
+        let start = animation.start;
+        if t < 1. {
+            let eased = animation.easing.ease(t);
+            proj.scale = start.scale * (animation.end_scale / start.scale).powf(eased);
+            let start_translation = start.translation.truncate();
+            let translation = match animation.anchor {
+                // Keeps the anchor in place throughout, blending in any
+                // correction from the bounds
+                Some(anchor) => {
+                    let anchored =
+                        |scale| anchored_translation(anchor, start_translation, start.scale, scale);
+                    anchored(proj.scale)
+                        + (animation.end_translation - anchored(animation.end_scale)) * eased
+                }
+                None => start_translation.lerp(animation.end_translation, eased),
+            };
+            transform.translation = translation.extend(start.translation.z);
+            continue;
+        }
+
+        proj.scale = animation.end_scale;
+        transform.translation = animation.end_translation.extend(start.translation.z);
+        done.send(PanCamCommandDone {
+            entity,
+            action: animation.action,
+        });
+        state.current = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn move_to_command_animates_and_reports_completion() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(&mut app, PanCam::default());
+        update(&mut app);
+        let action = PanCamAction::MoveTo(vec2(100., 0.));
+        app.world_mut()
+            .send_event(PanCamCommand::new(cam, action, 1.));
+
+        for _ in 0..5 {
+            update(&mut app);
+        }
+        // halfway through the eased animation
+        assert!((translation(&app, cam) - vec2(50., 0.)).length() < 1e-3);
+        assert!(command_done_events(&app).is_empty());
+
+        // one more frame than needed, in case of rounding
+        for _ in 0..6 {
+            update(&mut app);
+        }
+        assert_eq!(translation(&app, cam), vec2(100., 0.));
+        assert_eq!(
+            command_done_events(&app),
+            vec![PanCamCommandDone {
+                entity: cam,
+                action
+            }]
+        );
+    }
+
+    #[test]
+    fn commands_animate_with_their_easing() {
+        let quarter_way = |easing: Easing| {
+            let mut app = test_app();
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                250,
+            )));
+            let cam = spawn_cam(&mut app, PanCam::default());
+            update(&mut app);
+            let action = PanCamAction::MoveTo(vec2(100., 0.));
+            app.world_mut()
+                .send_event(PanCamCommand::new(cam, action, 1.).with_easing(easing));
+            update(&mut app);
+            translation(&app, cam).x
+        };
+        assert!((quarter_way(Easing::Linear) - 25.).abs() < 1e-3);
+        assert!((quarter_way(Easing::QuadOut) - 43.75).abs() < 1e-3);
+        assert!((quarter_way(Easing::Custom(|t| t.sqrt())) - 50.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn fit_rect_command_frames_rect_with_padding() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        update(&mut app);
+        let rect = Rect::new(100., 0., 300., 80.);
+        app.world_mut().send_event(PanCamCommand::new(
+            cam,
+            PanCamAction::FitRect { rect, padding: 10. },
+            0.,
+        ));
+        update(&mut app);
+        // the padded rect is 220 wide, and the view 100 pixels
+        assert!((scale(&app, cam) - 2.2).abs() < 1e-5);
+        assert_eq!(translation(&app, cam), vec2(200., 40.));
+    }
+
+    #[test]
+    fn zoom_at_command_keeps_point_in_place() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let cam = spawn_cam(&mut app, PanCam::default());
+        update(&mut app);
+
+        let point = vec2(40., 0.);
+        app.world_mut().send_event(PanCamCommand::new(
+            cam,
+            PanCamAction::ZoomAt { point, scale: 0.5 },
+            0.5,
+        ));
+        // Half way, the point is still 40 pixels right of the center
+        update(&mut app);
+        assert!(scale(&app, cam) < 1.);
+        let screen_offset = (point - translation(&app, cam)) / scale(&app, cam);
+        assert!((screen_offset - vec2(40., 0.)).length() < 1e-3);
+
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(20., 0.));
+        assert_eq!(scale(&app, cam), 0.5);
+    }
+
+    #[test]
+    fn zoom_to_command_respects_scale_limits() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                max_scale: 3.,
+                ..default()
+            },
+        );
+        update(&mut app);
+        app.world_mut()
+            .send_event(PanCamCommand::new(cam, PanCamAction::ZoomTo(10.), 0.));
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 3.);
+        assert_eq!(command_done_events(&app).len(), 1);
+
+        app.world_mut()
+            .send_event(PanCamCommand::new(cam, PanCamAction::Reset, 0.));
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+    }
+
+    #[test]
+    fn commands_keep_content_rect_partly_visible() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                content_rect: Some(Rect::new(0., 0., 100., 100.)),
+                ..default()
+            },
+        );
+        update(&mut app);
+        app.world_mut().send_event(PanCamCommand::new(
+            cam,
+            PanCamAction::MoveTo(vec2(-200., 50.)),
+            0.,
+        ));
+        update(&mut app);
+        // the right quarter of the view still shows the content
+        assert_eq!(translation(&app, cam), vec2(-25., 50.));
+    }
+}
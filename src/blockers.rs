@@ -0,0 +1,412 @@
+use std::borrow::Cow;
+
+use bevy::{prelude::*, utils::HashMap};
+
+/// Suppresses all pan and zoom input while any source blocks it
+///
+/// UI crates and game systems block input under their widgets by setting their
+/// own source, e.g. every frame from whether the cursor is over a panel:
+///
+/// ```rust ignore
+/// fn block_under_panel(panel: Res<MyPanel>, mut blockers: ResMut<PanCamInputBlockers>) {
+///     blockers.set("my_panel", panel.hovered);
+/// }
+/// ```
+///
+/// Sources stay set until cleared, and independent sources don't interfere
+/// with each other. Sources can also block only some kinds of input with
+/// [`set_input`](Self::set_input), or only one camera's input with
+/// [`set_for_camera`](Self::set_for_camera). The `bevy_egui` feature uses the
+/// `"bevy_egui"` source, the `picking` feature the `"picking"` source, and the
+/// `minimap` feature the `"minimap"` source for each minimap's target.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PanCamInputBlockers {
+    sources: HashMap<Cow<'static, str>, BlockedInput>,
+    camera_sources: HashMap<Entity, HashMap<Cow<'static, str>, BlockedInput>>,
+}
+
+impl PanCamInputBlockers {
+    /// Sets whether `source` blocks all input
+    pub fn set(&mut self, source: impl Into<Cow<'static, str>>, blocked: bool) {
+        let input = if blocked {
+            BlockedInput::ALL
+        } else {
+            BlockedInput::NONE
+        };
+        self.set_input(source, input);
+    }
+
+    /// Sets which kinds of input `source` blocks
+    pub fn set_input(&mut self, source: impl Into<Cow<'static, str>>, input: BlockedInput) {
+        let source = source.into();
+        if input == BlockedInput::NONE {
+            self.sources.remove(&source);
+        } else {
+            self.sources.insert(source, input);
+        }
+    }
+
+    /// Whether `source` currently blocks any input
+    pub fn is_blocked_by(&self, source: &str) -> bool {
+        self.sources.contains_key(source)
+    }
+
+    /// The kinds of input `source` currently blocks
+    pub fn blocked_input(&self, source: &str) -> BlockedInput {
+        self.sources.get(source).copied().unwrap_or_default()
+    }
+
+    /// Whether any source currently blocks all input
+    pub fn is_blocked(&self) -> bool {
+        self.sources
+            .values()
+            .any(|input| *input == BlockedInput::ALL)
+    }
+
+    /// Whether any source currently blocks panning with the pointer
+    pub fn blocks_pan(&self) -> bool {
+        self.sources.values().any(|input| input.pan)
+    }
+
+    /// Whether any source currently blocks scrolling and pinching
+    pub fn blocks_zoom(&self) -> bool {
+        self.sources.values().any(|input| input.zoom)
+    }
+
+    /// Whether any source currently blocks keyboard controls
+    pub fn blocks_keys(&self) -> bool {
+        self.sources.values().any(|input| input.keys)
+    }
+
+    /// Sets whether `source` blocks all of `camera`'s input
+    pub fn set_for_camera(
+        &mut self,
+        camera: Entity,
+        source: impl Into<Cow<'static, str>>,
+        blocked: bool,
+    ) {
+        let input = if blocked {
+            BlockedInput::ALL
+        } else {
+            BlockedInput::NONE
+        };
+        self.set_input_for_camera(camera, source, input);
+    }
+
+    /// Sets which kinds of `camera`'s input `source` blocks
+    pub fn set_input_for_camera(
+        &mut self,
+        camera: Entity,
+        source: impl Into<Cow<'static, str>>,
+        input: BlockedInput,
+    ) {
+        let source = source.into();
+        if input == BlockedInput::NONE {
+            if let Some(sources) = self.camera_sources.get_mut(&camera) {
+                sources.remove(&source);
+                if sources.is_empty() {
+                    self.camera_sources.remove(&camera);
+                }
+            }
+        } else {
+            self.camera_sources
+                .entry(camera)
+                .or_default()
+                .insert(source, input);
+        }
+    }
+
+    /// Whether `source` currently blocks any of `camera`'s input, not counting
+    /// blocking all cameras with [`set`](Self::set)
+    pub fn is_camera_blocked_by(&self, camera: Entity, source: &str) -> bool {
+        self.camera_sources
+            .get(&camera)
+            .is_some_and(|sources| sources.contains_key(source))
+    }
+
+    /// The cameras whose input `source` currently blocks
+    pub fn cameras_blocked_by<'a>(&'a self, source: &'a str) -> impl Iterator<Item = Entity> + 'a {
+        self.camera_sources
+            .iter()
+            .filter(move |(_, sources)| sources.contains_key(source))
+            .map(|(camera, _)| *camera)
+    }
+
+    /// The kinds of `camera`'s input currently blocked, by sources blocking
+    /// all cameras or only it
+    pub fn camera_blocked_input(&self, camera: Entity) -> BlockedInput {
+        self.sources
+            .values()
+            .chain(
+                self.camera_sources
+                    .get(&camera)
+                    .into_iter()
+                    .flat_map(HashMap::values),
+            )
+            .fold(BlockedInput::NONE, |blocked, input| blocked.union(*input))
+    }
+
+    /// Whether all of `camera`'s input is currently blocked
+    pub fn is_camera_blocked(&self, camera: Entity) -> bool {
+        self.is_blocked()
+            || self
+                .camera_sources
+                .get(&camera)
+                .is_some_and(|sources| sources.values().any(|input| *input == BlockedInput::ALL))
+    }
+}
+
+/// The kinds of input a source of [`PanCamInputBlockers`] blocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BlockedInput {
+    /// Dragging, clicking and edge scrolling with the pointer
+    ///
+    /// Drags that already started continue.
+    pub pan: bool,
+    /// Scrolling with the mouse wheel or trackpad, and pinching, whether they
+    /// zoom or pan
+    pub zoom: bool,
+    /// Keyboard controls
+    pub keys: bool,
+}
+
+impl BlockedInput {
+    /// Blocks nothing
+    pub const NONE: Self = Self {
+        pan: false,
+        zoom: false,
+        keys: false,
+    };
+
+    /// Blocks all input, including input not covered by the other kinds, like
+    /// gamepads
+    pub const ALL: Self = Self {
+        pan: true,
+        zoom: true,
+        keys: true,
+    };
+
+    /// The input blocked by either `self` or `other`
+    fn union(self, other: Self) -> Self {
+        Self {
+            pan: self.pan || other.pan,
+            zoom: self.zoom || other.zoom,
+            keys: self.keys || other.keys,
+        }
+    }
+}
+
+/// Run condition for input systems, which don't run while input is blocked
+pub(crate) fn input_not_blocked(blockers: Res<PanCamInputBlockers>) -> bool {
+    !blockers.is_blocked()
+}
+
+/// Run condition for systems controlled by clicking, which don't run while
+/// pointer panning is blocked
+pub(crate) fn pan_not_blocked(blockers: Res<PanCamInputBlockers>) -> bool {
+    !blockers.blocks_pan()
+}
+
+/// Run condition for systems controlled by pinching, which don't run while
+/// scrolling is blocked
+#[cfg(feature = "gestures")]
+pub(crate) fn zoom_not_blocked(blockers: Res<PanCamInputBlockers>) -> bool {
+    !blockers.blocks_zoom()
+}
+
+/// Run condition for systems controlled by the keyboard, which don't run while
+/// keyboard controls are blocked
+pub(crate) fn keys_not_blocked(blockers: Res<PanCamInputBlockers>) -> bool {
+    !blockers.blocks_keys()
+}
+
+/// Turns camera control on or off for all cameras at once, e.g. during
+/// cutscenes or modal dialogs
+///
+/// While `false`, input is ignored and inertia and zoom smoothing stop, like
+/// for cameras with `enabled` unset. `PanCamCommand`s keep running, so cutscenes
+/// can still move the camera.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Deref, DerefMut, Reflect)]
+#[reflect(Resource)]
+pub struct PanCamEnabled(pub bool);
+
+impl Default for PanCamEnabled {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// Run condition for input systems, which don't run while camera control is
+/// turned off
+pub(crate) fn controls_enabled(enabled: Res<PanCamEnabled>) -> bool {
+    **enabled
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, PanCam, PanCamAction, PanCamCommand};
+
+    #[test]
+    fn blocked_until_all_sources_are_cleared() {
+        let mut blockers = PanCamInputBlockers::default();
+        blockers.set("panel", true);
+        blockers.set(String::from("menu"), true);
+        assert!(blockers.is_blocked_by("menu"));
+
+        blockers.set("panel", false);
+        assert!(blockers.is_blocked());
+        blockers.set("menu", false);
+        assert!(!blockers.is_blocked());
+    }
+
+    #[test]
+    fn sources_can_block_only_some_input() {
+        let mut blockers = PanCamInputBlockers::default();
+        blockers.set_input(
+            "text_field",
+            BlockedInput {
+                keys: true,
+                ..default()
+            },
+        );
+        assert!(blockers.blocks_keys());
+        assert!(!blockers.blocks_pan());
+        assert!(!blockers.is_blocked());
+
+        blockers.set("panel", true);
+        assert!(blockers.blocks_pan() && blockers.blocks_zoom() && blockers.is_blocked());
+
+        blockers.set_input("text_field", BlockedInput::NONE);
+        assert!(!blockers.is_blocked_by("text_field"));
+    }
+
+    #[test]
+    fn camera_sources_only_block_their_camera() {
+        let mut blockers = PanCamInputBlockers::default();
+        let cam = Entity::from_raw(1);
+        let other = Entity::from_raw(2);
+        blockers.set_for_camera(cam, "minimap", true);
+        assert!(blockers.is_camera_blocked(cam));
+        assert!(!blockers.is_camera_blocked(other));
+        assert!(!blockers.is_blocked());
+        assert_eq!(
+            blockers.cameras_blocked_by("minimap").collect::<Vec<_>>(),
+            [cam]
+        );
+
+        blockers.set_input(
+            "text_field",
+            BlockedInput {
+                keys: true,
+                ..default()
+            },
+        );
+        assert!(blockers.camera_blocked_input(other).keys);
+
+        blockers.set_for_camera(cam, "minimap", false);
+        assert!(!blockers.camera_blocked_input(cam).pan);
+        assert_eq!(blockers.cameras_blocked_by("minimap").count(), 0);
+    }
+
+    #[test]
+    fn input_blockers_suppress_pan_and_zoom() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+
+        app.world_mut()
+            .resource_mut::<PanCamInputBlockers>()
+            .set("ui", true);
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.);
+
+        app.world_mut()
+            .resource_mut::<PanCamInputBlockers>()
+            .set("ui", false);
+        update(&mut app);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+    }
+
+    #[test]
+    fn partial_input_blockers_only_block_their_input() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(&mut app, PanCam::default());
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+        let block = |app: &mut App, input: BlockedInput| {
+            app.world_mut()
+                .resource_mut::<PanCamInputBlockers>()
+                .set_input("ui", input);
+        };
+
+        // e.g. while typing in a text field
+        block(
+            &mut app,
+            BlockedInput {
+                keys: true,
+                ..default()
+            },
+        );
+        keys(&mut app).press(KeyCode::ArrowLeft);
+        update(&mut app);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+        keys(&mut app).release(KeyCode::ArrowLeft);
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+
+        // e.g. while the pointer is over a panel that doesn't scroll
+        block(
+            &mut app,
+            BlockedInput {
+                pan: true,
+                ..default()
+            },
+        );
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!(scale(&app, cam) < 1.);
+    }
+
+    #[test]
+    fn global_toggle_pauses_all_cameras() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        let other = spawn_cam(&mut app, PanCam::default());
+
+        **app.world_mut().resource_mut::<PanCamEnabled>() = false;
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.);
+        assert_eq!(scale(&app, other), 1.);
+
+        // Commands still move the cameras
+        app.world_mut().send_event(PanCamCommand::new(
+            other,
+            PanCamAction::MoveTo(vec2(5., 0.)),
+            0.,
+        ));
+        update(&mut app);
+        assert_eq!(translation(&app, other), vec2(5., 0.));
+
+        **app.world_mut().resource_mut::<PanCamEnabled>() = true;
+        update(&mut app);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+    }
+}
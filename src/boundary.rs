@@ -0,0 +1,129 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{camera_window, safe_zone, PanCam};
+
+/// Which of a `PanCam` camera's bounds its view is currently pressed against
+///
+/// Updated every frame, e.g. to show an indicator at the edge the user can't
+/// pan past. All edges are `false` on axes without bounds.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct BoundaryContact {
+    /// The view touches `min_x`
+    pub left: bool,
+    /// The view touches `max_x`
+    pub right: bool,
+    /// The view touches `min_y`
+    pub bottom: bool,
+    /// The view touches `max_y`
+    pub top: bool,
+}
+
+impl BoundaryContact {
+    /// Whether the view touches any of the bounds
+    pub fn any(&self) -> bool {
+        self.left || self.right || self.bottom || self.top
+    }
+}
+
+/// Updates `BoundaryContact` for all `PanCam` cameras
+pub fn update_boundary_contact(
+    mut query: Query<(
+        &PanCam,
+        &Camera,
+        &OrthographicProjection,
+        &Transform,
+        &mut BoundaryContact,
+    )>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    let primary_window = primary_window.get_single().ok();
+    for (pan_cam, camera, proj, transform, mut contact) in &mut query {
+        let area = match camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        {
+            Some(window) => pan_cam.view_area(camera, proj, window),
+            None => proj.area,
+        };
+        // Zooming along a single axis stretches the transform instead
+        let area_size = area.size() * transform.scale.truncate();
+        let pos = transform.translation.truncate();
+        let (min, max) = safe_zone(pan_cam.aabb(area_size), area_size);
+        let tolerance = pan_cam.clamp_tolerance;
+        contact.set_if_neq(BoundaryContact {
+            left: pan_cam.min_x.is_finite() && pos.x - min.x <= tolerance,
+            right: pan_cam.max_x.is_finite() && max.x - pos.x <= tolerance,
+            bottom: pan_cam.min_y.is_finite() && pos.y - min.y <= tolerance,
+            top: pan_cam.max_y.is_finite() && max.y - pos.y <= tolerance,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn boundary_contact_follows_panning_into_and_away_from_edges() {
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                min_x: -70.,
+                max_y: 50.,
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        let contact = |app: &App| *app.world().get::<BoundaryContact>(cam).unwrap();
+        assert_eq!(
+            contact(&app),
+            BoundaryContact {
+                top: true,
+                ..default()
+            }
+        );
+
+        drag_right(&mut app, cam);
+        drag_right(&mut app, cam);
+        assert_eq!(
+            contact(&app),
+            BoundaryContact {
+                left: true,
+                top: true,
+                ..default()
+            }
+        );
+
+        set_view(&mut app, cam, vec2(0., -20.), 1.);
+        update(&mut app);
+        assert!(!contact(&app).any());
+    }
+
+    #[test]
+    fn boundary_contact_reflects_zoom_the_same_frame() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                min_x: -50.,
+                max_x: 50.,
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+        let contact = |app: &App| *app.world().get::<BoundaryContact>(cam).unwrap();
+        assert!(contact(&app).left && contact(&app).right);
+
+        // The projection's area isn't updated without a renderer
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!(scale(&app, cam) < 1.);
+        assert!(!contact(&app).any());
+    }
+}
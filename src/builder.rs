@@ -0,0 +1,207 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    BoundsMargin, ClickToTravel, DirectionKeys, DoubleClickCenter, DoubleTapZoom, DragPixelSpace,
+    EdgeScroll, EdgeSoftness, FitConfig, FollowZoomMode, GrabButtons, GridSnap, IntegerZoomMagnet,
+    KeyFocus, MaxPanSpeed, Overscroll, PanCam, PanCamGamepadConfig, PanCamSnapshot, PanInertia,
+    PanInputSource, ResetView, ResizeAnchor, ResizePolicy, RotateKeys, ScrollBehavior,
+    ScrollMapping, ZoomFocus, ZoomKeys, ZoomMode, ZoomSensitivityCurve, ZoomSteps,
+};
+
+/// A 2D camera with `PanCam` controls
+///
+/// Spawning `(Camera2d, PanCam)` does the same. The plugin's per-camera state
+/// is added through `PanCam`'s required components either way.
+#[derive(Bundle, Clone, Default)]
+pub struct PanCamBundle {
+    /// The 2D camera
+    pub camera: Camera2d,
+    /// The camera controls
+    pub pan_cam: PanCam,
+}
+
+/// Builds a [`PanCam`] one setting at a time, starting from the defaults
+///
+/// Unlike a struct literal, code using the builder keeps compiling when new
+/// settings are added.
+#[derive(Clone, Default)]
+pub struct PanCamBuilder {
+    pan_cam: PanCam,
+}
+
+impl PanCam {
+    /// Starts building a `PanCam` from the default settings
+    pub fn builder() -> PanCamBuilder {
+        PanCamBuilder::default()
+    }
+}
+
+impl PanCamBuilder {
+    /// Finishes building the `PanCam`
+    pub fn build(self) -> PanCam {
+        self.pan_cam
+    }
+}
+
+impl From<PanCamBuilder> for PanCam {
+    fn from(builder: PanCamBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// A builder method setting a `PanCam` field, taking anything that converts
+/// into the field's type when marked with `into`
+macro_rules! setter {
+    ($field:ident: into $ty:ty) => {
+        #[doc = concat!("Sets [`PanCam::", stringify!($field), "`]")]
+        pub fn $field(mut self, $field: impl Into<$ty>) -> Self {
+            self.pan_cam.$field = $field.into();
+            self
+        }
+    };
+    ($field:ident: $ty:ty) => {
+        #[doc = concat!("Sets [`PanCam::", stringify!($field), "`]")]
+        pub fn $field(mut self, $field: $ty) -> Self {
+            self.pan_cam.$field = $field;
+            self
+        }
+    };
+}
+
+impl PanCamBuilder {
+    setter!(grab_buttons: into GrabButtons);
+    setter!(release_ends_grab: bool);
+    setter!(invert_drag: bool);
+    setter!(pan_input: PanInputSource);
+    setter!(drag_pixel_space: DragPixelSpace);
+    setter!(pan_hold_delay: Duration);
+    setter!(pan_deadzone: f32);
+    setter!(pan_deadzone_world: f32);
+    setter!(move_keys: DirectionKeys);
+    setter!(page_keys: DirectionKeys);
+    setter!(page_overlap: f32);
+    setter!(page_duration: f32);
+    setter!(zoom_keys: ZoomKeys);
+    setter!(key_zoom_speed: f32);
+    setter!(key_zoom_to_cursor: bool);
+    setter!(key_focus: KeyFocus);
+    setter!(rotate_keys: RotateKeys);
+    setter!(rotate_speed: f32);
+    setter!(rotate_to_cursor: bool);
+    setter!(speed: f32);
+    setter!(pan_speed: f32);
+    setter!(key_pan_speed: f32);
+    setter!(max_pan_speed: into Option<MaxPanSpeed>);
+    setter!(enabled: bool);
+    setter!(passive: bool);
+    setter!(pan_enabled: bool);
+    setter!(pan_axes: BVec2);
+    setter!(zoom_enabled: bool);
+    setter!(zoom_focus: ZoomFocus);
+    setter!(zoom_to_cursor_wheel_only: bool);
+    setter!(zoom_to_cursor_strength: f32);
+    setter!(invert_zoom: bool);
+    setter!(zoom_smoothing: into Option<f32>);
+    setter!(zoom_while_following: FollowZoomMode);
+    setter!(zoom_axes: BVec2);
+    setter!(scroll_mapping: ScrollMapping);
+    setter!(scroll_behavior: ScrollBehavior);
+    setter!(zoom_modifier: into Option<KeyCode>);
+    setter!(pinch_zoom_sensitivity: f32);
+    setter!(zoom_sensitivity_curve: into Option<ZoomSensitivityCurve>);
+    setter!(zoom_sensitivity: into Option<f32>);
+    setter!(zoom_mode: ZoomMode);
+    setter!(pixels_per_line: into Option<f32>);
+    setter!(pixel_scroll_multiplier: f32);
+    setter!(scroll_deadzone: f32);
+    setter!(integer_zoom_magnet: into Option<IntegerZoomMagnet>);
+    setter!(zoom_steps: into Option<ZoomSteps>);
+    setter!(zoom_percent_scale: f32);
+    setter!(render_rect: into Option<Rect>);
+    setter!(double_click_center: into Option<DoubleClickCenter>);
+    setter!(click_to_travel: into Option<ClickToTravel>);
+    setter!(double_tap_zoom: into Option<DoubleTapZoom>);
+    setter!(home: into Option<PanCamSnapshot>);
+    setter!(reset_view: into Option<ResetView>);
+    setter!(wrap_cursor: bool);
+    setter!(change_cursor_icon: bool);
+    setter!(snap_to_pixels: bool);
+    setter!(grid_snap: into Option<GridSnap>);
+    setter!(edge_scroll: into Option<EdgeScroll>);
+    setter!(inertia: into Option<PanInertia>);
+    setter!(touch_inertia: into Option<PanInertia>);
+    setter!(gamepad: into Option<PanCamGamepadConfig>);
+    setter!(resize_anchor: into Option<ResizeAnchor>);
+    setter!(resize_policy: ResizePolicy);
+    setter!(scale_factor_anchor: into Option<ResizeAnchor>);
+    setter!(fit_on_startup: into Option<FitConfig>);
+    setter!(rail: into Option<Vec<Vec2>>);
+    setter!(min_scale: f32);
+    setter!(max_scale: f32);
+    setter!(max_empty_fraction: f32);
+    setter!(min_content_fraction: f32);
+    setter!(zoom_out_to_fit_bounds: bool);
+    setter!(clamp_tolerance: f32);
+    setter!(edge_softness: EdgeSoftness);
+    setter!(overscroll: into Option<Overscroll>);
+    setter!(bounds_margin: into Option<BoundsMargin>);
+    setter!(content_rect: into Option<Rect>);
+    setter!(min_content_visible: f32);
+    setter!(min_x: f32);
+    setter!(max_x: f32);
+    setter!(min_y: f32);
+    setter!(max_y: f32);
+
+    /// Sets [`PanCam::zoom_focus`] to `ZoomFocus::Cursor` if true, or else
+    /// `ZoomFocus::ScreenCenter`
+    #[deprecated(since = "0.16.0", note = "use `zoom_focus` instead")]
+    pub fn zoom_to_cursor(mut self, zoom_to_cursor: bool) -> Self {
+        self.pan_cam.zoom_focus = if zoom_to_cursor {
+            ZoomFocus::Cursor
+        } else {
+            ZoomFocus::ScreenCenter
+        };
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn builder_and_bundle_spawn_working_cameras() {
+        let pan_cam = PanCam::builder()
+            .grab_buttons(vec![MouseButton::Middle])
+            .min_scale(0.5)
+            .inertia(PanInertia::default())
+            .build();
+        assert_eq!(pan_cam.grab_buttons, vec![MouseButton::Middle].into());
+        assert_eq!(pan_cam.min_scale, 0.5);
+        assert!(pan_cam.inertia.is_some());
+        assert_eq!(pan_cam.speed, PanCam::default().speed);
+
+        let mut app = test_app();
+        let cam = app
+            .world_mut()
+            .spawn(PanCamBundle {
+                pan_cam,
+                ..default()
+            })
+            .id();
+        // the area of the camera's projection is only updated when rendering
+        app.world_mut()
+            .entity_mut(cam)
+            .insert(mock_proj(vec2(100., 100.)));
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+        mouse(&mut app).press(MouseButton::Middle);
+        update(&mut app);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+    }
+}
@@ -0,0 +1,132 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    camera_window, cursor_in_viewport, view_pos_to_world, GrabChord, PanCam, PanCamAction,
+    PanCamCommand, PanCamInputBlockers, RawInputFilter,
+};
+
+/// Centers the view on the clicked world position when clicking with a chord,
+/// like in strategy games and map viewers
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClickToTravel {
+    /// The mouse button to click, optionally with a key held
+    pub chord: GrabChord,
+    /// How long the camera takes to center on the clicked position, in seconds
+    ///
+    /// This is the same for any distance, so the camera moves faster the
+    /// further away the clicked position is.
+    pub duration: f32,
+}
+
+impl Default for ClickToTravel {
+    fn default() -> Self {
+        Self {
+            chord: GrabChord::new(KeyCode::AltLeft, MouseButton::Left),
+            duration: 0.4,
+        }
+    }
+}
+
+/// Animates cameras with `click_to_travel` to center on the clicked position
+pub fn do_camera_click_travel(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    keyboard_buttons: Res<ButtonInput<KeyCode>>,
+    query: Query<
+        (
+            Entity,
+            &PanCam,
+            &Camera,
+            &OrthographicProjection,
+            &Transform,
+        ),
+        RawInputFilter,
+    >,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    blockers: Res<PanCamInputBlockers>,
+    mut pan_cam_commands: EventWriter<PanCamCommand>,
+) {
+    if mouse_buttons.get_just_pressed().next().is_none() {
+        return;
+    }
+
+    let primary_window = primary_window.get_single().ok();
+
+    for (entity, pan_cam, camera, proj, transform) in &query {
+        let Some(config) = pan_cam.click_to_travel else {
+            continue;
+        };
+        if !pan_cam.enabled
+            || !camera.is_active
+            || !pan_cam.pan_enabled
+            || blockers.camera_blocked_input(entity).pan
+            || !config.chord.just_pressed(&mouse_buttons, &keyboard_buttons)
+        {
+            continue;
+        }
+
+        let Some(window) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+        let Some(cursor_pos) = window
+            .cursor_position()
+            .filter(|cursor_pos| cursor_in_viewport(camera, window, *cursor_pos))
+        else {
+            continue;
+        };
+
+        let view_rect = pan_cam.view_rect(camera, window);
+        let world_pos = view_pos_to_world(cursor_pos, view_rect, proj, transform);
+        pan_cam_commands.send(PanCamCommand::new(
+            entity,
+            PanCamAction::MoveTo(world_pos),
+            config.duration,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn click_to_travel_centers_on_position_clicked_with_chord() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                click_to_travel: Some(ClickToTravel::default()),
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(75., 75.));
+        update(&mut app);
+
+        let click = |app: &mut App| {
+            mouse(app).press(MouseButton::Left);
+            update(app);
+            mouse(app).release(MouseButton::Left);
+            for _ in 0..5 {
+                update(app);
+            }
+        };
+        // without the modifier
+        click(&mut app);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+
+        keys(&mut app).press(KeyCode::AltLeft);
+        click(&mut app);
+        assert_eq!(translation(&app, cam), vec2(25., -25.));
+    }
+}
@@ -0,0 +1,333 @@
+use bevy::{math::vec2, prelude::*};
+use bevy_console::{AddConsoleCommand, ConsoleCommand, ConsolePlugin};
+use clap::{Parser, Subcommand};
+
+use crate::{
+    PanCam, PanCamAction, PanCamBookmarks, PanCamCommand, PanCamCommandsExt, PanCamContent,
+};
+
+/// How long console commands animate cameras for by default, in seconds
+const DEFAULT_DURATION: f32 = 0.5;
+
+/// Centers the view on a world position
+#[derive(Parser, ConsoleCommand)]
+#[command(name = "cam.goto", allow_negative_numbers = true)]
+pub struct CamGotoCommand {
+    /// The world x position
+    x: f32,
+    /// The world y position
+    y: f32,
+    /// How long the camera takes to get there, in seconds
+    #[arg(long, default_value_t = DEFAULT_DURATION)]
+    duration: f32,
+}
+
+/// Zooms to a projection scale, keeping the center of the view
+#[derive(Parser, ConsoleCommand)]
+#[command(name = "cam.zoom")]
+pub struct CamZoomCommand {
+    /// The projection scale, smaller values zoom in
+    scale: f32,
+    /// How long the zoom takes, in seconds
+    #[arg(long, default_value_t = DEFAULT_DURATION)]
+    duration: f32,
+}
+
+/// Frames all entities with a `PanCamContent` component
+#[derive(Parser, ConsoleCommand)]
+#[command(name = "cam.fit")]
+pub struct CamFitCommand {
+    /// Margin to keep around the content, in world units
+    #[arg(long, default_value_t = 0.)]
+    padding: f32,
+    /// How long the camera takes to frame the content, in seconds
+    #[arg(long, default_value_t = DEFAULT_DURATION)]
+    duration: f32,
+}
+
+/// Saves or loads a bookmarked view
+#[derive(Parser, ConsoleCommand)]
+#[command(name = "cam.bookmark")]
+pub struct CamBookmarkCommand {
+    #[command(subcommand)]
+    action: BookmarkAction,
+}
+
+#[derive(Subcommand)]
+enum BookmarkAction {
+    /// Saves the current view as a bookmark
+    Save {
+        /// The name of the bookmark, replacing any bookmark with that name
+        name: String,
+    },
+    /// Animates to a bookmarked view
+    Load {
+        /// The name of the bookmark
+        name: String,
+        /// How long the camera takes to get there, in seconds
+        #[arg(long, default_value_t = DEFAULT_DURATION)]
+        duration: f32,
+    },
+}
+
+/// Registers the `cam.*` commands with `bevy_console`, once all plugins are
+/// added
+///
+/// The commands control every active `PanCam` camera. Without the console,
+/// nothing is registered.
+pub(crate) fn add_console_commands(app: &mut App) {
+    if !app.is_plugin_added::<ConsolePlugin>() {
+        warn!("ConsolePlugin wasn't added, so no cam.* commands are available");
+        return;
+    }
+    app.add_console_command::<CamGotoCommand, _>(goto_command)
+        .add_console_command::<CamZoomCommand, _>(zoom_command)
+        .add_console_command::<CamFitCommand, _>(fit_command)
+        .add_console_command::<CamBookmarkCommand, _>(bookmark_command);
+}
+
+/// The cameras console commands control
+fn active_cameras(cameras: &Query<(Entity, &Camera), With<PanCam>>) -> Vec<Entity> {
+    cameras
+        .iter()
+        .filter(|(_, camera)| camera.is_active)
+        .map(|(entity, _)| entity)
+        .collect()
+}
+
+fn goto_command(
+    mut command: ConsoleCommand<CamGotoCommand>,
+    cameras: Query<(Entity, &Camera), With<PanCam>>,
+    mut pan_cam_commands: EventWriter<PanCamCommand>,
+) {
+    let Some(Ok(CamGotoCommand { x, y, duration })) = command.take() else {
+        return;
+    };
+    for entity in active_cameras(&cameras) {
+        pan_cam_commands.send(PanCamCommand::new(
+            entity,
+            PanCamAction::MoveTo(vec2(x, y)),
+            duration,
+        ));
+    }
+    command.ok();
+}
+
+fn zoom_command(
+    mut command: ConsoleCommand<CamZoomCommand>,
+    cameras: Query<(Entity, &Camera), With<PanCam>>,
+    mut pan_cam_commands: EventWriter<PanCamCommand>,
+) {
+    let Some(Ok(CamZoomCommand { scale, duration })) = command.take() else {
+        return;
+    };
+    if scale <= 0. {
+        command.reply_failed("the scale must be positive");
+        return;
+    }
+    for entity in active_cameras(&cameras) {
+        pan_cam_commands.send(PanCamCommand::new(
+            entity,
+            PanCamAction::ZoomTo(scale),
+            duration,
+        ));
+    }
+    command.ok();
+}
+
+fn fit_command(
+    mut command: ConsoleCommand<CamFitCommand>,
+    cameras: Query<(Entity, &Camera), With<PanCam>>,
+    content: Query<(), With<PanCamContent>>,
+    mut commands: Commands,
+) {
+    let Some(Ok(CamFitCommand { padding, duration })) = command.take() else {
+        return;
+    };
+    if content.is_empty() {
+        command.reply_failed("no entities have a PanCamContent component");
+        return;
+    }
+    for entity in active_cameras(&cameras) {
+        commands
+            .entity(entity)
+            .fit_entities_matching::<With<PanCamContent>>(padding, duration);
+    }
+    command.ok();
+}
+
+fn bookmark_command(
+    mut command: ConsoleCommand<CamBookmarkCommand>,
+    cameras: Query<(Entity, &Camera), With<PanCam>>,
+    bookmarks: Query<&PanCamBookmarks>,
+    mut commands: Commands,
+) {
+    let Some(Ok(CamBookmarkCommand { action })) = command.take() else {
+        return;
+    };
+    match action {
+        BookmarkAction::Save { name } => {
+            for entity in active_cameras(&cameras) {
+                commands.entity(entity).save_bookmark(name.clone());
+            }
+        }
+        BookmarkAction::Load { name, duration } => {
+            let mut found = false;
+            for entity in active_cameras(&cameras) {
+                if bookmarks
+                    .get(entity)
+                    .is_ok_and(|bookmarks| bookmarks.0.contains_key(&name))
+                {
+                    commands
+                        .entity(entity)
+                        .go_to_bookmark(name.clone(), duration);
+                    found = true;
+                }
+            }
+            if !found {
+                command.reply_failed(format!("no bookmark called {name}"));
+                return;
+            }
+        }
+    }
+    command.ok();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+    use bevy_console::{ConsoleCommandEntered, PrintConsoleLine};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    /// Builds a test app running the command handlers without the console UI,
+    /// with an active and an inactive camera
+    fn console_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.add_event::<ConsoleCommandEntered>()
+            .add_event::<PrintConsoleLine>()
+            .add_systems(
+                Update,
+                (goto_command, zoom_command, fit_command, bookmark_command),
+            );
+        let cam = spawn_cam(&mut app, PanCam::default());
+        app.world_mut().spawn((
+            Camera {
+                is_active: false,
+                ..default()
+            },
+            PanCam::default(),
+        ));
+        (app, cam)
+    }
+
+    /// Enters `line` in the console and runs a frame, returning the lines
+    /// printed and the `PanCamCommand`s sent
+    fn enter(app: &mut App, line: &str) -> (Vec<String>, Vec<PanCamCommand>) {
+        let mut args = line.split_whitespace().map(String::from);
+        let command_name = args.next().unwrap();
+        app.world_mut().send_event(ConsoleCommandEntered {
+            command_name,
+            args: args.collect(),
+        });
+        update(app);
+        let world = app.world_mut();
+        let lines = world
+            .resource_mut::<Events<PrintConsoleLine>>()
+            .drain()
+            .map(|line| line.line)
+            .collect();
+        let commands = world
+            .resource_mut::<Events<PanCamCommand>>()
+            .drain()
+            .collect();
+        (lines, commands)
+    }
+
+    #[test]
+    fn goto_and_zoom_animate_active_cameras() {
+        let (mut app, cam) = console_app();
+
+        let (lines, commands) = enter(&mut app, "cam.goto 10 -20 --duration 1");
+        assert_eq!(lines, ["[ok]"]);
+        assert_eq!(
+            commands,
+            [PanCamCommand::new(
+                cam,
+                PanCamAction::MoveTo(vec2(10., -20.)),
+                1.
+            )]
+        );
+
+        let (lines, commands) = enter(&mut app, "cam.zoom 2");
+        assert_eq!(lines, ["[ok]"]);
+        assert_eq!(
+            commands,
+            [PanCamCommand::new(
+                cam,
+                PanCamAction::ZoomTo(2.),
+                DEFAULT_DURATION
+            )]
+        );
+    }
+
+    #[test]
+    fn zoom_rejects_non_positive_scales() {
+        let (mut app, _) = console_app();
+        let (lines, commands) = enter(&mut app, "cam.zoom 0");
+        assert_eq!(lines, ["the scale must be positive", "[failed]"]);
+        assert!(commands.is_empty());
+    }
+
+    #[test]
+    fn fit_frames_content_and_fails_without_any() {
+        let (mut app, cam) = console_app();
+        let (lines, commands) = enter(&mut app, "cam.fit");
+        assert_eq!(
+            lines,
+            ["no entities have a PanCamContent component", "[failed]"]
+        );
+        assert!(commands.is_empty());
+
+        app.world_mut().spawn((
+            GlobalTransform::from_translation(Vec3::new(100., 0., 0.)),
+            PanCamContent {
+                size: vec2(20., 10.),
+            },
+        ));
+        let (lines, commands) = enter(&mut app, "cam.fit --padding 5 --duration 0");
+        assert_eq!(lines, ["[ok]"]);
+        assert_eq!(
+            commands,
+            [PanCamCommand::new(
+                cam,
+                PanCamAction::FitRect {
+                    rect: Rect::from_center_size(vec2(100., 0.), vec2(20., 10.)),
+                    padding: 5.,
+                },
+                0.
+            )]
+        );
+    }
+
+    #[test]
+    fn bookmarks_load_only_once_saved() {
+        let (mut app, cam) = console_app();
+        let (lines, commands) = enter(&mut app, "cam.bookmark load home");
+        assert_eq!(lines, ["no bookmark called home", "[failed]"]);
+        assert!(commands.is_empty());
+
+        let (lines, _) = enter(&mut app, "cam.bookmark save home");
+        assert_eq!(lines, ["[ok]"]);
+        assert!(app
+            .world()
+            .get::<PanCamBookmarks>(cam)
+            .is_some_and(|bookmarks| bookmarks.0.contains_key("home")));
+
+        let (lines, commands) = enter(&mut app, "cam.bookmark load home");
+        assert_eq!(lines, ["[ok]"]);
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].entity, cam);
+    }
+}
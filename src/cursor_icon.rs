@@ -0,0 +1,90 @@
+//! Cursor icon feedback while panning
+
+use bevy::{
+    prelude::*,
+    utils::HashMap,
+    window::{PrimaryWindow, SystemCursorIcon},
+    winit::cursor::CursorIcon,
+};
+
+use crate::{camera_window, GrabState, PanCam};
+
+/// Shows a grabbing cursor icon while cameras with `change_cursor_icon` are
+/// panned by dragging, and restores the previous icon once the drag ends
+pub fn update_cursor_icon(
+    mut commands: Commands,
+    cameras: Query<(Entity, &PanCam, &Camera, &GrabState)>,
+    cursor_icons: Query<&CursorIcon>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut replaced: Local<HashMap<Entity, (Entity, Option<CursorIcon>)>>,
+) {
+    let is_panning = |camera: Entity| {
+        cameras
+            .get(camera)
+            .is_ok_and(|(_, pan_cam, _, grab_state)| {
+                pan_cam.change_cursor_icon && grab_state.panning
+            })
+    };
+
+    // Restore the icons of windows whose camera stopped panning, or was
+    // despawned
+    replaced.retain(|camera, (window, previous)| {
+        if is_panning(*camera) {
+            return true;
+        }
+        if let Some(mut window) = commands.get_entity(*window) {
+            match previous.take() {
+                Some(icon) => window.insert(icon),
+                None => window.remove::<CursorIcon>(),
+            };
+        }
+        false
+    });
+
+    let primary_window = primary_window.get_single().ok();
+    for (entity, _, camera, _) in &cameras {
+        if replaced.contains_key(&entity) || !is_panning(entity) {
+            continue;
+        }
+        let Some(window) = camera_window(camera, primary_window) else {
+            continue;
+        };
+        replaced.insert(entity, (window, cursor_icons.get(window).ok().cloned()));
+        commands
+            .entity(window)
+            .insert(CursorIcon::from(SystemCursorIcon::Grabbing));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn cursor_shows_grabbing_icon_while_panning() {
+        use bevy::{window::SystemCursorIcon, winit::cursor::CursorIcon};
+
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                change_cursor_icon: true,
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        let window = app
+            .world_mut()
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(app.world());
+        let icon = |app: &App| app.world().get::<CursorIcon>(window).cloned();
+
+        drag_right(&mut app, cam);
+        assert_eq!(icon(&app), Some(SystemCursorIcon::Grabbing.into()));
+
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+        assert_eq!(icon(&app), None);
+    }
+}
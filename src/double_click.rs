@@ -0,0 +1,235 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    camera_window, cursor_in_viewport, view_pos_to_world, PanCam, PanCamAction, PanCamCommand,
+    PanCamInputBlockers, RawInputFilter,
+};
+
+/// Centers the view on the clicked world position when double-clicking
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoubleClickCenter {
+    /// The mouse button to double-click
+    pub button: MouseButton,
+    /// The longest time between the two clicks, in seconds
+    pub max_interval: f32,
+    /// How far apart the two clicks can be, in logical pixels
+    pub max_distance: f32,
+    /// How long the camera takes to center on the clicked position, in seconds
+    pub duration: f32,
+}
+
+impl Default for DoubleClickCenter {
+    fn default() -> Self {
+        Self {
+            button: MouseButton::Left,
+            max_interval: 0.3,
+            max_distance: 4.,
+            duration: 0.25,
+        }
+    }
+}
+
+/// Zooms in when double-tapping a touch screen with one finger, and out when
+/// double-tapping with two fingers, keeping the tapped position in place
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoubleTapZoom {
+    /// How much each double tap zooms, as a factor of the projection scale
+    ///
+    /// Ignored when the camera has `zoom_steps`, which then zooms one step.
+    pub factor: f32,
+    /// The longest time between the two taps, in seconds
+    pub max_interval: f32,
+    /// How far fingers can move during a tap, and how far apart the two taps
+    /// can be, in logical pixels
+    pub max_distance: f32,
+    /// How long zooming takes, in seconds
+    pub duration: f32,
+}
+
+impl Default for DoubleTapZoom {
+    fn default() -> Self {
+        Self {
+            factor: 2.,
+            max_interval: 0.3,
+            max_distance: 30.,
+            duration: 0.25,
+        }
+    }
+}
+
+/// Sent when a camera with `double_click_center` is double-clicked, before it
+/// starts centering on the clicked position
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct PanCamDoubleClick {
+    /// The camera entity
+    pub entity: Entity,
+    /// The clicked world position
+    pub world_pos: Vec2,
+}
+
+/// The last click that could start a double-click
+#[derive(Component, Default)]
+pub struct DoubleClickState {
+    /// When the click happened, in seconds since startup, and where, in
+    /// logical window pixels
+    last_click: Option<(f32, Vec2)>,
+}
+
+impl DoubleClickState {
+    /// Registers a click at `time` and `pos`, returning whether it completes a
+    /// double-click
+    fn click(&mut self, config: &DoubleClickCenter, time: f32, pos: Vec2) -> bool {
+        match self.last_click.take() {
+            Some((last_time, last_pos))
+                if time - last_time <= config.max_interval
+                    && pos.distance(last_pos) <= config.max_distance =>
+            {
+                true
+            }
+            _ => {
+                self.last_click = Some((time, pos));
+                false
+            }
+        }
+    }
+}
+
+/// Detects double-clicks on cameras with `double_click_center`, and animates
+/// them to center on the clicked position
+#[allow(clippy::too_many_arguments)]
+pub fn do_camera_double_click(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut query: Query<
+        (
+            Entity,
+            &PanCam,
+            &Camera,
+            &OrthographicProjection,
+            &Transform,
+            &mut DoubleClickState,
+        ),
+        RawInputFilter,
+    >,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    blockers: Res<PanCamInputBlockers>,
+    time: Res<Time>,
+    mut double_clicks: EventWriter<PanCamDoubleClick>,
+    mut pan_cam_commands: EventWriter<PanCamCommand>,
+) {
+    if mouse_buttons.get_just_pressed().next().is_none() {
+        return;
+    }
+
+    let primary_window = primary_window.get_single().ok();
+
+    for (entity, pan_cam, camera, proj, transform, mut state) in &mut query {
+        let Some(config) = pan_cam.double_click_center else {
+            continue;
+        };
+        if !pan_cam.enabled
+            || !camera.is_active
+            || !pan_cam.pan_enabled
+            || blockers.camera_blocked_input(entity).pan
+            || !mouse_buttons.just_pressed(config.button)
+        {
+            continue;
+        }
+
+        let Some(window) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+        let Some(cursor_pos) = window
+            .cursor_position()
+            .filter(|cursor_pos| cursor_in_viewport(camera, window, *cursor_pos))
+        else {
+            continue;
+        };
+
+        if !state.click(&config, time.elapsed_secs(), cursor_pos) {
+            continue;
+        }
+
+        let view_rect = pan_cam.view_rect(camera, window);
+        let world_pos = view_pos_to_world(cursor_pos, view_rect, proj, transform);
+        double_clicks.send(PanCamDoubleClick { entity, world_pos });
+        pan_cam_commands.send(PanCamCommand::new(
+            entity,
+            PanCamAction::MoveTo(world_pos),
+            config.duration,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn double_click_needs_two_close_clicks_in_time() {
+        let config = DoubleClickCenter::default();
+        let mut state = DoubleClickState::default();
+        assert!(!state.click(&config, 0., Vec2::ZERO));
+        // too late
+        assert!(!state.click(&config, 0.5, Vec2::ZERO));
+        // too far
+        assert!(!state.click(&config, 0.6, Vec2::new(10., 0.)));
+        assert!(state.click(&config, 0.8, Vec2::new(11., 1.)));
+        // a third click starts over
+        assert!(!state.click(&config, 0.9, Vec2::new(11., 1.)));
+    }
+
+    #[test]
+    fn double_click_centers_on_clicked_position() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                double_click_center: Some(DoubleClickCenter::default()),
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(75., 50.));
+        update(&mut app);
+
+        let click = |app: &mut App| {
+            mouse(app).press(MouseButton::Left);
+            update(app);
+            mouse(app).release(MouseButton::Left);
+            update(app);
+        };
+        click(&mut app);
+        click(&mut app);
+        let double_clicks: Vec<_> = app
+            .world()
+            .resource::<Events<PanCamDoubleClick>>()
+            .get_cursor()
+            .read(app.world().resource::<Events<PanCamDoubleClick>>())
+            .copied()
+            .collect();
+        assert_eq!(
+            double_clicks,
+            [PanCamDoubleClick {
+                entity: cam,
+                world_pos: vec2(25., 0.)
+            }]
+        );
+
+        for _ in 0..3 {
+            update(&mut app);
+        }
+        assert_eq!(translation(&app, cam), vec2(25., 0.));
+    }
+}
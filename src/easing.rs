@@ -0,0 +1,78 @@
+/// How a camera animation progresses over its duration, e.g. a
+/// [`PanCamCommand`](crate::PanCamCommand) or a [`PathWaypoint`](crate::PathWaypoint)
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Easing {
+    /// At a constant speed
+    Linear,
+    /// Speeding up, then slowing down gently
+    #[default]
+    SmoothStep,
+    /// Starting slowly and speeding up
+    QuadIn,
+    /// Starting fast and slowing down
+    QuadOut,
+    /// Speeding up, then slowing down, more sharply than `SmoothStep`
+    CubicInOut,
+    /// Starting very fast and slowing down sharply
+    ExpoOut,
+    /// A function from time to progress, both from `0.` to `1.`
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// The progress of an animation at time `t`, from `0.` to `1.`
+    pub fn ease(&self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Easing::Linear => t,
+            Easing::SmoothStep => t * t * (3. - 2. * t),
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1. - (1. - t) * (1. - t),
+            Easing::CubicInOut => {
+                if t < 0.5 {
+                    4. * t * t * t
+                } else {
+                    1. - (2. - 2. * t).powi(3) / 2.
+                }
+            }
+            Easing::ExpoOut => {
+                if t >= 1. {
+                    1.
+                } else {
+                    1. - 2f32.powf(-10. * t)
+                }
+            }
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+impl PartialEq for Easing {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Easing::Custom(a), Easing::Custom(b)) => *a as usize == *b as usize,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easings_start_at_0_and_end_at_1() {
+        for easing in [
+            Easing::Linear,
+            Easing::SmoothStep,
+            Easing::QuadIn,
+            Easing::QuadOut,
+            Easing::CubicInOut,
+            Easing::ExpoOut,
+        ] {
+            assert_eq!(easing.ease(0.), 0., "{easing:?}");
+            assert_eq!(easing.ease(1.), 1., "{easing:?}");
+        }
+        assert_eq!(Easing::CubicInOut.ease(0.5), 0.5);
+    }
+}
@@ -0,0 +1,176 @@
+use bevy::prelude::*;
+
+/// Scrolls the camera when the cursor is close to the edges of its view, as is
+/// common for RTS and city-builder cameras
+///
+/// The scroll speed depends on how deep into the edge band the cursor is, and
+/// ramps up and down over `ramp_time` rather than switching on and off.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeScroll {
+    /// Width of the band along the edges that triggers scrolling, in logical
+    /// pixels
+    pub margin: f32,
+    /// Scroll speed with the cursor at the very edge, once fully ramped up
+    ///
+    /// Like the keyboard speed, this is multiplied with the projection scale.
+    pub speed: f32,
+    /// Time in seconds to ramp from standstill to full speed, and back to a
+    /// standstill after the cursor leaves the band. `0.` switches instantly.
+    pub ramp_time: f32,
+    /// Shapes how speed grows with the depth into the band
+    ///
+    /// The speed fraction is `depth.powf(depth_exponent)`, where depth goes
+    /// from 0 at the inner side of the band to 1 at the edge. `1.` is linear,
+    /// higher values keep the speed low until the cursor gets close to the edge.
+    pub depth_exponent: f32,
+    /// Whether the left edge scrolls the camera
+    pub left: bool,
+    /// Whether the right edge scrolls the camera
+    pub right: bool,
+    /// Whether the bottom edge scrolls the camera
+    pub bottom: bool,
+    /// Whether the top edge scrolls the camera
+    pub top: bool,
+}
+
+impl Default for EdgeScroll {
+    fn default() -> Self {
+        Self {
+            margin: 20.,
+            speed: 400.,
+            ramp_time: 0.2,
+            depth_exponent: 1.,
+            left: true,
+            right: true,
+            bottom: true,
+            top: true,
+        }
+    }
+}
+
+impl EdgeScroll {
+    /// The scroll direction for a cursor position, scaled by the speed
+    /// fraction for how deep into the band the cursor is on each axis
+    pub(crate) fn target_velocity(&self, cursor_pos: Vec2, view_rect: Rect) -> Vec2 {
+        if self.margin <= 0. || !view_rect.contains(cursor_pos) {
+            return Vec2::ZERO;
+        }
+
+        let depth = |enabled: bool, distance_to_edge: f32| {
+            if !enabled {
+                return 0.;
+            }
+            let depth = (1. - distance_to_edge / self.margin).clamp(0., 1.);
+            depth.powf(self.depth_exponent)
+        };
+
+        let left = depth(self.left, cursor_pos.x - view_rect.min.x);
+        let right = depth(self.right, view_rect.max.x - cursor_pos.x);
+        // window coordinates have y pointing down
+        let up = depth(self.top, cursor_pos.y - view_rect.min.y);
+        let down = depth(self.bottom, view_rect.max.y - cursor_pos.y);
+
+        Vec2::new(right - left, up - down).clamp_length_max(1.)
+    }
+
+    /// Moves `velocity` towards `target`, at a rate that takes `ramp_time` to
+    /// go from a standstill to full speed
+    pub(crate) fn ramp(&self, velocity: Vec2, target: Vec2, dt: f32) -> Vec2 {
+        if self.ramp_time <= 0. {
+            return target;
+        }
+        let max_change = dt / self.ramp_time;
+        velocity + (target - velocity).clamp_length_max(max_change)
+    }
+}
+
+/// Current edge scroll velocity of a camera, as a fraction of full speed
+#[derive(Component, Default)]
+pub struct EdgeScrollState {
+    pub(crate) velocity: Vec2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view() -> Rect {
+        Rect::new(0., 0., 100., 100.)
+    }
+
+    #[test]
+    fn no_scroll_outside_band() {
+        let edge_scroll = EdgeScroll::default();
+        assert_eq!(
+            edge_scroll.target_velocity(Vec2::new(50., 50.), view()),
+            Vec2::ZERO
+        );
+    }
+
+    #[test]
+    fn speed_is_proportional_to_depth_into_band() {
+        let edge_scroll = EdgeScroll::default();
+        let shallow = edge_scroll.target_velocity(Vec2::new(95., 50.), view());
+        let deep = edge_scroll.target_velocity(Vec2::new(99., 50.), view());
+        assert!((shallow.x - 0.75).abs() < 1e-5);
+        assert!((deep.x - 0.95).abs() < 1e-5);
+        assert_eq!(shallow.y, 0.);
+    }
+
+    #[test]
+    fn top_edge_scrolls_up() {
+        let edge_scroll = EdgeScroll::default();
+        let velocity = edge_scroll.target_velocity(Vec2::new(50., 0.), view());
+        assert_eq!(velocity, Vec2::new(0., 1.));
+    }
+
+    #[test]
+    fn disabled_edges_dont_scroll() {
+        let edge_scroll = EdgeScroll {
+            top: false,
+            right: false,
+            ..default()
+        };
+        let top_right_corner = Vec2::new(100., 0.);
+        assert_eq!(
+            edge_scroll.target_velocity(top_right_corner, view()),
+            Vec2::ZERO
+        );
+        let bottom_left_corner = Vec2::new(0., 100.);
+        let velocity = edge_scroll.target_velocity(bottom_left_corner, view());
+        assert!((velocity - Vec2::new(-1., -1.).normalize()).length() < 1e-5);
+    }
+
+    #[test]
+    fn speed_ramps_up_and_down() {
+        let edge_scroll = EdgeScroll {
+            ramp_time: 1.,
+            ..default()
+        };
+        let full = Vec2::new(1., 0.);
+        let mut velocity = Vec2::ZERO;
+        velocity = edge_scroll.ramp(velocity, full, 0.25);
+        assert!((velocity.x - 0.25).abs() < 1e-5);
+        velocity = edge_scroll.ramp(velocity, full, 0.5);
+        assert!((velocity.x - 0.75).abs() < 1e-5);
+        velocity = edge_scroll.ramp(velocity, full, 0.5);
+        assert_eq!(velocity, full);
+
+        // leaving the band slows down gradually
+        velocity = edge_scroll.ramp(velocity, Vec2::ZERO, 0.5);
+        assert!((velocity.x - 0.5).abs() < 1e-5);
+        velocity = edge_scroll.ramp(velocity, Vec2::ZERO, 0.5);
+        assert_eq!(velocity, Vec2::ZERO);
+    }
+
+    #[test]
+    fn zero_ramp_time_switches_instantly() {
+        let edge_scroll = EdgeScroll {
+            ramp_time: 0.,
+            ..default()
+        };
+        let full = Vec2::new(0., -1.);
+        assert_eq!(edge_scroll.ramp(Vec2::ZERO, full, 0.01), full);
+    }
+}
@@ -0,0 +1,281 @@
+use bevy::{math::vec2, prelude::*, render::primitives::Aabb, window::PrimaryWindow};
+
+use crate::{base_world_size, camera_window, clamp_to_safe_zone, PanCam, PanCamAction};
+
+/// How a camera fits its view to content once it's available
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FitConfig {
+    /// What to fit the view to
+    pub target: FitTarget,
+    /// Margin to keep around the content, in world units
+    pub padding: f32,
+}
+
+impl Default for FitConfig {
+    fn default() -> Self {
+        Self {
+            target: FitTarget::Content,
+            padding: 0.,
+        }
+    }
+}
+
+/// What a camera fits its view to
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FitTarget {
+    /// The combined bounds of all entities with a [`PanCamContent`] component
+    Content,
+    /// A fixed rectangle in world space
+    Rect(Rect),
+}
+
+/// Marks an entity as content that cameras can fit their view to
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct PanCamContent {
+    /// Size of the content in world units, centered on the entity's position
+    pub size: Vec2,
+}
+
+/// Whether a camera has done its fit on startup
+#[derive(Component, Default)]
+pub struct FitOnStartupState {
+    done: bool,
+}
+
+impl FitOnStartupState {
+    pub(crate) fn is_done(&self) -> bool {
+        self.done
+    }
+}
+
+/// Computes the camera position and projection scale that frame `rect`, plus
+/// `padding` on each side, in a view of `view_size` logical pixels
+pub(crate) fn fit_view(
+    rect: Rect,
+    padding: f32,
+    proj: &OrthographicProjection,
+    view_size: Vec2,
+) -> (Vec2, f32) {
+    let size = rect.size() + Vec2::splat(2. * padding);
+    let scale = size / base_world_size(proj, view_size);
+    (rect.center(), scale.max_element())
+}
+
+/// Returns the combined bounds of all content, or `None` if there is no
+/// content yet or some of it was just spawned and hasn't been positioned yet
+fn content_bounds(content: &Query<(Ref<GlobalTransform>, &PanCamContent)>) -> Option<Rect> {
+    content
+        .iter()
+        .map(|(global_transform, content)| {
+            // Global transforms are only propagated at the end of the frame
+            (!global_transform.is_added()).then(|| {
+                Rect::from_center_size(global_transform.translation().truncate(), content.size)
+            })
+        })
+        .reduce(|a, b| a.zip(b).map(|(a, b)| a.union(b)))
+        .flatten()
+}
+
+/// The world bounds of an entity: its `PanCamContent` size, or else its
+/// render `Aabb`, or else just its position. `None` without a
+/// `GlobalTransform`.
+fn entity_bounds(entity: EntityRef) -> Option<Rect> {
+    let global_transform = entity.get::<GlobalTransform>()?;
+    let pos = global_transform.translation().truncate();
+    if let Some(content) = entity.get::<PanCamContent>() {
+        return Some(Rect::from_center_size(pos, content.size));
+    }
+    let Some(aabb) = entity.get::<Aabb>() else {
+        return Some(Rect::from_center_size(pos, Vec2::ZERO));
+    };
+    // The box may be rotated or scaled, so its corners are bounded instead
+    let center = Vec3::from(aabb.center);
+    let half_size = Vec3::from(aabb.half_extents).truncate();
+    let corners = [vec2(-1., -1.), vec2(1., -1.), vec2(-1., 1.), vec2(1., 1.)].map(|corner| {
+        global_transform
+            .transform_point(center + (half_size * corner).extend(0.))
+            .truncate()
+    });
+    Some(corners.iter().fold(
+        Rect::from_corners(corners[0], corners[0]),
+        |rect, corner| rect.union_point(*corner),
+    ))
+}
+
+/// The action that frames the combined bounds of `entities`, plus `padding`
+/// on each side, or `None` if none of them have a `GlobalTransform`
+pub(crate) fn fit_entities_action(
+    world: &World,
+    entities: impl IntoIterator<Item = Entity>,
+    padding: f32,
+) -> Option<PanCamAction> {
+    let rect = entities
+        .into_iter()
+        .filter_map(|entity| world.get_entity(entity).ok().and_then(entity_bounds))
+        .reduce(|a, b| a.union(b))?;
+    // A single point can't be framed, only centered on
+    Some(if rect.size() == Vec2::ZERO && padding <= 0. {
+        PanCamAction::MoveTo(rect.center())
+    } else {
+        PanCamAction::FitRect { rect, padding }
+    })
+}
+
+/// Fits cameras with `fit_on_startup` to their content, once it exists
+pub fn fit_on_startup(
+    mut cameras: Query<(
+        &PanCam,
+        &Camera,
+        &mut FitOnStartupState,
+        &mut OrthographicProjection,
+        &mut Transform,
+    )>,
+    content: Query<(Ref<GlobalTransform>, &PanCamContent)>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    let primary_window = primary_window.get_single().ok();
+
+    for (pan_cam, camera, mut state, mut proj, mut transform) in &mut cameras {
+        let Some(config) = pan_cam.fit_on_startup else {
+            continue;
+        };
+        if state.done {
+            continue;
+        }
+
+        let rect = match config.target {
+            FitTarget::Rect(rect) => rect,
+            // Retry next frame until there is content to fit to
+            FitTarget::Content => match content_bounds(&content) {
+                Some(rect) => rect,
+                None => continue,
+            },
+        };
+
+        let Some(window) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+        let view_size = pan_cam.view_rect(camera, window).size();
+
+        let (center, scale) = fit_view(rect, config.padding, &proj, view_size);
+        proj.scale = scale;
+        pan_cam.constrain_scale(&mut proj, view_size);
+
+        let area_size = base_world_size(&proj, view_size) * proj.scale;
+        transform.translation = clamp_to_safe_zone(center, pan_cam.aabb(area_size), area_size)
+            .extend(transform.translation.z);
+        state.done = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::*, PanCamCommandsExt};
+
+    #[test]
+    fn fit_on_startup_waits_for_content_then_fits_once() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                fit_on_startup: Some(FitConfig::default()),
+                ..default()
+            },
+        );
+        update(&mut app);
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.);
+
+        app.world_mut().spawn((
+            GlobalTransform::from_translation(Vec3::new(-100., 0., 0.)),
+            PanCamContent { size: Vec2::ZERO },
+        ));
+        let content = app
+            .world_mut()
+            .spawn((
+                GlobalTransform::from_translation(Vec3::new(100., 50., 0.)),
+                PanCamContent {
+                    size: vec2(20., 20.),
+                },
+            ))
+            .id();
+        // freshly spawned content isn't positioned yet
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.);
+        update(&mut app);
+        assert!((scale(&app, cam) - 2.1).abs() < 1e-5);
+        assert_eq!(translation(&app, cam), vec2(5., 30.));
+
+        *app.world_mut().get_mut::<GlobalTransform>(content).unwrap() =
+            GlobalTransform::from_translation(Vec3::new(1000., 0., 0.));
+        update(&mut app);
+        assert!((scale(&app, cam) - 2.1).abs() < 1e-5);
+    }
+
+    #[test]
+    fn fit_on_startup_to_rect_adds_padding() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                fit_on_startup: Some(FitConfig {
+                    target: FitTarget::Rect(Rect::new(0., 0., 40., 180.)),
+                    padding: 10.,
+                }),
+                ..default()
+            },
+        );
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 2.);
+        assert_eq!(translation(&app, cam), vec2(20., 90.));
+    }
+
+    #[test]
+    fn fit_entities_frames_their_content_and_render_bounds() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        let content = |pos: Vec2| {
+            (
+                GlobalTransform::from_translation(pos.extend(0.)),
+                PanCamContent {
+                    size: vec2(20., 20.),
+                },
+            )
+        };
+        app.world_mut().spawn(content(vec2(100., 0.)));
+        app.world_mut().spawn(content(vec2(200., 40.)));
+        // a 10x10 box scaled by 2
+        let rendered = app
+            .world_mut()
+            .spawn((
+                GlobalTransform::from_scale(Vec3::splat(2.)),
+                bevy::render::primitives::Aabb::from_min_max(Vec3::splat(-5.), Vec3::splat(5.)),
+            ))
+            .id();
+        update(&mut app);
+
+        run_commands(&mut app, move |commands| {
+            commands
+                .entity(cam)
+                .fit_entities_matching::<With<PanCamContent>>(0., 0.);
+        });
+        update(&mut app);
+        // the content spans 90..210 by -10..50
+        assert!((scale(&app, cam) - 1.2).abs() < 1e-5);
+        assert_eq!(translation(&app, cam), vec2(150., 20.));
+
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).fit_entities([rendered], 5., 0.);
+        });
+        update(&mut app);
+        assert!((scale(&app, cam) - 0.3).abs() < 1e-5);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+    }
+}
@@ -0,0 +1,209 @@
+use bevy::prelude::*;
+
+use crate::{GrabState, PanCam, PanCamSettings};
+
+/// Makes a `PanCam` camera follow an entity, keeping it centered in the view
+///
+/// Insert it on the camera. The camera eases towards the target's position,
+/// within the bounds, so it catches up after the target moves, the user pans or
+/// zooming to the cursor moves the camera away. While the user drags the
+/// camera, following pauses, and the camera eases back once the drag ends.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct PanCamFollow {
+    /// The entity to follow
+    pub target: Entity,
+    /// About how many seconds the camera takes to catch up with the target
+    ///
+    /// After this time, the camera has covered 99% of the distance. `0.` keeps
+    /// the target centered at all times.
+    pub smoothing: f32,
+    /// Size of a rectangle around the center of the view, in world units,
+    /// within which the target can move without the camera following
+    ///
+    /// Once the target leaves it, the camera follows just enough to keep the
+    /// target at its edge. `Vec2::ZERO` keeps the target centered.
+    pub deadzone: Vec2,
+}
+
+impl PanCamFollow {
+    /// Follows `target` without smoothing or deadzone
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            smoothing: 0.,
+            deadzone: Vec2::ZERO,
+        }
+    }
+
+    /// Where the camera at `cam_pos` should move to for the target at
+    /// `target_pos` to be within the deadzone
+    fn camera_target(&self, cam_pos: Vec2, target_pos: Vec2) -> Vec2 {
+        let half_deadzone = self.deadzone.max(Vec2::ZERO) / 2.;
+        target_pos - (target_pos - cam_pos).clamp(-half_deadzone, half_deadzone)
+    }
+}
+
+/// How zooming works while a camera has a [`PanCamFollow`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FollowZoomMode {
+    /// Zoom towards the followed entity, so it stays centered
+    #[default]
+    Target,
+    /// Zoom towards the cursor like when not following, after which the camera
+    /// eases back to the followed entity
+    Cursor,
+}
+
+/// Moves cameras with a `PanCamFollow` towards their target
+pub fn follow_target(
+    mut cameras: Query<(
+        &PanCam,
+        &PanCamFollow,
+        &GrabState,
+        &OrthographicProjection,
+        &mut Transform,
+    )>,
+    targets: Query<&GlobalTransform>,
+    time: Res<Time>,
+    settings: Res<PanCamSettings>,
+) {
+    for (pan_cam, follow, grab_state, proj, mut transform) in &mut cameras {
+        if grab_state.grabbing {
+            continue;
+        }
+        let Ok(target) = targets.get(follow.target) else {
+            continue;
+        };
+
+        let cam_pos = transform.translation.truncate();
+        let target_pos = follow.camera_target(cam_pos, target.translation().truncate());
+        if target_pos == cam_pos {
+            continue;
+        }
+        let t = if follow.smoothing > 0. && !settings.reduced_motion {
+            1. - 0.01f32.powf(time.delta_secs() / follow.smoothing)
+        } else {
+            1.
+        };
+        let new_pos =
+            pan_cam.clamp_position(cam_pos, cam_pos.lerp(target_pos, t), proj.area.size());
+        if new_pos != cam_pos {
+            transform.translation = new_pos.extend(transform.translation.z);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn camera_follows_target_only_outside_deadzone() {
+        let follow = PanCamFollow {
+            deadzone: Vec2::new(20., 10.),
+            ..PanCamFollow::new(Entity::PLACEHOLDER)
+        };
+        let cam_pos = Vec2::new(100., 0.);
+        assert_eq!(follow.camera_target(cam_pos, Vec2::new(108., -4.)), cam_pos);
+        assert_eq!(
+            follow.camera_target(cam_pos, Vec2::new(130., -20.)),
+            Vec2::new(120., -15.)
+        );
+    }
+
+    fn spawn_following_cam(app: &mut App, mode: FollowZoomMode, smoothing: f32) -> Entity {
+        let target = app
+            .world_mut()
+            .spawn(GlobalTransform::from_translation(Vec3::new(30., 40., 0.)))
+            .id();
+        let cam = spawn_cam(
+            app,
+            PanCam {
+                zoom_while_following: mode,
+                ..default()
+            },
+        );
+        app.world_mut().entity_mut(cam).insert(PanCamFollow {
+            smoothing,
+            ..PanCamFollow::new(target)
+        });
+        set_view(app, cam, vec2(30., 40.), 1.);
+        set_cursor(app, vec2(90., 10.));
+        update(app);
+        cam
+    }
+
+    #[test]
+    fn target_follow_zoom_keeps_target_centered() {
+        let mut app = test_app();
+        let cam = spawn_following_cam(&mut app, FollowZoomMode::Target, 0.5);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!(scale(&app, cam) < 1.);
+        assert_eq!(translation(&app, cam), vec2(30., 40.));
+    }
+
+    #[test]
+    fn cursor_follow_zoom_recenters_within_smoothing_time() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+        let cam = spawn_following_cam(&mut app, FollowZoomMode::Cursor, 0.5);
+        scroll(&mut app, 3.);
+        update(&mut app);
+        let offset = (translation(&app, cam) - vec2(30., 40.)).length();
+        assert!(offset > 1.);
+
+        for _ in 0..10 {
+            update(&mut app);
+        }
+        let remaining = (translation(&app, cam) - vec2(30., 40.)).length();
+        assert!(remaining <= offset * 0.01 + 1e-4);
+    }
+
+    #[test]
+    fn dragging_pauses_following_until_released() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+        let cam = spawn_following_cam(&mut app, FollowZoomMode::Target, 0.5);
+        set_cursor(&mut app, vec2(50., 50.));
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        for _ in 0..3 {
+            drag_right(&mut app, cam);
+        }
+        assert_eq!(translation(&app, cam), vec2(0., 40.));
+
+        mouse(&mut app).release(MouseButton::Left);
+        for _ in 0..11 {
+            update(&mut app);
+        }
+        assert!((translation(&app, cam) - vec2(30., 40.)).length() < 0.3 + 1e-4);
+    }
+
+    #[test]
+    fn reduced_motion_follows_without_smoothing() {
+        let mut app = test_app();
+        app.insert_resource(PanCamSettings {
+            reduced_motion: true,
+            ..default()
+        });
+        let cam = spawn_following_cam(&mut app, FollowZoomMode::Target, 0.5);
+        let target = app.world().get::<PanCamFollow>(cam).unwrap().target;
+        app.world_mut()
+            .entity_mut(target)
+            .insert(GlobalTransform::from_translation(Vec3::new(80., -20., 0.)));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(80., -20.));
+    }
+}
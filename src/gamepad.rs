@@ -0,0 +1,285 @@
+use bevy::{
+    input::gamepad::{Gamepad, GamepadButton},
+    prelude::*,
+    window::PrimaryWindow,
+};
+
+use crate::{
+    camera_window, pan_camera, visible_area_size, zoom_camera, IntendedPan, IntendedZoom, PanCam,
+    PanCamInputBlockers, PanCamStats, ZoomFocusState,
+};
+
+/// Gamepad controls for a camera: the right stick pans, and the triggers zoom
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PanCamGamepadConfig {
+    /// How far the stick has to be pushed before it pans, from `0.` to `1.`
+    ///
+    /// Stick input is rescaled so panning starts smoothly at the edge of the
+    /// deadzone.
+    pub deadzone: f32,
+    /// Panning speed with the stick pushed all the way
+    ///
+    /// Like the keyboard speed, this is multiplied with the projection scale.
+    pub pan_speed: f32,
+    /// How many times per second fully pressing a zoom button halves or
+    /// doubles the projection scale
+    pub zoom_speed: f32,
+    /// The buttons that zoom in, pressed partially for analog triggers
+    pub zoom_in: Vec<GamepadButton>,
+    /// The buttons that zoom out, pressed partially for analog triggers
+    pub zoom_out: Vec<GamepadButton>,
+}
+
+impl Default for PanCamGamepadConfig {
+    fn default() -> Self {
+        Self {
+            deadzone: 0.15,
+            pan_speed: 400.,
+            zoom_speed: 1.,
+            zoom_in: vec![GamepadButton::RightTrigger2, GamepadButton::RightTrigger],
+            zoom_out: vec![GamepadButton::LeftTrigger2, GamepadButton::LeftTrigger],
+        }
+    }
+}
+
+impl PanCamGamepadConfig {
+    /// The stick position with the deadzone removed
+    fn pan_input(&self, stick: Vec2) -> Vec2 {
+        let length = stick.length();
+        if length <= self.deadzone || self.deadzone >= 1. {
+            return Vec2::ZERO;
+        }
+        let rescaled = (length - self.deadzone) / (1. - self.deadzone);
+        stick / length * rescaled.min(1.)
+    }
+
+    /// How strongly to zoom in, from `-1.` (zooming out) to `1.`
+    fn zoom_input(&self, gamepad: &Gamepad) -> f32 {
+        let strength = |buttons: &[GamepadButton]| {
+            buttons
+                .iter()
+                .map(|button| {
+                    gamepad
+                        .get(*button)
+                        .unwrap_or(if gamepad.pressed(*button) { 1. } else { 0. })
+                })
+                .fold(0., f32::max)
+        };
+        strength(&self.zoom_in) - strength(&self.zoom_out)
+    }
+}
+
+/// Pans and zooms cameras with a `gamepad` config using connected gamepads
+#[allow(clippy::too_many_arguments)]
+pub fn do_camera_gamepad(
+    gamepads: Query<&Gamepad>,
+    mut query: Query<(
+        Entity,
+        &PanCam,
+        &Camera,
+        &mut OrthographicProjection,
+        &mut Transform,
+        &ZoomFocusState,
+    )>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    blockers: Res<PanCamInputBlockers>,
+    time: Res<Time>,
+    mut stats: ResMut<PanCamStats>,
+    mut intended_pans: EventWriter<IntendedPan>,
+    mut intended_zooms: EventWriter<IntendedZoom>,
+) {
+    if gamepads.is_empty() {
+        return;
+    }
+
+    let primary_window = primary_window.get_single().ok();
+
+    for (entity, pan_cam, camera, mut proj, mut transform, focus) in &mut query {
+        let Some(config) = &pan_cam.gamepad else {
+            continue;
+        };
+        if !pan_cam.enabled || !camera.is_active || blockers.is_camera_blocked(entity) {
+            continue;
+        }
+        let window = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok());
+        if window.is_some_and(|window| !pan_cam.key_focus.allows(camera, window)) {
+            continue;
+        }
+
+        let pan_input = gamepads
+            .iter()
+            .map(|gamepad| config.pan_input(gamepad.right_stick()))
+            .sum::<Vec2>()
+            .clamp_length_max(1.);
+        let zoom_input = gamepads
+            .iter()
+            .map(|gamepad| config.zoom_input(gamepad))
+            .sum::<f32>()
+            .clamp(-1., 1.);
+
+        if pan_cam.pan_enabled && pan_input != Vec2::ZERO {
+            let movement = pan_cam.limit_pan_speed(
+                time.delta_secs() * pan_input * config.pan_speed * proj.scale,
+                time.delta_secs(),
+                proj.scale,
+            );
+            if pan_cam.passive {
+                intended_pans.send(IntendedPan {
+                    entity,
+                    delta: movement,
+                });
+            } else {
+                let proj_area_size = visible_area_size(&proj, &transform);
+                let distance = pan_camera(pan_cam, &mut transform, movement, proj_area_size);
+                if stats.enabled {
+                    stats.total_pan_distance += distance;
+                }
+            }
+        }
+
+        if !pan_cam.zoom_enabled || zoom_input == 0. {
+            continue;
+        }
+
+        let Some(window) = window else {
+            continue;
+        };
+
+        let zoom_factor = 2f32.powf(-zoom_input * config.zoom_speed * time.delta_secs());
+        if pan_cam.passive {
+            intended_zooms.send(IntendedZoom::new(
+                entity,
+                pan_cam,
+                &proj,
+                &transform,
+                zoom_factor,
+                None,
+                focus,
+            ));
+            continue;
+        }
+
+        let zoom_change = zoom_camera(
+            pan_cam,
+            &mut proj,
+            &mut transform,
+            zoom_factor,
+            None,
+            focus,
+            pan_cam.view_rect(camera, window).size(),
+        );
+        if stats.enabled {
+            stats.total_zoom_change += zoom_change;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn deadzone_is_removed_from_stick_input() {
+        let config = PanCamGamepadConfig {
+            deadzone: 0.2,
+            ..default()
+        };
+        assert_eq!(config.pan_input(Vec2::new(0.1, 0.1)), Vec2::ZERO);
+        assert!((config.pan_input(Vec2::new(0.6, 0.)) - Vec2::new(0.5, 0.)).length() < 1e-5);
+        assert_eq!(config.pan_input(Vec2::new(0., -1.)), Vec2::new(0., -1.));
+    }
+
+    #[test]
+    fn gamepad_stick_pans_and_triggers_zoom() {
+        use bevy::input::gamepad::{Gamepad, GamepadAxis, GamepadButton, GamepadInput};
+
+        fn set_analog(app: &mut App, gamepad: Entity, input: impl Into<GamepadInput>, value: f32) {
+            let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad).unwrap();
+            gamepad.analog_mut().set(input, value);
+        }
+
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                gamepad: Some(PanCamGamepadConfig {
+                    deadzone: 0.2,
+                    pan_speed: 100.,
+                    ..default()
+                }),
+                ..default()
+            },
+        );
+        let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+        update(&mut app);
+
+        set_analog(&mut app, gamepad, GamepadAxis::RightStickX, 0.1);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+
+        set_analog(&mut app, gamepad, GamepadAxis::RightStickX, 0.6);
+        update(&mut app);
+        assert!((translation(&app, cam) - vec2(5., 0.)).length() < 1e-4);
+
+        set_analog(&mut app, gamepad, GamepadAxis::RightStickX, 0.);
+        set_analog(&mut app, gamepad, GamepadButton::RightTrigger2, 1.);
+        update(&mut app);
+        assert!((scale(&app, cam) - 2f32.powf(-0.1)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn gamepad_only_moves_cameras_with_key_focus() {
+        use bevy::input::gamepad::{Gamepad, GamepadAxis};
+
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let spawn_half = |app: &mut App, x: u32| {
+            app.world_mut()
+                .spawn((
+                    Camera {
+                        viewport: Some(bevy::render::camera::Viewport {
+                            physical_position: UVec2::new(x, 0),
+                            physical_size: UVec2::new(50, 100),
+                            ..default()
+                        }),
+                        ..default()
+                    },
+                    mock_proj(vec2(50., 100.)),
+                    Transform::default(),
+                    PanCam {
+                        gamepad: Some(PanCamGamepadConfig::default()),
+                        key_focus: crate::KeyFocus::Hovered,
+                        ..default()
+                    },
+                ))
+                .id()
+        };
+        let left = spawn_half(&mut app, 0);
+        let right = spawn_half(&mut app, 50);
+        let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+        set_cursor(&mut app, vec2(75., 50.));
+        update(&mut app);
+
+        app.world_mut()
+            .get_mut::<Gamepad>(gamepad)
+            .unwrap()
+            .analog_mut()
+            .set(GamepadAxis::RightStickX, 1.);
+        update(&mut app);
+        assert_eq!(translation(&app, left), Vec2::ZERO);
+        assert!(translation(&app, right).x > 0.);
+    }
+}
@@ -0,0 +1,138 @@
+//! Native trackpad gesture support
+//!
+//! Dedicated pinch gestures are only reported by some platforms:
+//!
+//! | Platform        | Pinch gesture events | Fallback                        |
+//! |-----------------|----------------------|---------------------------------|
+//! | macOS           | yes                  |                                 |
+//! | iOS             | yes                  |                                 |
+//! | Windows         | no                   | ctrl+scroll from the trackpad   |
+//! | Linux (X11/Wayland) | no               | ctrl+scroll from the trackpad   |
+//! | Web             | no                   | ctrl+scroll from the browser    |
+//!
+//! Where pinch events aren't available, trackpad drivers typically report
+//! pinching as scrolling, which zooms like a mouse wheel. In browsers, the
+//! `web` feature makes pinching zoom like control+scroll, which it is
+//! reported as.
+
+use bevy::{input::gestures::PinchGesture, prelude::*, window::PrimaryWindow};
+
+use crate::{
+    camera_window, normalize_view_pos, zoom_camera, FollowZoomMode, IntendedZoom, PanCam,
+    PanCamFollow, PanCamInputBlockers, PanCamStats, ZoomFocusState,
+};
+
+/// Zooms cameras with trackpad pinch gestures, anchored at the cursor
+#[allow(clippy::type_complexity)]
+pub fn do_camera_pinch_zoom(
+    mut query: Query<(
+        Entity,
+        &PanCam,
+        &Camera,
+        &mut OrthographicProjection,
+        &mut Transform,
+        &ZoomFocusState,
+        Has<PanCamFollow>,
+    )>,
+    mut pinch_events: EventReader<PinchGesture>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    blockers: Res<PanCamInputBlockers>,
+    mut stats: ResMut<PanCamStats>,
+    mut intended_zooms: EventWriter<IntendedZoom>,
+) {
+    let pinch: f32 = pinch_events.read().map(|ev| ev.0).sum();
+    if pinch == 0. {
+        return;
+    }
+
+    let primary_window = primary_window.get_single().ok();
+
+    for (entity, pan_cam, camera, mut proj, mut transform, focus, following) in &mut query {
+        if !pan_cam.enabled
+            || !camera.is_active
+            || !pan_cam.zoom_enabled
+            || blockers.camera_blocked_input(entity).zoom
+        {
+            continue;
+        }
+
+        let Some(window) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+
+        // Pinch events don't say which window they happened in, but the
+        // gesture is centered on the cursor, so only zoom the camera under it
+        let Some(cursor_pos) = window.cursor_position() else {
+            continue;
+        };
+        let view_rect = pan_cam.view_rect(camera, window);
+        if !view_rect.contains(cursor_pos) {
+            continue;
+        }
+
+        // Spreading the fingers apart reports a positive pinch and zooms in
+        let zoom_factor = (-pinch * pan_cam.pinch_zoom_sensitivity).exp();
+        let anchor = Some(normalize_view_pos(cursor_pos, view_rect))
+            .filter(|_| !following || pan_cam.zoom_while_following == FollowZoomMode::Cursor);
+
+        if pan_cam.passive {
+            intended_zooms.send(IntendedZoom::new(
+                entity,
+                pan_cam,
+                &proj,
+                &transform,
+                zoom_factor,
+                anchor,
+                focus,
+            ));
+            continue;
+        }
+
+        let zoom_change = zoom_camera(
+            pan_cam,
+            &mut proj,
+            &mut transform,
+            zoom_factor,
+            anchor,
+            focus,
+            view_rect.size(),
+        );
+
+        if stats.enabled {
+            stats.total_zoom_change += zoom_change;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn pinch_zooms_anchored_at_cursor() {
+        use bevy::input::gestures::PinchGesture;
+
+        let mut app = test_app();
+        app.add_event::<PinchGesture>();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        set_cursor(&mut app, vec2(75., 50.));
+        app.world_mut().send_event(PinchGesture(0.5));
+        update(&mut app);
+
+        let expected_scale = (-0.5f32).exp();
+        assert!((scale(&app, cam) - expected_scale).abs() < 1e-5);
+        // the world position under the cursor stays in place
+        let world_under_cursor = translation(&app, cam).x + 50. * 0.5 * scale(&app, cam);
+        assert!((world_under_cursor - 25.).abs() < 1e-4);
+
+        app.world_mut().send_event(PinchGesture(-0.5));
+        update(&mut app);
+        assert!((scale(&app, cam) - 1.).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,276 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::PanCam;
+
+/// A mouse button that grabs the camera, optionally only while a key is held,
+/// e.g. space and the left mouse button like in image editors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GrabChord {
+    /// The key that has to be held, if any
+    pub modifier: Option<KeyCode>,
+    /// The mouse button to drag with
+    pub button: MouseButton,
+}
+
+impl GrabChord {
+    /// Grabs with `button` while `modifier` is held
+    pub fn new(modifier: KeyCode, button: MouseButton) -> Self {
+        Self {
+            modifier: Some(modifier),
+            button,
+        }
+    }
+
+    fn modifier_pressed(&self, keyboard_buttons: &ButtonInput<KeyCode>) -> bool {
+        self.modifier
+            .is_none_or(|modifier| keyboard_buttons.pressed(modifier))
+    }
+
+    /// Whether both the button and the modifier are held
+    fn pressed(
+        &self,
+        mouse_buttons: &ButtonInput<MouseButton>,
+        keyboard_buttons: &ButtonInput<KeyCode>,
+    ) -> bool {
+        mouse_buttons.pressed(self.button) && self.modifier_pressed(keyboard_buttons)
+    }
+
+    /// Whether the button was pressed this frame with the modifier held
+    pub(crate) fn just_pressed(
+        &self,
+        mouse_buttons: &ButtonInput<MouseButton>,
+        keyboard_buttons: &ButtonInput<KeyCode>,
+    ) -> bool {
+        mouse_buttons.just_pressed(self.button) && self.modifier_pressed(keyboard_buttons)
+    }
+
+    /// Whether the button or the modifier was released this frame
+    fn just_released(
+        &self,
+        mouse_buttons: &ButtonInput<MouseButton>,
+        keyboard_buttons: &ButtonInput<KeyCode>,
+    ) -> bool {
+        mouse_buttons.just_released(self.button)
+            || self
+                .modifier
+                .is_some_and(|modifier| keyboard_buttons.just_released(modifier))
+    }
+}
+
+impl From<MouseButton> for GrabChord {
+    fn from(button: MouseButton) -> Self {
+        Self {
+            modifier: None,
+            button,
+        }
+    }
+}
+
+/// The mouse buttons, optionally combined with keys, that grab the camera
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deref, DerefMut, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GrabButtons(pub Vec<GrabChord>);
+
+impl GrabButtons {
+    /// Whether any chord is held
+    pub(crate) fn any_pressed(
+        &self,
+        mouse_buttons: &ButtonInput<MouseButton>,
+        keyboard_buttons: &ButtonInput<KeyCode>,
+    ) -> bool {
+        self.iter()
+            .any(|chord| chord.pressed(mouse_buttons, keyboard_buttons))
+    }
+
+    /// Whether any chord was pressed this frame
+    pub(crate) fn any_just_pressed(
+        &self,
+        mouse_buttons: &ButtonInput<MouseButton>,
+        keyboard_buttons: &ButtonInput<KeyCode>,
+    ) -> bool {
+        self.iter()
+            .any(|chord| chord.just_pressed(mouse_buttons, keyboard_buttons))
+    }
+
+    /// Whether any chord was released this frame
+    pub(crate) fn any_just_released(
+        &self,
+        mouse_buttons: &ButtonInput<MouseButton>,
+        keyboard_buttons: &ButtonInput<KeyCode>,
+    ) -> bool {
+        self.iter()
+            .any(|chord| chord.just_released(mouse_buttons, keyboard_buttons))
+    }
+
+    /// Whether any chord is held that was already held last frame
+    pub(crate) fn any_held_since_last_frame(
+        &self,
+        mouse_buttons: &ButtonInput<MouseButton>,
+        keyboard_buttons: &ButtonInput<KeyCode>,
+    ) -> bool {
+        self.iter().any(|chord| {
+            chord.pressed(mouse_buttons, keyboard_buttons)
+                && !mouse_buttons.just_pressed(chord.button)
+                && !chord
+                    .modifier
+                    .is_some_and(|modifier| keyboard_buttons.just_pressed(modifier))
+        })
+    }
+}
+
+impl From<Vec<MouseButton>> for GrabButtons {
+    fn from(buttons: Vec<MouseButton>) -> Self {
+        Self(buttons.into_iter().map(GrabChord::from).collect())
+    }
+}
+
+impl From<Vec<GrabChord>> for GrabButtons {
+    fn from(chords: Vec<GrabChord>) -> Self {
+        Self(chords)
+    }
+}
+
+/// Tracks whether a camera is currently being dragged with its grab buttons
+#[derive(Component, Default)]
+pub struct GrabState {
+    pub(crate) grabbing: bool,
+    /// How long the current grab has been held for
+    pub(crate) held: Duration,
+    /// How far the current grab has been dragged, in logical pixels
+    pub(crate) dragged_pixels: Vec2,
+    /// How far the current grab has been dragged, in world units
+    pub(crate) dragged_world: Vec2,
+    /// Whether the grab buttons were pressed outside the camera's viewport
+    pub(crate) blocked: bool,
+    /// Whether the current grab has moved past the dead zone and panned
+    pub(crate) panning: bool,
+}
+
+impl GrabState {
+    /// Adds this frame's drag movement to the current grab, returning whether
+    /// the grab has been dragged past the camera's pan dead zone
+    pub(crate) fn past_deadzone(&mut self, pan_cam: &PanCam, pixels: Vec2, world: Vec2) -> bool {
+        self.dragged_pixels += pixels;
+        self.dragged_world += world;
+        if pan_cam.pan_deadzone_world > 0. {
+            self.dragged_world.length() >= pan_cam.pan_deadzone_world
+        } else {
+            self.dragged_pixels.length() >= pan_cam.pan_deadzone
+        }
+    }
+
+    /// Updates the grab state from this frame's mouse input, returning whether
+    /// drag movement should be applied this frame.
+    ///
+    /// Drag movement is never applied on the frame a grab starts, since the
+    /// cursor may have jumped (e.g. when the click also focused the window).
+    pub(crate) fn update(
+        &mut self,
+        grab_buttons: &GrabButtons,
+        release_ends_grab: bool,
+        mouse_buttons: &ButtonInput<MouseButton>,
+        keyboard_buttons: &ButtonInput<KeyCode>,
+    ) -> bool {
+        let any_held = grab_buttons.any_pressed(mouse_buttons, keyboard_buttons);
+        let any_released = grab_buttons.any_just_released(mouse_buttons, keyboard_buttons);
+
+        if !release_ends_grab {
+            // Any held grab button keeps the pan going, so buttons can be
+            // handed off to each other without interrupting it
+            self.grabbing = any_held;
+            return grab_buttons.any_held_since_last_frame(mouse_buttons, keyboard_buttons);
+        }
+
+        if !any_held || any_released {
+            self.grabbing = false;
+            return false;
+        }
+
+        if self.grabbing {
+            return true;
+        }
+
+        // Once ended, a grab only restarts with a fresh button press
+        self.grabbing = grab_buttons.any_just_pressed(mouse_buttons, keyboard_buttons);
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+
+    use super::*;
+    use crate::{test_utils::*, PanCam};
+
+    #[test]
+    fn pressing_second_grab_button_keeps_panning() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+
+        mouse(&mut app).press(MouseButton::Right);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+    }
+
+    #[test]
+    fn chorded_grab_needs_the_key_held() {
+        let mut app = test_app();
+        let pan_cam = PanCam {
+            grab_buttons: vec![GrabChord::new(KeyCode::Space, MouseButton::Left)].into(),
+            ..default()
+        };
+        let cam = start_grab(&mut app, pan_cam, MouseButton::Left);
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+
+        keys(&mut app).press(KeyCode::Space);
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+
+        keys(&mut app).release(KeyCode::Space);
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+    }
+
+    #[test]
+    fn releasing_one_of_two_grab_buttons_keeps_panning() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        mouse(&mut app).press(MouseButton::Right);
+        update(&mut app);
+
+        mouse(&mut app).release(MouseButton::Left);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+    }
+
+    #[test]
+    fn release_ends_grab_stops_on_first_release() {
+        let mut app = test_app();
+        let pan_cam = PanCam {
+            release_ends_grab: true,
+            ..default()
+        };
+        let cam = start_grab(&mut app, pan_cam, MouseButton::Left);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+
+        mouse(&mut app).press(MouseButton::Right);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+
+        mouse(&mut app).release(MouseButton::Left);
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+
+        // pressing again starts a new pan
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+    }
+}
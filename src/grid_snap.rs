@@ -0,0 +1,146 @@
+use bevy::prelude::*;
+
+use crate::{PanCam, PanCamAction, PanCamCommand, PanEnded};
+
+/// When a [`GridSnap`] aligns the camera to its grid
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GridSnapMode {
+    /// Every frame, like `snap_to_pixels`, so the view moves a cell at a time
+    ///
+    /// Movement is still computed from the unsnapped translation.
+    Continuous,
+    /// When a drag is released, animating to the nearest grid point over
+    /// `duration` seconds
+    OnRelease {
+        /// How long the snap animation takes, in seconds
+        duration: f32,
+    },
+}
+
+/// Aligns a camera's translation to a grid, e.g. to tile boundaries in tile
+/// editors
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridSnap {
+    /// The size of the grid's cells, in world units
+    pub cell_size: Vec2,
+    /// When the camera is aligned
+    pub mode: GridSnapMode,
+}
+
+impl GridSnap {
+    /// Snaps to a grid of `cell_size` every frame
+    pub fn continuous(cell_size: Vec2) -> Self {
+        Self {
+            cell_size,
+            mode: GridSnapMode::Continuous,
+        }
+    }
+
+    /// Snaps to a grid of `cell_size` when a drag is released, animating there
+    /// in 0.15 seconds
+    pub fn on_release(cell_size: Vec2) -> Self {
+        Self {
+            cell_size,
+            mode: GridSnapMode::OnRelease { duration: 0.15 },
+        }
+    }
+
+    /// The grid point nearest to `pos`
+    pub(crate) fn snap(&self, pos: Vec2) -> Vec2 {
+        let cell_size = Vec2::select(self.cell_size.cmpgt(Vec2::ZERO), self.cell_size, Vec2::ONE);
+        let snapped = (pos / cell_size).round() * cell_size;
+        // Axes without a cell size aren't snapped
+        Vec2::select(self.cell_size.cmpgt(Vec2::ZERO), snapped, pos)
+    }
+}
+
+/// Animates cameras with `GridSnapMode::OnRelease` to the nearest grid point
+/// when a drag ends
+pub fn snap_to_grid_on_release(
+    mut pans_ended: EventReader<PanEnded>,
+    query: Query<(&PanCam, &Transform)>,
+    mut pan_cam_commands: EventWriter<PanCamCommand>,
+) {
+    for &PanEnded { entity } in pans_ended.read() {
+        let Ok((pan_cam, transform)) = query.get(entity) else {
+            continue;
+        };
+        let Some(grid_snap) = pan_cam.grid_snap else {
+            continue;
+        };
+        let GridSnapMode::OnRelease { duration } = grid_snap.mode else {
+            continue;
+        };
+        let pos = transform.translation.truncate();
+        let snapped = grid_snap.snap(pos);
+        if snapped != pos {
+            pan_cam_commands.send(PanCamCommand::new(
+                entity,
+                PanCamAction::MoveTo(snapped),
+                duration,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn snaps_to_nearest_grid_point_on_sized_axes() {
+        let grid_snap = GridSnap::continuous(vec2(16., 0.));
+        assert_eq!(grid_snap.snap(vec2(23.9, 5.5)), vec2(16., 5.5));
+        assert_eq!(grid_snap.snap(vec2(24.1, -5.5)), vec2(32., -5.5));
+    }
+
+    #[test]
+    fn grid_snap_aligns_translation_continuously_or_on_release() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        // panning 5 world units per frame on a 16 unit grid
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                speed: 50.,
+                grid_snap: Some(GridSnap::continuous(Vec2::splat(16.))),
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+        keys(&mut app).press(KeyCode::ArrowRight);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(16., 0.));
+
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                grid_snap: Some(GridSnap::on_release(Vec2::splat(16.))),
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+        mouse(&mut app).release(MouseButton::Left);
+        for _ in 0..3 {
+            update(&mut app);
+        }
+        assert_eq!(translation(&app, cam), vec2(-16., 0.));
+    }
+}
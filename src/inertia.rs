@@ -0,0 +1,255 @@
+use bevy::prelude::*;
+
+use crate::{
+    pan_camera, visible_area_size, IntendedPan, PanCam, PanCamEnabled, PanCamSettings, PanCamStats,
+};
+
+/// Keeps a camera gliding after a drag is released while moving, slowing down
+/// with friction
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PanInertia {
+    /// How quickly the glide slows down, as an exponential decay rate per
+    /// second. Higher values stop sooner.
+    pub friction: f32,
+    /// How far back drag movement is averaged to get the velocity at release,
+    /// in seconds
+    pub sample_time: f32,
+    /// The speed below which the glide stops
+    ///
+    /// Like the keyboard speed, this is multiplied with the projection scale.
+    pub min_speed: f32,
+}
+
+impl Default for PanInertia {
+    fn default() -> Self {
+        Self {
+            friction: 5.,
+            sample_time: 0.1,
+            min_speed: 10.,
+        }
+    }
+}
+
+impl PanInertia {
+    /// Longer flings, like scrolling on phones, for `touch_inertia`
+    pub fn touch() -> Self {
+        Self {
+            friction: 3.,
+            sample_time: 0.05,
+            ..default()
+        }
+    }
+}
+
+/// Recent drag movement of a camera, and its velocity while gliding
+#[derive(Component, Default)]
+pub struct InertiaState {
+    /// Frame durations and camera movement of the current drag, oldest first
+    samples: Vec<(f32, Vec2)>,
+    velocity: Vec2,
+    /// Whether the drag is a touch drag, which glides with `touch_inertia`
+    from_touch: bool,
+}
+
+impl InertiaState {
+    /// Records a frame of the current drag, forgetting frames older than
+    /// `sample_time`
+    pub(crate) fn record(&mut self, dt: f32, movement: Vec2, sample_time: f32, from_touch: bool) {
+        if from_touch != self.from_touch {
+            self.samples.clear();
+            self.from_touch = from_touch;
+        }
+        self.velocity = Vec2::ZERO;
+        self.samples.push((dt, movement));
+        let mut time = 0.;
+        let keep_from = self
+            .samples
+            .iter()
+            .rposition(|(dt, _)| {
+                time += dt;
+                time >= sample_time
+            })
+            .unwrap_or(0);
+        self.samples.drain(..keep_from);
+    }
+
+    /// Ends the current drag, gliding on with its recent average velocity
+    pub(crate) fn release(&mut self) {
+        let (time, movement) = self
+            .samples
+            .drain(..)
+            .fold((0., Vec2::ZERO), |(time, movement), (dt, delta)| {
+                (time + dt, movement + delta)
+            });
+        self.velocity = if time > 0. {
+            movement / time
+        } else {
+            Vec2::ZERO
+        };
+    }
+
+    /// Stops gliding and forgets the current drag
+    pub(crate) fn stop(&mut self) {
+        self.samples.clear();
+        self.velocity = Vec2::ZERO;
+    }
+
+    /// Whether the camera is gliding on after a drag
+    pub(crate) fn is_gliding(&self) -> bool {
+        self.velocity != Vec2::ZERO
+    }
+
+    /// Whether a touch drag is being recorded
+    #[cfg(feature = "touch")]
+    pub(crate) fn is_recording_touch(&self) -> bool {
+        self.from_touch && !self.samples.is_empty()
+    }
+
+    /// The inertia the current glide uses
+    fn config(&self, pan_cam: &PanCam) -> Option<PanInertia> {
+        if self.from_touch {
+            pan_cam.touch_inertia
+        } else {
+            pan_cam.inertia
+        }
+    }
+}
+
+/// Moves cameras that are gliding after a drag with `inertia`, or a touch drag
+/// with `touch_inertia`
+pub fn glide_with_inertia(
+    mut query: Query<(
+        Entity,
+        &PanCam,
+        &mut InertiaState,
+        &mut Transform,
+        &OrthographicProjection,
+    )>,
+    time: Res<Time>,
+    mut stats: ResMut<PanCamStats>,
+    mut intended_pans: EventWriter<IntendedPan>,
+    controls_enabled: Res<PanCamEnabled>,
+    settings: Res<PanCamSettings>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, pan_cam, mut state, mut transform, projection) in &mut query {
+        if state.velocity == Vec2::ZERO {
+            continue;
+        }
+        let Some(inertia) = state.config(pan_cam) else {
+            state.stop();
+            continue;
+        };
+        if !**controls_enabled
+            || !pan_cam.enabled
+            || !pan_cam.pan_enabled
+            || settings.reduced_motion
+        {
+            state.stop();
+            continue;
+        }
+
+        let movement = pan_cam.limit_pan_speed(state.velocity * dt, dt, projection.scale);
+        if pan_cam.passive {
+            intended_pans.send(IntendedPan {
+                entity,
+                delta: movement,
+            });
+        } else {
+            let proj_area_size = visible_area_size(projection, &transform);
+            let distance = pan_camera(pan_cam, &mut transform, movement, proj_area_size);
+            if stats.enabled {
+                stats.total_pan_distance += distance;
+            }
+            // Running into the bounds stops the glide
+            if distance < 0.5 * movement.length() {
+                state.stop();
+                continue;
+            }
+        }
+
+        state.velocity *= (-inertia.friction * dt).exp();
+        if state.velocity.length() < inertia.min_speed * projection.scale {
+            state.stop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn release_velocity_averages_recent_movement() {
+        let mut state = InertiaState::default();
+        state.record(0.1, Vec2::new(100., 0.), 0.2, false);
+        state.record(0.1, Vec2::new(10., 0.), 0.2, false);
+        state.record(0.1, Vec2::new(30., 0.), 0.2, false);
+        state.release();
+        assert!((state.velocity - Vec2::new(200., 0.)).length() < 1e-3);
+        assert!(state.samples.is_empty());
+    }
+
+    #[test]
+    fn holding_still_before_release_doesnt_glide() {
+        let mut state = InertiaState::default();
+        state.record(0.1, Vec2::new(100., 0.), 0.1, false);
+        state.record(0.1, Vec2::ZERO, 0.1, false);
+        state.release();
+        assert_eq!(state.velocity, Vec2::ZERO);
+    }
+
+    #[test]
+    fn released_drag_glides_and_slows_down() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                inertia: Some(PanInertia::default()),
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        drag_right(&mut app, cam);
+        drag_right(&mut app, cam);
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+
+        let mut last_step = f32::INFINITY;
+        for _ in 0..3 {
+            let before = translation(&app, cam);
+            update(&mut app);
+            let step = before.x - translation(&app, cam).x;
+            assert!(step > 0. && step < last_step);
+            last_step = step;
+        }
+        for _ in 0..20 {
+            update(&mut app);
+        }
+        let resting = translation(&app, cam);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), resting);
+    }
+
+    #[test]
+    fn released_drag_stops_without_inertia() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        drag_right(&mut app, cam);
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+        let released_at = translation(&app, cam);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), released_at);
+    }
+}
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+
+use crate::{
+    animation::AnimationState, inertia::InertiaState, overscroll::OverscrollState, GrabState,
+    PanCam, PanCamEnabled, PanCamInputBlockers, PanCamPath, PanCamReplay, Travel,
+};
+
+/// What a `PanCam` camera is currently doing, e.g. to pause hover tooltips
+/// while the user pans or show an indicator while it springs back
+///
+/// Updated every frame. [`PanCamInteractionChanged`] is sent when it changes.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub enum PanCamInteraction {
+    /// Waiting for input
+    #[default]
+    Idle,
+    /// Being dragged, past the camera's dead zone
+    Dragging,
+    /// Gliding on after a drag, see `PanCam::inertia`
+    Flinging,
+    /// Springing back within the bounds after being pulled past them, see
+    /// `PanCam::overscroll`
+    SnappingBack,
+    /// Moved by a `PanCamCommand`, `PanCamPath`, `Travel` or `PanCamReplay`
+    Animating,
+    /// Ignoring input, because it is blocked by `PanCamInputBlockers`, turned
+    /// off with `PanCamEnabled` or the camera's `enabled` is unset
+    Blocked,
+}
+
+/// Sent when a camera's [`PanCamInteraction`] changes
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PanCamInteractionChanged {
+    /// The camera entity
+    pub entity: Entity,
+    /// What the camera was doing
+    pub from: PanCamInteraction,
+    /// What the camera is doing now
+    pub to: PanCamInteraction,
+}
+
+/// Updates `PanCamInteraction` for all `PanCam` cameras
+#[allow(clippy::type_complexity)]
+pub fn update_interaction(
+    mut query: Query<(
+        Entity,
+        &PanCam,
+        &GrabState,
+        &InertiaState,
+        &OverscrollState,
+        &AnimationState,
+        Has<PanCamPath>,
+        Has<Travel>,
+        Has<PanCamReplay>,
+        &mut PanCamInteraction,
+    )>,
+    blockers: Res<PanCamInputBlockers>,
+    enabled: Res<PanCamEnabled>,
+    mut changes: EventWriter<PanCamInteractionChanged>,
+) {
+    for (
+        entity,
+        pan_cam,
+        grab_state,
+        inertia_state,
+        overscroll_state,
+        animation,
+        playing_path,
+        traveling,
+        replaying,
+        mut interaction,
+    ) in &mut query
+    {
+        let current = if animation.is_animating() || playing_path || traveling || replaying {
+            PanCamInteraction::Animating
+        } else if grab_state.panning {
+            PanCamInteraction::Dragging
+        } else if overscroll_state.is_springing_back() {
+            PanCamInteraction::SnappingBack
+        } else if inertia_state.is_gliding() {
+            PanCamInteraction::Flinging
+        } else if blockers.is_camera_blocked(entity) || !**enabled || !pan_cam.enabled {
+            PanCamInteraction::Blocked
+        } else {
+            PanCamInteraction::Idle
+        };
+        if *interaction != current {
+            changes.send(PanCamInteractionChanged {
+                entity,
+                from: *interaction,
+                to: current,
+            });
+            *interaction = current;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::{test_utils::*, PanInertia};
+
+    #[test]
+    fn interaction_follows_dragging_flinging_and_blocking() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            16,
+        )));
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                inertia: Some(PanInertia::default()),
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        let interaction = |app: &App| *app.world().get::<PanCamInteraction>(cam).unwrap();
+        assert_eq!(interaction(&app), PanCamInteraction::Idle);
+
+        drag_right(&mut app, cam);
+        assert_eq!(interaction(&app), PanCamInteraction::Dragging);
+
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+        assert_eq!(interaction(&app), PanCamInteraction::Flinging);
+
+        app.world_mut().get_mut::<PanCam>(cam).unwrap().inertia = None;
+        app.world_mut()
+            .resource_mut::<PanCamInputBlockers>()
+            .set("ui", true);
+        update(&mut app);
+        assert_eq!(interaction(&app), PanCamInteraction::Blocked);
+
+        let changes: Vec<_> = app
+            .world()
+            .resource::<Events<PanCamInteractionChanged>>()
+            .iter_current_update_events()
+            .map(|change| (change.from, change.to))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![(PanCamInteraction::Flinging, PanCamInteraction::Blocked)]
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+
+use crate::cursor_in_viewport;
+
+/// Which cameras react to keyboard, gamepad, `PanCamInput` action and touch
+/// input, for split-screen or multi-window setups where each camera should
+/// only be moved when the user is looking at it
+///
+/// Defaults to `Always`, so cameras sharing a window or the same keys all move
+/// together. Split-screen and multi-window setups opt in by setting
+/// `Hovered` or `FocusedWindow` on each camera.
+///
+/// Dragging with the mouse always only moves the camera under the cursor, and
+/// touches only ever move the camera they start in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyFocus {
+    /// Every camera, wherever the cursor is
+    #[default]
+    Always,
+    /// Cameras rendering to the focused window
+    FocusedWindow,
+    /// Cameras whose viewport contains the cursor
+    Hovered,
+}
+
+impl KeyFocus {
+    /// Whether a camera rendering to `window` reacts to keyboard input
+    pub(crate) fn allows(&self, camera: &Camera, window: &Window) -> bool {
+        self.allows_at(camera, window, window.cursor_position())
+    }
+
+    /// Whether a camera rendering to `window` reacts to input while the user
+    /// points at `pointer`, e.g. a touch, in logical window pixels
+    pub(crate) fn allows_at(
+        &self,
+        camera: &Camera,
+        window: &Window,
+        pointer: Option<Vec2>,
+    ) -> bool {
+        match self {
+            KeyFocus::Always => true,
+            KeyFocus::FocusedWindow => window.focused,
+            KeyFocus::Hovered => pointer.is_some_and(|pos| cursor_in_viewport(camera, window, pos)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, PanCam};
+
+    #[test]
+    fn hovered_key_focus_only_moves_camera_under_cursor() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            500,
+        )));
+        let spawn_half = |app: &mut App, x: u32| {
+            app.world_mut()
+                .spawn((
+                    Camera {
+                        viewport: Some(bevy::render::camera::Viewport {
+                            physical_position: UVec2::new(x, 0),
+                            physical_size: UVec2::new(50, 100),
+                            ..default()
+                        }),
+                        ..default()
+                    },
+                    mock_proj(vec2(50., 100.)),
+                    Transform::default(),
+                    PanCam {
+                        key_focus: KeyFocus::Hovered,
+                        ..default()
+                    },
+                ))
+                .id()
+        };
+        let left = spawn_half(&mut app, 0);
+        let right = spawn_half(&mut app, 50);
+
+        set_cursor(&mut app, vec2(75., 50.));
+        update(&mut app);
+        keys(&mut app).press(KeyCode::ArrowRight);
+        update(&mut app);
+        assert_eq!(translation(&app, left), Vec2::ZERO);
+        assert!(translation(&app, right).x > 0.);
+    }
+}
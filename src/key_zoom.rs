@@ -0,0 +1,217 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    camera_window, normalize_view_pos, zoom_camera, FollowZoomMode, IntendedZoom, PanCam,
+    PanCamFollow, PanCamInputBlockers, PanCamStats, RawInputFilter, ZoomFocusState,
+};
+
+/// Which keys zoom the camera in and out for keyboard zooming
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZoomKeys {
+    /// The keys that zoom in
+    pub zoom_in: Vec<KeyCode>,
+    /// The keys that zoom out
+    pub zoom_out: Vec<KeyCode>,
+}
+
+impl ZoomKeys {
+    /// No keys zoom the camera
+    pub const NONE: Self = Self {
+        zoom_in: vec![],
+        zoom_out: vec![],
+    };
+
+    /// The camera is zoomed by the plus and minus keys, on the main keyboard
+    /// and the numpad
+    pub fn plus_minus() -> Self {
+        Self {
+            zoom_in: vec![KeyCode::Equal, KeyCode::NumpadAdd],
+            zoom_out: vec![KeyCode::Minus, KeyCode::NumpadSubtract],
+        }
+    }
+
+    /// The camera is zoomed by Page Up and Page Down
+    pub fn page_up_down() -> Self {
+        Self {
+            zoom_in: vec![KeyCode::PageUp],
+            zoom_out: vec![KeyCode::PageDown],
+        }
+    }
+
+    /// 1 when zooming in, -1 when zooming out, 0 when both or neither
+    fn direction(&self, keyboard_buttons: &ButtonInput<KeyCode>) -> f32 {
+        let mut direction = 0.;
+        if keyboard_buttons.any_pressed(self.zoom_in.iter().copied()) {
+            direction += 1.;
+        }
+        if keyboard_buttons.any_pressed(self.zoom_out.iter().copied()) {
+            direction -= 1.;
+        }
+        direction
+    }
+}
+
+/// Zooms cameras while their `zoom_keys` are held, within the same scale
+/// limits as zooming with the mouse wheel
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn do_camera_key_zoom(
+    keyboard_buttons: Res<ButtonInput<KeyCode>>,
+    mut query: Query<
+        (
+            Entity,
+            &PanCam,
+            &Camera,
+            &mut OrthographicProjection,
+            &mut Transform,
+            &ZoomFocusState,
+            Has<PanCamFollow>,
+        ),
+        RawInputFilter,
+    >,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    blockers: Res<PanCamInputBlockers>,
+    time: Res<Time>,
+    mut stats: ResMut<PanCamStats>,
+    mut intended_zooms: EventWriter<IntendedZoom>,
+) {
+    if keyboard_buttons.get_pressed().next().is_none() {
+        return;
+    }
+
+    let primary_window = primary_window.get_single().ok();
+
+    for (entity, pan_cam, camera, mut proj, mut transform, focus, following) in &mut query {
+        if !pan_cam.enabled
+            || !camera.is_active
+            || !pan_cam.zoom_enabled
+            || blockers.camera_blocked_input(entity).keys
+        {
+            continue;
+        }
+
+        let direction = pan_cam.zoom_keys.direction(&keyboard_buttons);
+        if direction == 0. {
+            continue;
+        }
+
+        let Some(window) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+        if !pan_cam.key_focus.allows(camera, window) {
+            continue;
+        }
+        let view_rect = pan_cam.view_rect(camera, window);
+
+        let zoom_factor = 2f32.powf(-direction * pan_cam.key_zoom_speed * time.delta_secs());
+        let anchor = window
+            .cursor_position()
+            .filter(|_| pan_cam.key_zoom_to_cursor)
+            .filter(|_| !following || pan_cam.zoom_while_following == FollowZoomMode::Cursor)
+            .map(|cursor_pos| normalize_view_pos(cursor_pos, view_rect));
+
+        if pan_cam.passive {
+            intended_zooms.send(IntendedZoom::new(
+                entity,
+                pan_cam,
+                &proj,
+                &transform,
+                zoom_factor,
+                anchor,
+                focus,
+            ));
+            continue;
+        }
+
+        let zoom_change = zoom_camera(
+            pan_cam,
+            &mut proj,
+            &mut transform,
+            zoom_factor,
+            anchor,
+            focus,
+            view_rect.size(),
+        );
+        if stats.enabled {
+            stats.total_zoom_change += zoom_change;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn zoom_keys_zoom_within_scale_limits() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                min_scale: 0.8,
+                zoom_keys: ZoomKeys::plus_minus(),
+                ..default()
+            },
+        );
+        update(&mut app);
+        keys(&mut app).press(KeyCode::Equal);
+        update(&mut app);
+        assert!((scale(&app, cam) - 2f32.powf(-0.1)).abs() < 1e-4);
+        for _ in 0..5 {
+            update(&mut app);
+        }
+        assert_eq!(scale(&app, cam), 0.8);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+
+        keys(&mut app).release(KeyCode::Equal);
+        keys(&mut app).press(KeyCode::Minus);
+        update(&mut app);
+        assert!(scale(&app, cam) > 0.8);
+    }
+
+    #[test]
+    fn no_keys_zoom_by_default() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        update(&mut app);
+        keys(&mut app).press(KeyCode::Equal);
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.);
+    }
+
+    #[test]
+    fn zoom_keys_can_zoom_to_cursor() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_keys: ZoomKeys::page_up_down(),
+                key_zoom_to_cursor: true,
+                ..default()
+            },
+        );
+        // halfway between the center and the right edge
+        set_cursor(&mut app, vec2(75., 50.));
+        update(&mut app);
+        keys(&mut app).press(KeyCode::PageUp);
+        update(&mut app);
+
+        assert!(scale(&app, cam) < 1.);
+        let world_under_cursor = translation(&app, cam).x + 25. * scale(&app, cam);
+        assert!((world_under_cursor - 25.).abs() < 1e-4);
+    }
+}
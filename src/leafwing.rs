@@ -0,0 +1,282 @@
+//! Controls through [`leafwing_input_manager`] actions
+
+use bevy::{math::vec2, prelude::*, utils::HashMap, window::PrimaryWindow};
+use leafwing_input_manager::prelude::*;
+
+use crate::{
+    camera_window, cursor_in_viewport, normalize_view_pos, pan_camera, visible_area_size,
+    zoom_camera, FollowZoomMode, GrabState, IntendedPan, IntendedZoom, PanCam, PanCamFollow,
+    PanCamInputBlockers, PanCamStats, ZoomFocusState,
+};
+
+/// Camera controls that can be bound through an `InputMap`
+///
+/// Cameras with an `ActionState<PanCamInput>` are controlled by these actions
+/// instead of the mouse and keyboard bindings of their `PanCam`. Add
+/// `InputManagerPlugin::<PanCamInput>::default()` to the app, and an
+/// `InputMap<PanCamInput>` to the camera, e.g. from
+/// [`PanCamInput::default_input_map`].
+#[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum PanCamInput {
+    /// Moves the camera in a direction, at the `PanCam` speed when fully
+    /// pushed
+    #[actionlike(DualAxis)]
+    Pan,
+    /// Zooms in while held, at the `PanCam` key zoom speed
+    ZoomIn,
+    /// Zooms out while held, at the `PanCam` key zoom speed
+    ZoomOut,
+    /// Drags the camera with the cursor while held
+    Grab,
+}
+
+impl PanCamInput {
+    /// Bindings matching the default `PanCam` controls: dragging with the left
+    /// mouse button, panning with WASD or the arrow keys and zooming with `+`
+    /// and `-`
+    pub fn default_input_map() -> InputMap<Self> {
+        InputMap::default()
+            .with(Self::Grab, MouseButton::Left)
+            .with_dual_axis(Self::Pan, VirtualDPad::wasd())
+            .with_dual_axis(Self::Pan, VirtualDPad::arrow_keys())
+            .with(Self::ZoomIn, KeyCode::Equal)
+            .with(Self::ZoomIn, KeyCode::NumpadAdd)
+            .with(Self::ZoomOut, KeyCode::Minus)
+            .with(Self::ZoomOut, KeyCode::NumpadSubtract)
+    }
+}
+
+/// Pans and zooms cameras with an `ActionState<PanCamInput>`
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn do_camera_actions(
+    mut query: Query<(
+        Entity,
+        &PanCam,
+        &ActionState<PanCamInput>,
+        &Camera,
+        &mut GrabState,
+        &mut OrthographicProjection,
+        &mut Transform,
+        &ZoomFocusState,
+        Has<PanCamFollow>,
+    )>,
+    windows: Query<(Entity, &Window)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    blockers: Res<PanCamInputBlockers>,
+    mut last_pos: Local<HashMap<Entity, Vec2>>,
+    time: Res<Time>,
+    mut stats: ResMut<PanCamStats>,
+    mut intended_pans: EventWriter<IntendedPan>,
+    mut intended_zooms: EventWriter<IntendedZoom>,
+) {
+    let primary_window = primary_window.get_single().ok();
+
+    for (
+        entity,
+        pan_cam,
+        actions,
+        camera,
+        mut grab_state,
+        mut proj,
+        mut transform,
+        focus,
+        following,
+    ) in &mut query
+    {
+        if !pan_cam.enabled || !camera.is_active || blockers.is_camera_blocked(entity) {
+            grab_state.grabbing = false;
+            continue;
+        }
+
+        let Some((window_entity, window)) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+        let view_rect = pan_cam.view_rect(camera, window);
+        let cursor_pos = window.cursor_position();
+        // Grabbing is tied to the cursor, unlike panning and zooming actions
+        let has_focus = pan_cam.key_focus.allows(camera, window);
+
+        if pan_cam.pan_enabled {
+            let proj_area_size = visible_area_size(&proj, &transform);
+
+            // Like with the grab buttons, a grab only starts over the camera's
+            // viewport, and doesn't move the camera on the frame it starts
+            let mut drag_delta = Vec2::ZERO;
+            if actions.just_pressed(&PanCamInput::Grab) {
+                grab_state.grabbing = cursor_pos
+                    .is_some_and(|cursor_pos| cursor_in_viewport(camera, window, cursor_pos));
+            } else if !actions.pressed(&PanCamInput::Grab) {
+                grab_state.grabbing = false;
+            } else if grab_state.grabbing {
+                if let (Some(cursor_pos), Some(last_pos)) =
+                    (cursor_pos, last_pos.get(&window_entity))
+                {
+                    let delta_pixels = vec2(cursor_pos.x - last_pos.x, last_pos.y - cursor_pos.y);
+                    drag_delta = -delta_pixels * proj_area_size / view_rect.size();
+                    if pan_cam.invert_drag {
+                        drag_delta = -drag_delta;
+                    }
+                }
+            }
+
+            let direction = if has_focus {
+                actions.axis_pair(&PanCamInput::Pan).clamp_length_max(1.)
+            } else {
+                Vec2::ZERO
+            };
+            let movement = pan_cam.limit_pan_speed(
+                drag_delta + time.delta_secs() * direction * pan_cam.speed * proj.scale,
+                time.delta_secs(),
+                proj.scale,
+            );
+
+            if movement != Vec2::ZERO {
+                if pan_cam.passive {
+                    intended_pans.send(IntendedPan {
+                        entity,
+                        delta: movement,
+                    });
+                } else {
+                    let distance = pan_camera(pan_cam, &mut transform, movement, proj_area_size);
+                    if stats.enabled {
+                        stats.total_pan_distance += distance;
+                    }
+                }
+            }
+        }
+
+        let mut zoom_direction = 0.;
+        if actions.pressed(&PanCamInput::ZoomIn) {
+            zoom_direction += 1.;
+        }
+        if actions.pressed(&PanCamInput::ZoomOut) {
+            zoom_direction -= 1.;
+        }
+        if !pan_cam.zoom_enabled || !has_focus || zoom_direction == 0. {
+            continue;
+        }
+
+        let zoom_factor = 2f32.powf(-zoom_direction * pan_cam.key_zoom_speed * time.delta_secs());
+        let anchor = cursor_pos
+            .filter(|_| pan_cam.key_zoom_to_cursor)
+            .filter(|_| !following || pan_cam.zoom_while_following == FollowZoomMode::Cursor)
+            .map(|cursor_pos| normalize_view_pos(cursor_pos, view_rect));
+
+        if pan_cam.passive {
+            intended_zooms.send(IntendedZoom::new(
+                entity,
+                pan_cam,
+                &proj,
+                &transform,
+                zoom_factor,
+                anchor,
+                focus,
+            ));
+            continue;
+        }
+
+        let zoom_change = zoom_camera(
+            pan_cam,
+            &mut proj,
+            &mut transform,
+            zoom_factor,
+            anchor,
+            focus,
+            view_rect.size(),
+        );
+        if stats.enabled {
+            stats.total_zoom_change += zoom_change;
+        }
+    }
+
+    for (window_entity, window) in &windows {
+        if let Some(cursor_pos) = window.cursor_position() {
+            last_pos.insert(window_entity, cursor_pos);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn action_controlled_cameras_use_actions_instead_of_raw_input() {
+        use bevy::utils::Instant;
+        use leafwing_input_manager::prelude::ActionState;
+
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                speed: 100.,
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        app.world_mut()
+            .entity_mut(cam)
+            .insert(ActionState::<PanCamInput>::default());
+        fn actions(app: &mut App, cam: Entity) -> Mut<'_, ActionState<PanCamInput>> {
+            app.world_mut()
+                .get_mut::<ActionState<PanCamInput>>(cam)
+                .unwrap()
+        }
+
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+        mouse(&mut app).release(MouseButton::Left);
+
+        actions(&mut app, cam).press(&PanCamInput::Grab);
+        update(&mut app);
+        // like the input manager plugin would, turn the press into a hold
+        actions(&mut app, cam).tick(Instant::now(), Instant::now());
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+
+        actions(&mut app, cam).release(&PanCamInput::Grab);
+        actions(&mut app, cam).set_axis_pair(&PanCamInput::Pan, vec2(0., 1.));
+        let before = translation(&app, cam);
+        update(&mut app);
+        assert!((translation(&app, cam) - before - vec2(0., 10.)).length() < 1e-4);
+    }
+
+    #[test]
+    fn pan_and_zoom_actions_respect_key_focus() {
+        use leafwing_input_manager::prelude::ActionState;
+
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                key_focus: crate::KeyFocus::Hovered,
+                ..default()
+            },
+        );
+        let mut actions = ActionState::<PanCamInput>::default();
+        actions.set_axis_pair(&PanCamInput::Pan, vec2(1., 0.));
+        actions.press(&PanCamInput::ZoomIn);
+        app.world_mut().entity_mut(cam).insert(actions);
+
+        // the cursor is outside the window
+        update(&mut app);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+        assert_eq!(scale(&app, cam), 1.);
+
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+        assert!(translation(&app, cam).x > 0.);
+        assert!(scale(&app, cam) < 1.);
+    }
+}
@@ -1,28 +1,189 @@
 #![warn(missing_docs)]
 #![doc = include_str!("../README.md")]
 
+use animation::AnimationState;
 use bevy::{
-    input::mouse::{MouseScrollUnit, MouseWheel},
-    math::{
-        bounding::{Aabb2d, BoundingVolume},
-        vec2, Rect,
+    input::{
+        mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        InputSystem,
     },
+    math::{bounding::Aabb2d, vec2, Rect},
     prelude::*,
-    render::camera::CameraProjection,
-    window::PrimaryWindow,
+    render::camera::{CameraProjection, CameraUpdateSystem, NormalizedRenderTarget},
+    transform::TransformSystem,
+    utils::HashMap,
+    window::{PrimaryWindow, WindowResized},
 };
-use std::ops::RangeInclusive;
+use double_click::DoubleClickState;
+use edge_scroll::EdgeScrollState;
+use fit::FitOnStartupState;
+use grab_buttons::GrabState;
+use inertia::InertiaState;
+use overscroll::{pan_camera_elastic, OverscrollState};
+use page::PageState;
+use pan_delta::PanDeltaState;
+use pixel_snap::PixelSnapState;
+use ready::ReadyState;
+use resize::ResizeState;
+use smooth_zoom::ZoomSmoothingState;
+use std::{ops::RangeInclusive, time::Duration};
+use timestep::{buffer_input, restore_input, use_buffered_input, BufferedInput};
+use view_events::ViewChangeState;
+use zoom_focus::ZoomFocusState;
+
+pub use animation::{animate_cameras, PanCamAction, PanCamCommand, PanCamCommandDone};
+pub use blockers::{BlockedInput, PanCamEnabled, PanCamInputBlockers};
+pub use boundary::{update_boundary_contact, BoundaryContact};
+pub use builder::{PanCamBuilder, PanCamBundle};
+pub use click_travel::{do_camera_click_travel, ClickToTravel};
+#[cfg(feature = "console")]
+pub use console::{CamBookmarkCommand, CamFitCommand, CamGotoCommand, CamZoomCommand};
+#[cfg(feature = "cursor_icon")]
+pub use cursor_icon::update_cursor_icon;
+pub use double_click::{
+    do_camera_double_click, DoubleClickCenter, DoubleTapZoom, PanCamDoubleClick,
+};
+pub use easing::Easing;
+pub use edge_scroll::EdgeScroll;
+pub use fit::{fit_on_startup, FitConfig, FitTarget, PanCamContent};
+pub use follow::{follow_target, FollowZoomMode, PanCamFollow};
+pub use gamepad::{do_camera_gamepad, PanCamGamepadConfig};
+#[cfg(feature = "gestures")]
+pub use gestures::do_camera_pinch_zoom;
+pub use grab_buttons::{GrabButtons, GrabChord};
+pub use grid_snap::{snap_to_grid_on_release, GridSnap, GridSnapMode};
+pub use inertia::{glide_with_inertia, PanInertia};
+pub use interaction::{update_interaction, PanCamInteraction, PanCamInteractionChanged};
+pub use key_focus::KeyFocus;
+pub use key_zoom::{do_camera_key_zoom, ZoomKeys};
+#[cfg(feature = "leafwing")]
+pub use leafwing::{do_camera_actions, PanCamInput};
+pub use link::{sync_linked_cameras, PanCamLink};
+#[cfg(feature = "minimap")]
+pub use minimap::{
+    navigate_minimaps, sync_minimaps, MinimapCorner, PanCamMinimap, PanCamMinimapGizmos,
+};
+pub use overscroll::{spring_back_overscroll, Overscroll};
+pub use page::do_camera_page_pan;
+pub use pan_delta::{send_pan_deltas, CameraPanDelta};
+pub use pan_lifecycle::{PanEnded, PanStarted};
+pub use parallax::{sync_parallax_layers, ParallaxLayer};
+pub use passive::{IntendedPan, IntendedZoom};
+pub use path::{play_paths, resume_input_after_path, PanCamPath, PanCamPathDone, PathWaypoint};
+pub use perspective::{do_perspective_camera, PanCamPerspective};
+#[cfg(feature = "picking")]
+pub use picking::block_while_picking_drags;
+pub use pixel_snap::{snap_translation, unsnap_translation};
+pub use projection::{copy_projection_from_enum, copy_projection_to_enum};
+pub use punch_zoom::{punch_zoom, PunchZoom};
+pub use ready::{send_ready_events, PanCamReady};
+pub use recording::{
+    play_replays, record_cameras, resume_input_after_replay, PanCamRecorder, PanCamRecording,
+    PanCamReplay, PanCamReplayDone, RecordedFrame,
+};
+pub use reset::{do_camera_reset, ResetView};
+pub use resize::{apply_resize_policy, keep_anchor_on_resize, ResizeAnchor, ResizePolicy};
+pub use rotate::{do_camera_key_rotate, RotateKeys};
+pub use settings::{EguiBlockPolicy, PanCamSettings};
+pub use shake::{shake_translation, unshake_translation, PanCamShake};
+pub use simulate::SimulatedScroll;
+pub use smooth_zoom::smooth_zoom;
+pub use snapshot::{PanCamBookmarks, PanCamCommandsExt, PanCamSnapshot, PanCamSnapshotStack};
+pub use state::{update_pan_cam_state, PanCamState};
+pub use timestep::PanCamTimestep;
+#[cfg(feature = "touch")]
+pub use touch::{do_camera_double_tap, do_camera_touch_pan, do_camera_touch_pinch};
+pub use travel::{travel, Travel, TravelMode};
+pub use view_events::{begin_view_changes, send_view_changes, PanCamMoved, PanCamZoomed};
+pub use visible_rect::{update_visible_world_rect, VisibleWorldRect, VisibleWorldRectChanged};
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+pub use web::{prevent_browser_zoom, WebWheelModifiers};
+pub use zoom_focus::{track_zoom_focus, ZoomFocus};
+pub use zoom_steps::ZoomSteps;
+
+mod animation;
+mod blockers;
+mod boundary;
+mod builder;
+mod click_travel;
+#[cfg(feature = "console")]
+mod console;
+#[cfg(feature = "cursor_icon")]
+mod cursor_icon;
+mod double_click;
+mod easing;
+mod edge_scroll;
+mod fit;
+mod follow;
+mod gamepad;
+#[cfg(feature = "gestures")]
+mod gestures;
+mod grab_buttons;
+mod grid_snap;
+mod inertia;
+mod interaction;
+mod key_focus;
+mod key_zoom;
+#[cfg(feature = "leafwing")]
+mod leafwing;
+mod link;
+#[cfg(feature = "minimap")]
+mod minimap;
+mod overscroll;
+mod page;
+mod pan_delta;
+mod pan_lifecycle;
+mod parallax;
+mod passive;
+mod path;
+mod perspective;
+#[cfg(feature = "picking")]
+mod picking;
+mod pixel_snap;
+mod projection;
+mod punch_zoom;
+mod ready;
+mod recording;
+mod reset;
+mod resize;
+mod rotate;
+#[cfg(feature = "serde")]
+mod serde_limits;
+mod settings;
+mod shake;
+mod simulate;
+mod smooth_zoom;
+mod snapshot;
+mod state;
+#[cfg(any(test, feature = "test_utils"))]
+pub mod test_utils;
+mod timestep;
+#[cfg(feature = "touch")]
+mod touch;
+mod travel;
+mod view_events;
+mod visible_rect;
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+mod web;
+mod zoom_focus;
+mod zoom_steps;
 
 /// Plugin that adds the necessary systems for `PanCam` components to work
+///
+/// Cameras are moved in `Update`, or in `FixedUpdate` if a
+/// [`PanCamTimestep`] says so when the plugin is added.
 #[derive(Default)]
 pub struct PanCamPlugin;
 
 /// System set to allow ordering of `PanCamPlugin`
+///
+/// It runs in `Update`, or in `FixedUpdate` with [`PanCamTimestep::Fixed`].
 #[derive(Debug, Clone, Copy, SystemSet, PartialEq, Eq, Hash)]
 pub struct PanCamSystemSet;
 
 /// Which keys move the camera in particular directions for keyboard movement
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DirectionKeys {
     ///  The keys that move the camera up
     pub up: Vec<KeyCode>,
@@ -73,22 +234,40 @@ impl DirectionKeys {
         }
     }
 
+    /// Keys for paging up and down with Page Up and Page Down
+    pub fn page_up_down() -> Self {
+        Self {
+            up: vec![KeyCode::PageUp],
+            down: vec![KeyCode::PageDown],
+            left: vec![],
+            right: vec![],
+        }
+    }
+
     fn direction(&self, keyboard_buttons: &Res<ButtonInput<KeyCode>>) -> Vec2 {
+        self.direction_where(|key| keyboard_buttons.pressed(key))
+    }
+
+    fn just_pressed_direction(&self, keyboard_buttons: &Res<ButtonInput<KeyCode>>) -> Vec2 {
+        self.direction_where(|key| keyboard_buttons.just_pressed(key))
+    }
+
+    fn direction_where(&self, active: impl Fn(KeyCode) -> bool) -> Vec2 {
         let mut direction = Vec2::ZERO;
 
-        if self.left.iter().any(|key| keyboard_buttons.pressed(*key)) {
+        if self.left.iter().any(|key| active(*key)) {
             direction.x -= 1.;
         }
 
-        if self.right.iter().any(|key| keyboard_buttons.pressed(*key)) {
+        if self.right.iter().any(|key| active(*key)) {
             direction.x += 1.;
         }
 
-        if self.up.iter().any(|key| keyboard_buttons.pressed(*key)) {
+        if self.up.iter().any(|key| active(*key)) {
             direction.y += 1.;
         }
 
-        if self.down.iter().any(|key| keyboard_buttons.pressed(*key)) {
+        if self.down.iter().any(|key| active(*key)) {
             direction.y -= 1.;
         }
 
@@ -98,131 +277,1142 @@ impl DirectionKeys {
 
 impl Plugin for PanCamPlugin {
     fn build(&self, app: &mut App) {
+        let timestep = app
+            .world()
+            .get_resource::<PanCamTimestep>()
+            .copied()
+            .unwrap_or_default();
+        let schedule = timestep.schedule();
         app.add_systems(
-            Update,
-            (do_camera_movement, do_camera_zoom).in_set(PanCamSystemSet),
+            schedule,
+            (
+                copy_projection_from_enum.before(begin_view_changes),
+                unsnap_translation.before(begin_view_changes),
+                unshake_translation
+                    .after(unsnap_translation)
+                    .before(begin_view_changes),
+                begin_view_changes.before(fit_on_startup),
+                fit_on_startup.before(PanCamSystemSet),
+                pause_inactive_cameras.before(PanCamSystemSet),
+                track_zoom_focus.before(PanCamSystemSet),
+                (
+                    do_camera_movement.run_if(movement_input_present),
+                    do_camera_page_pan.run_if(blockers::keys_not_blocked),
+                    do_camera_zoom.run_if(on_event::<MouseWheel>.or(on_event::<SimulatedScroll>)),
+                    do_camera_key_zoom.run_if(blockers::keys_not_blocked),
+                    do_camera_key_rotate.run_if(blockers::keys_not_blocked),
+                    do_camera_double_click.run_if(blockers::pan_not_blocked),
+                    do_camera_click_travel.run_if(blockers::pan_not_blocked),
+                    do_camera_reset.run_if(blockers::keys_not_blocked),
+                    do_camera_gamepad,
+                    do_perspective_camera,
+                )
+                    .in_set(PanCamSystemSet),
+                // Camera movement, then reporting the resulting view
+                (
+                    (
+                        spring_back_overscroll,
+                        glide_with_inertia,
+                        smooth_zoom,
+                        follow_target,
+                        enforce_camera_limits,
+                        punch_zoom,
+                        travel,
+                        snap_to_grid_on_release,
+                        animate_cameras,
+                        play_paths,
+                        play_replays,
+                        sync_linked_cameras,
+                        record_cameras,
+                    )
+                        .chain(),
+                    (
+                        update_cursor_world_pos,
+                        update_boundary_contact,
+                        update_pan_cam_state,
+                        update_visible_world_rect,
+                        update_interaction,
+                        send_view_changes,
+                        send_pan_deltas,
+                        send_ready_events,
+                    )
+                        .chain(),
+                )
+                    .chain()
+                    .after(PanCamSystemSet),
+            ),
         )
+        .add_observer(lock_zoom_on_spawn)
+        .add_event::<PanCamReady>()
+        .add_event::<VisibleWorldRectChanged>()
+        .add_event::<IntendedPan>()
+        .add_event::<IntendedZoom>()
+        .add_event::<CameraPanDelta>()
+        .add_event::<SimulatedScroll>()
+        .add_event::<PanCamCommand>()
+        .add_event::<PanCamCommandDone>()
+        .add_event::<PanCamPathDone>()
+        .add_event::<PanCamReplayDone>()
+        .add_event::<PanCamDoubleClick>()
+        .add_event::<PanStarted>()
+        .add_event::<PanEnded>()
+        .add_event::<PanCamMoved>()
+        .add_event::<PanCamZoomed>()
+        .add_event::<PanCamInteractionChanged>()
         .register_type::<PanCam>()
-        .register_type::<DirectionKeys>();
+        .register_type::<CursorWorldPos>()
+        .register_type::<BoundaryContact>()
+        .register_type::<PanCamState>()
+        .register_type::<VisibleWorldRect>()
+        .register_type::<PanCamInteraction>()
+        .register_type::<PanCamSnapshotStack>()
+        .register_type::<PanCamBookmarks>()
+        .register_type::<PanCamContent>()
+        .register_type::<PanCamFollow>()
+        .register_type::<PanCamAction>()
+        .register_type::<FollowZoomMode>()
+        .register_type::<FitConfig>()
+        .register_type::<DirectionKeys>()
+        .register_type::<GrabButtons>()
+        .register_type::<GrabChord>()
+        .register_type::<ZoomKeys>()
+        .register_type::<RotateKeys>()
+        .register_type::<DoubleClickCenter>()
+        .register_type::<ClickToTravel>()
+        .register_type::<DoubleTapZoom>()
+        .register_type::<ResetView>()
+        .register_type::<ScrollMapping>()
+        .register_type::<ScrollBehavior>()
+        .register_type::<PanInputSource>()
+        .register_type::<DragPixelSpace>()
+        .register_type::<MaxPanSpeed>()
+        .register_type::<KeyFocus>()
+        .register_type::<ResizeAnchor>()
+        .register_type::<ResizePolicy>()
+        .register_type::<ZoomSensitivityCurve>()
+        .register_type::<ZoomMode>()
+        .register_type::<IntegerZoomMagnet>()
+        .register_type::<ZoomSteps>()
+        .register_type::<ZoomFocus>()
+        .register_type::<EdgeScroll>()
+        .register_type::<PanInertia>()
+        .register_type::<PanCamGamepadConfig>()
+        .register_type::<EdgeSoftness>()
+        .register_type::<Overscroll>()
+        .register_type::<GridSnap>()
+        .register_type::<GridSnapMode>()
+        .register_type::<PanCamShake>()
+        .register_type::<ParallaxLayer>()
+        .register_type::<PanCamLink>()
+        .register_type::<PanCamPerspective>()
+        .register_type::<BoundsMargin>()
+        .register_type::<PanCamStats>()
+        .init_resource::<PanCamStats>()
+        .init_resource::<PanCamSettings>()
+        .register_type::<PanCamSettings>()
+        .register_type::<EguiBlockPolicy>()
+        .add_observer(resume_input_after_path)
+        .add_observer(resume_input_after_replay)
+        .init_resource::<PanCamInputBlockers>()
+        .init_resource::<PanCamEnabled>()
+        .register_type::<PanCamEnabled>()
+        .insert_resource(timestep)
+        .register_type::<PanCamTimestep>()
+        .configure_sets(
+            schedule,
+            PanCamSystemSet
+                .run_if(blockers::input_not_blocked)
+                .run_if(blockers::controls_enabled),
+        );
 
-        #[cfg(feature = "bevy_egui")]
-        {
-            app.init_resource::<EguiWantsFocus>()
-                .add_systems(PostUpdate, check_egui_wants_focus)
-                .configure_sets(
-                    Update,
-                    PanCamSystemSet.run_if(resource_equals(EguiWantsFocus(false))),
+        if timestep == PanCamTimestep::Fixed {
+            app.init_resource::<BufferedInput<MouseButton>>()
+                .init_resource::<BufferedInput<KeyCode>>()
+                .add_systems(
+                    PreUpdate,
+                    (buffer_input::<MouseButton>, buffer_input::<KeyCode>).after(InputSystem),
+                )
+                .add_systems(
+                    FixedPreUpdate,
+                    (
+                        use_buffered_input::<MouseButton>,
+                        use_buffered_input::<KeyCode>,
+                    ),
+                )
+                .add_systems(
+                    FixedPostUpdate,
+                    (restore_input::<MouseButton>, restore_input::<KeyCode>),
                 );
         }
+
+        // Limits are enforced again before the projection is updated for
+        // rendering, so changes made to a `PanCam` later in `Update` still take
+        // effect the same frame
+        app.add_systems(
+            PostUpdate,
+            (
+                begin_view_changes.before(enforce_camera_limits),
+                apply_resize_policy
+                    .after(begin_view_changes)
+                    .before(enforce_camera_limits),
+                enforce_camera_limits.before(CameraUpdateSystem),
+                copy_projection_to_enum
+                    .after(enforce_camera_limits)
+                    .before(CameraUpdateSystem),
+                keep_anchor_on_resize
+                    .after(CameraUpdateSystem)
+                    .before(TransformSystem::TransformPropagate),
+                send_view_changes
+                    .after(keep_anchor_on_resize)
+                    .before(TransformSystem::TransformPropagate),
+                // Parallax layers follow the unshaken camera, which is then
+                // snapped with its shake applied. Both are removed again
+                // before it's next panned
+                (
+                    sync_parallax_layers,
+                    unsnap_translation,
+                    shake_translation,
+                    snap_translation,
+                )
+                    .chain()
+                    .after(send_view_changes)
+                    .before(TransformSystem::TransformPropagate),
+            ),
+        );
+
+        #[cfg(feature = "gestures")]
+        app.add_systems(
+            schedule,
+            do_camera_pinch_zoom
+                .run_if(blockers::zoom_not_blocked)
+                .in_set(PanCamSystemSet),
+        );
+
+        #[cfg(feature = "touch")]
+        app.add_systems(
+            schedule,
+            (
+                do_camera_touch_pan,
+                do_camera_touch_pinch,
+                do_camera_double_tap,
+            )
+                .in_set(PanCamSystemSet),
+        );
+
+        #[cfg(feature = "cursor_icon")]
+        app.add_systems(schedule, update_cursor_icon.after(PanCamSystemSet));
+
+        #[cfg(all(feature = "web", target_arch = "wasm32"))]
+        app.add_systems(Update, prevent_browser_zoom)
+            .init_resource::<WebWheelModifiers>();
+
+        #[cfg(feature = "leafwing")]
+        app.add_systems(schedule, do_camera_actions.in_set(PanCamSystemSet))
+            .register_type::<PanCamInput>();
+
+        #[cfg(feature = "minimap")]
+        app.add_systems(schedule, navigate_minimaps.before(PanCamSystemSet))
+            .add_systems(
+                PostUpdate,
+                sync_minimaps
+                    .after(enforce_camera_limits)
+                    .before(CameraUpdateSystem),
+            )
+            .insert_gizmo_config(
+                PanCamMinimapGizmos,
+                GizmoConfig {
+                    render_layers: bevy::render::view::RenderLayers::layer(
+                        PanCamMinimap::VIEW_RECT_LAYER,
+                    ),
+                    ..default()
+                },
+            )
+            .register_type::<PanCamMinimap>()
+            .register_type::<MinimapCorner>();
+
+        #[cfg(feature = "bevy_egui")]
+        app.add_systems(PostUpdate, check_egui_wants_focus);
+
+        // The events are only added by the picking plugins, which may be
+        // missing
+        #[cfg(feature = "picking")]
+        app.add_event::<bevy::picking::events::Pointer<bevy::picking::events::DragStart>>()
+            .add_event::<bevy::picking::events::Pointer<bevy::picking::events::DragEnd>>()
+            .add_systems(
+                PreUpdate,
+                block_while_picking_drags.after(bevy::picking::PickSet::Last),
+            );
     }
-}
 
-#[derive(Resource, Deref, DerefMut, PartialEq, Eq, Default)]
-#[cfg(feature = "bevy_egui")]
-struct EguiWantsFocus(bool);
+    // Registered once all plugins are added, so `ConsolePlugin` can be added
+    // before or after this one
+    #[cfg(feature = "console")]
+    fn finish(&self, app: &mut App) {
+        console::add_console_commands(app);
+    }
+}
 
 // todo: make run condition when Bevy supports mutable resources in them
 #[cfg(feature = "bevy_egui")]
 fn check_egui_wants_focus(
     mut contexts: Query<&mut bevy_egui::EguiContext>,
-    mut wants_focus: ResMut<EguiWantsFocus>,
+    mut blockers: ResMut<PanCamInputBlockers>,
+    settings: Res<PanCamSettings>,
 ) {
-    let ctx = contexts.iter_mut().next();
-    let new_wants_focus = if let Some(ctx) = ctx {
+    let policy = settings.egui_block_policy;
+    let blocked = if let Some(ctx) = contexts.iter_mut().next() {
         let ctx = ctx.into_inner().get_mut();
-        ctx.wants_pointer_input() || ctx.wants_keyboard_input()
+        BlockedInput {
+            pan: policy.pan && ctx.wants_pointer_input(),
+            zoom: policy.zoom && egui_scrolls_at_pointer(ctx),
+            keys: policy.keys && ctx.wants_keyboard_input(),
+        }
     } else {
-        false
+        BlockedInput::NONE
     };
-    wants_focus.set_if_neq(EguiWantsFocus(new_wants_focus));
+    if blockers.blocked_input("bevy_egui") != blocked {
+        blockers.set_input("bevy_egui", blocked);
+    }
+}
+
+/// Whether egui takes scrolling at the pointer, i.e. it's over a `ScrollArea`
+/// whose content doesn't fit
+///
+/// egui doesn't tell which widgets take scrolling, so this looks for the area
+/// scroll areas can be dragged by, which they only have while their content
+/// doesn't fit. Other widgets, panels and windows don't block zooming.
+///
+/// Any other widget that only senses drags looks the same, so the resize
+/// handles of resizable panels and windows, and custom drag-only widgets, also
+/// block zooming while the pointer is right over them.
+#[cfg(feature = "bevy_egui")]
+fn egui_scrolls_at_pointer(ctx: &bevy_egui::egui::Context) -> bool {
+    use bevy_egui::egui::Sense;
+
+    let hovered = ctx.interaction_snapshot(|interaction| interaction.contains_pointer.clone());
+    hovered
+        .into_iter()
+        .filter_map(|id| ctx.read_response(id))
+        // Areas and windows are moved by dragging a widget of their own
+        .any(|widget| widget.sense == Sense::drag() && widget.id != widget.layer_id.id.with("move"))
 }
 
+/// Zooms cameras with the mouse wheel
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn do_camera_zoom(
     mut query: Query<(
+        Entity,
         &PanCam,
         &Camera,
         &mut OrthographicProjection,
         &mut Transform,
+        &mut ZoomSmoothingState,
+        &ZoomFocusState,
+        Has<PanCamFollow>,
     )>,
-    scroll_events: EventReader<MouseWheel>,
-    primary_window: Query<&Window, With<PrimaryWindow>>,
+    raw_input: Query<(), RawInputFilter>,
+    mut scroll_events: EventReader<MouseWheel>,
+    mut simulated_scroll_events: EventReader<SimulatedScroll>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    keyboard_buttons: Res<ButtonInput<KeyCode>>,
+    #[cfg(all(feature = "web", target_arch = "wasm32"))] web_wheel: Res<WebWheelModifiers>,
+    mut stats: ResMut<PanCamStats>,
+    mut intended_pans: EventWriter<IntendedPan>,
+    mut intended_zooms: EventWriter<IntendedZoom>,
+    blockers: Res<PanCamInputBlockers>,
+    settings: Res<PanCamSettings>,
 ) {
-    const ZOOM_SENSITIVITY: f32 = 0.001;
-
-    let scroll_offset = scroll_offset_from_events(scroll_events);
-    if scroll_offset == 0. {
+    // Sum up scrolling per window once, so cameras in windows that weren't
+    // scrolled in are skipped early. Trackpads scroll in pixels, mouse wheels
+    // in lines.
+    let mut window_scroll = HashMap::<Entity, WindowScroll>::default();
+    for ev in scroll_events.read() {
+        let scroll = window_scroll.entry(ev.window).or_default();
+        match ev.unit {
+            MouseScrollUnit::Pixel => scroll.pixels += vec2(ev.x, ev.y),
+            MouseScrollUnit::Line => {
+                scroll.lines += vec2(ev.x, ev.y);
+                scroll.from_wheel = true;
+            }
+        }
+    }
+    let simulated_scroll: Vec<_> = simulated_scroll_events.read().collect();
+    if window_scroll.is_empty() && simulated_scroll.is_empty() {
         return;
     }
 
-    let Ok(window) = primary_window.get_single() else {
-        return;
-    };
+    let ctrl = keyboard_buttons.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    // Browsers report pinching as scrolling with control held, without a key
+    // press
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
+    let ctrl = ctrl || web_wheel.ctrl();
+    let shift = keyboard_buttons.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
 
-    for (pan_cam, camera, mut proj, mut transform) in &mut query {
-        if !pan_cam.enabled {
+    let primary_window = primary_window.get_single().ok();
+
+    for (entity, pan_cam, camera, mut proj, mut transform, mut zoom_smoothing, focus, following) in
+        &mut query
+    {
+        if !pan_cam.enabled || !camera.is_active {
             continue;
         }
 
-        let view_size = camera.logical_viewport_size().unwrap_or(window.size());
+        // Only react to scrolling in the window the camera renders to
+        let Some(window_entity) = camera_window(camera, primary_window) else {
+            continue;
+        };
+        let Ok(window) = windows.get(window_entity) else {
+            continue;
+        };
+        let pixels_per_line = pan_cam.pixels_per_line_or(&settings);
 
-        let old_scale = proj.scale;
-        proj.scale *= 1. - scroll_offset * ZOOM_SENSITIVITY;
+        // Vertical and horizontal scrolling are mapped to actions separately.
+        // Scrolling right reveals content on the right like scrolling down
+        // reveals content below, and both have negative offsets.
+        let wheel_actions = (
+            pan_cam.scroll_mapping.action(ctrl, shift),
+            pan_cam.scroll_mapping.horizontal,
+        );
+        let pixel_actions = pan_cam
+            .scroll_behavior
+            .pixel_actions(&pan_cam.scroll_mapping, ctrl);
 
-        constrain_proj_scale(
-            &mut proj,
-            pan_cam.rect().size(),
-            &pan_cam.scale_range(),
-            view_size,
+        // Simulated scrolling is handled exactly like scrolling the mouse wheel
+        // with the cursor at the given position
+        let real_scroll = window_scroll
+            .get(&window_entity)
+            .filter(|_| raw_input.contains(entity) && !blockers.camera_blocked_input(entity).zoom)
+            .filter(|_| {
+                window
+                    .cursor_position()
+                    .is_none_or(|pos| cursor_in_viewport(camera, window, pos))
+            })
+            .into_iter()
+            .flat_map(|scroll| {
+                let cursor_pos = window.cursor_position();
+                match pixel_actions {
+                    Some(pixel_actions) => vec![
+                        (
+                            scroll.lines * pixels_per_line,
+                            true,
+                            cursor_pos,
+                            wheel_actions,
+                        ),
+                        (
+                            scroll.scaled_pixels(pan_cam),
+                            false,
+                            cursor_pos,
+                            pixel_actions,
+                        ),
+                    ],
+                    None => vec![(
+                        scroll.offset(pan_cam, pixels_per_line),
+                        scroll.from_wheel,
+                        cursor_pos,
+                        wheel_actions,
+                    )],
+                }
+            });
+        let simulated_scroll = simulated_scroll
+            .iter()
+            .filter(|ev| ev.entity == entity)
+            .map(|ev| {
+                (
+                    vec2(0., ev.offset(pixels_per_line)),
+                    true,
+                    ev.cursor_pos,
+                    wheel_actions,
+                )
+            });
+
+        let scrolls = real_scroll.chain(simulated_scroll).flat_map(
+            |(offset, from_wheel, cursor_pos, (vertical_action, horizontal_action))| {
+                [
+                    (vertical_action, offset.y, from_wheel, cursor_pos),
+                    (horizontal_action, offset.x, from_wheel, cursor_pos),
+                ]
+            },
         );
 
-        let cursor_normalized_viewport_pos = window
-            .cursor_position()
-            .map(|cursor_pos| {
-                let view_pos = camera
-                    .logical_viewport_rect()
-                    .map(|v| v.min)
-                    .unwrap_or(Vec2::ZERO);
+        for (action, scroll_offset, from_wheel, cursor_pos) in scrolls {
+            if scroll_offset == 0. {
+                continue;
+            }
 
-                ((cursor_pos - view_pos) / view_size) * 2. - Vec2::ONE
-            })
-            .map(|p| vec2(p.x, -p.y));
+            let view_rect = pan_cam.view_rect(camera, window);
+            let view_size = view_rect.size();
 
-        // Move the camera position to normalize the projection window
-        let (Some(cursor_normalized_view_pos), true) =
-            (cursor_normalized_viewport_pos, pan_cam.zoom_to_cursor)
-        else {
-            continue;
-        };
+            let zoom_modifier_held = pan_cam
+                .zoom_modifier
+                .is_none_or(|key| keyboard_buttons.pressed(key));
+            let scroll_pan = match action {
+                ScrollAction::Zoom if pan_cam.zoom_enabled && zoom_modifier_held => None,
+                ScrollAction::PanVertical => Some(vec2(0., scroll_offset)),
+                // Scrolling down moves right, like in document viewers
+                ScrollAction::PanHorizontal => Some(vec2(-scroll_offset, 0.)),
+                ScrollAction::Zoom | ScrollAction::None => continue,
+            };
+
+            if let Some(scroll_pan) = scroll_pan {
+                if !pan_cam.pan_enabled {
+                    continue;
+                }
+                let proj_area_size = visible_area_size(&proj, &transform);
+                let movement = scroll_pan * proj_area_size / view_size;
+                if pan_cam.passive {
+                    intended_pans.send(IntendedPan {
+                        entity,
+                        delta: movement,
+                    });
+                    continue;
+                }
+                let distance = pan_camera(pan_cam, &mut transform, movement, proj_area_size);
+                if stats.enabled {
+                    stats.total_pan_distance += distance;
+                }
+                continue;
+            }
 
-        let proj_size = proj.area.max / old_scale;
+            let zoom_scroll = if pan_cam.invert_zoom {
+                -scroll_offset
+            } else {
+                scroll_offset
+            };
+            let zoom_scroll =
+                zoom_smoothing.scroll_past_deadzone(zoom_scroll, pan_cam.scroll_deadzone);
+            if zoom_scroll == 0. {
+                continue;
+            }
+            let zoom_factor = match &pan_cam.zoom_steps {
+                // Steps continue from the scale being smoothly zoomed to, so
+                // quickly scrolling several notches skips ahead several steps
+                Some(zoom_steps) => {
+                    let steps = zoom_smoothing.scroll_steps(zoom_scroll / pixels_per_line);
+                    if steps == 0 {
+                        continue;
+                    }
+                    let scale = zoom_smoothing.target_scale().unwrap_or(proj.scale);
+                    zoom_steps.step(scale, steps) / scale
+                }
+                None => {
+                    let sensitivity = pan_cam
+                        .zoom_sensitivity_curve
+                        .map_or(1., |curve| curve.multiplier(proj.scale));
+                    // Relative to the scale being smoothly zoomed to, like the
+                    // factor is applied to
+                    let mut zoom_factor = pan_cam.zoom_mode.zoom_factor(
+                        zoom_smoothing.target_scale().unwrap_or(proj.scale),
+                        zoom_scroll,
+                        pan_cam.zoom_sensitivity_or(&settings) * sensitivity,
+                    );
+                    if let Some(magnet) = pan_cam.integer_zoom_magnet {
+                        zoom_factor =
+                            magnet.apply(proj.scale, proj.scale * zoom_factor) / proj.scale;
+                    }
+                    zoom_factor
+                }
+            };
 
-        let cursor_world_pos =
-            transform.translation.truncate() + cursor_normalized_view_pos * proj_size * old_scale;
+            // The cursor position comes straight from the window, so anchoring
+            // stays correct when zooming over UI drawn on top of the camera's view
+            let cursor_normalized_viewport_pos = cursor_pos
+                .filter(|_| from_wheel || !pan_cam.zoom_to_cursor_wheel_only)
+                .filter(|_| !following || pan_cam.zoom_while_following == FollowZoomMode::Cursor)
+                .map(|cursor_pos| normalize_view_pos(cursor_pos, view_rect));
 
-        let proposed_cam_pos =
-            cursor_world_pos - cursor_normalized_view_pos * proj_size * proj.scale;
+            if pan_cam.passive {
+                intended_zooms.send(IntendedZoom::new(
+                    entity,
+                    pan_cam,
+                    &proj,
+                    &transform,
+                    zoom_factor,
+                    cursor_normalized_viewport_pos,
+                    focus,
+                ));
+                continue;
+            }
 
-        // As we zoom out, we don't want the viewport to move beyond the provided
-        // boundary. If the most recent change to the camera zoom would move cause
-        // parts of the window beyond the boundary to be shown, we need to change the
-        // camera position to keep the viewport within bounds.
-        transform.translation =
-            clamp_to_safe_zone(proposed_cam_pos, pan_cam.aabb(), proj.area.size())
-                .extend(transform.translation.z);
+            if pan_cam.zoom_smoothing_or(&settings) > 0. && pan_cam.zoom_axes == BVec2::TRUE {
+                let scale = zoom_smoothing.target_scale().unwrap_or(proj.scale);
+                let max_scale = max_zoom_scale(pan_cam, &proj, view_size).max(pan_cam.min_scale);
+                zoom_smoothing.zoom_towards(
+                    (scale * zoom_factor).clamp(pan_cam.min_scale, max_scale),
+                    cursor_normalized_viewport_pos,
+                    view_size,
+                );
+                continue;
+            }
+
+            let zoom_change = zoom_camera(
+                pan_cam,
+                &mut proj,
+                &mut transform,
+                zoom_factor,
+                cursor_normalized_viewport_pos,
+                focus,
+                view_size,
+            );
+
+            if stats.enabled {
+                stats.total_zoom_change += zoom_change;
+            }
+        }
     }
 }
 
-/// Consumes `MouseWheel` event reader and calculates a single scalar,
-/// representing positive or negative scroll offset.
-fn scroll_offset_from_events(mut scroll_events: EventReader<MouseWheel>) -> f32 {
-    let pixels_per_line = 100.; // Maybe make configurable?
-    scroll_events
-        .read()
-        .map(|ev| match ev.unit {
-            MouseScrollUnit::Pixel => ev.y,
-            MouseScrollUnit::Line => ev.y * pixels_per_line,
-        })
-        .sum::<f32>()
+/// Multiplies the projection scale by `zoom_factor`, within the camera's scale
+/// limits, keeping the world position at `anchor` in place if zooming to the
+/// cursor, or else the camera's `zoom_focus`. `anchor` is a normalized view
+/// position, see `normalize_view_pos`.
+///
+/// Returns the absolute change of the projection scale.
+///
+/// The projection and transform are only written to when they change, so they
+/// aren't marked as changed when zooming past the scale limits.
+fn zoom_camera(
+    pan_cam: &PanCam,
+    proj: &mut Mut<OrthographicProjection>,
+    transform: &mut Mut<Transform>,
+    zoom_factor: f32,
+    anchor: Option<Vec2>,
+    focus: &ZoomFocusState,
+    view_size: Vec2,
+) -> f32 {
+    if pan_cam.zoom_axes != BVec2::TRUE {
+        return stretch_camera(pan_cam, proj, transform, zoom_factor, anchor, focus);
+    }
+
+    let old_scale = proj.scale;
+    let cam_pos = transform.translation.truncate();
+
+    let (new_scale, mut proposed_cam_pos) = compute_zoom(ZoomParams {
+        scale: old_scale,
+        zoom_factor,
+        min_scale: pan_cam.min_scale,
+        max_scale: max_zoom_scale(pan_cam, proj, view_size),
+        anchor: pan_cam
+            .zoom_anchor(anchor, focus)
+            .map(|anchor| pan_cam.anchor_within_content(anchor, cam_pos, proj.area)),
+        area: scale_rect(proj.area, Vec2::splat(1. / old_scale)),
+        translation: cam_pos,
+    });
+    if new_scale != old_scale {
+        proj.scale = new_scale;
+    }
+
+    let zoom_change = (new_scale - old_scale).abs();
+
+    if zoom_change == 0. && proposed_cam_pos == cam_pos {
+        return zoom_change;
+    }
+
+    // The zoom moves the camera along the axes of its view, which may be
+    // rotated
+    proposed_cam_pos = cam_pos + view_to_world_offset(transform, proposed_cam_pos - cam_pos);
+    proposed_cam_pos = Vec2::select(pan_cam.pan_axes, proposed_cam_pos, cam_pos);
+    if let Some(rail) = &pan_cam.rail {
+        proposed_cam_pos = move_along_rail(rail, cam_pos, proposed_cam_pos - cam_pos);
+    }
+
+    // As we zoom out, we don't want the viewport to move beyond the provided
+    // boundary. If the most recent change to the camera zoom would move cause
+    // parts of the window beyond the boundary to be shown, we need to change the
+    // camera position to keep the viewport within bounds. The projection area is
+    // only updated later in the frame, so account for the scale change here.
+    let area_size = proj.area.size() / old_scale * proj.scale;
+    let new_cam_pos = pan_cam.clamp_position(cam_pos, proposed_cam_pos, area_size);
+    if new_cam_pos != cam_pos {
+        transform.translation = new_cam_pos.extend(transform.translation.z);
+    }
+
+    zoom_change
+}
+
+/// The largest scale zooming out may reach, from the scale limits and the
+/// limits derived from the bounds
+fn max_zoom_scale(pan_cam: &PanCam, proj: &OrthographicProjection, view_size: Vec2) -> f32 {
+    max_allowed_scale(
+        proj,
+        pan_cam.view_filling_size(),
+        pan_cam.max_scale,
+        view_size,
+        pan_cam.max_empty_fraction,
+    )
+    .min(pan_cam.max_scale_for_content(proj, view_size))
+}
+
+/// Zooms only along the camera's `zoom_axes`, by stretching its transform's
+/// scale rather than changing the projection's scale
+///
+/// Returns the absolute change of the effective scale along the zoomed axes.
+fn stretch_camera(
+    pan_cam: &PanCam,
+    proj: &OrthographicProjection,
+    transform: &mut Mut<Transform>,
+    zoom_factor: f32,
+    anchor: Option<Vec2>,
+    focus: &ZoomFocusState,
+) -> f32 {
+    let old_stretch = transform.scale.truncate();
+    let min_stretch = Vec2::splat(pan_cam.min_scale / proj.scale);
+    let max_stretch = Vec2::splat(pan_cam.max_scale / proj.scale);
+    let new_stretch = Vec2::select(
+        pan_cam.zoom_axes,
+        (old_stretch * zoom_factor)
+            .max(min_stretch)
+            .min(max_stretch),
+        old_stretch,
+    );
+    if new_stretch != old_stretch {
+        transform.scale = new_stretch.extend(transform.scale.z);
+    }
+
+    if let Some(anchor) = pan_cam.zoom_anchor(anchor, focus) {
+        let cam_pos = transform.translation.truncate();
+        let anchor_world_pos =
+            normalized_view_pos_to_world(anchor, cam_pos, scale_rect(proj.area, old_stretch));
+        let proposed_cam_pos = Vec2::select(
+            pan_cam.pan_axes,
+            anchor_world_pos
+                - normalized_view_pos_to_world(
+                    anchor,
+                    Vec2::ZERO,
+                    scale_rect(proj.area, new_stretch),
+                ),
+            cam_pos,
+        );
+        let new_cam_pos =
+            pan_cam.clamp_position(cam_pos, proposed_cam_pos, proj.area.size() * new_stretch);
+        if new_cam_pos != cam_pos {
+            transform.translation = new_cam_pos.extend(transform.translation.z);
+        }
+    }
+
+    (new_stretch - old_stretch).abs().max_element() * proj.scale
+}
+
+/// Scales both corners of a projection area
+fn scale_rect(area: Rect, scale: Vec2) -> Rect {
+    Rect {
+        min: area.min * scale,
+        max: area.max * scale,
+    }
+}
+
+/// The size of the world area shown by a camera, including any stretching from
+/// zooming along a single axis
+fn visible_area_size(proj: &OrthographicProjection, transform: &Transform) -> Vec2 {
+    proj.area.size() * transform.scale.truncate()
+}
+
+/// The inputs of [`compute_zoom`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZoomParams {
+    /// The current projection scale
+    pub scale: f32,
+    /// What to multiply the scale by, see [`scroll_zoom_factor`]
+    pub zoom_factor: f32,
+    /// The smallest scale allowed
+    pub min_scale: f32,
+    /// The largest scale allowed
+    pub max_scale: f32,
+    /// The view position to keep in place, ranging from -1 to 1 on both axes
+    /// with y pointing up
+    ///
+    /// When `None`, the camera position stays in place instead.
+    pub anchor: Option<Vec2>,
+    /// The projection's `area` at a scale of `1.`, the world area shown
+    /// relative to the camera position
+    ///
+    /// It is only centered on the camera when the projection's
+    /// `viewport_origin` is.
+    pub area: Rect,
+    /// The current camera position
+    pub translation: Vec2,
+}
+
+/// Computes the new scale and camera position for zooming by
+/// `params.zoom_factor`, without bounds
+///
+/// This is the zoom math used by `PanCam`, for use in custom zoom controls.
+/// When the scale is already at the limit it zooms towards, neither scale nor
+/// position change.
+pub fn compute_zoom(params: ZoomParams) -> (f32, Vec2) {
+    let new_scale = (params.scale * params.zoom_factor)
+        .max(params.min_scale)
+        .min(params.max_scale);
+
+    let Some(anchor) = params.anchor else {
+        return (new_scale, params.translation);
+    };
+    if new_scale == params.scale {
+        return (new_scale, params.translation);
+    }
+
+    let anchor_world_pos = normalized_view_pos_to_world(
+        anchor,
+        params.translation,
+        scale_rect(params.area, Vec2::splat(params.scale)),
+    );
+    let delta = PanCam::zoom_translation_delta(
+        params.scale,
+        new_scale,
+        anchor_world_pos,
+        params.translation,
+    );
+    (new_scale, params.translation + delta)
+}
+
+/// The zoom factor for scrolling by `scroll` pixels, where positive values
+/// zoom in, changing the scale by `sensitivity` per pixel
+pub fn scroll_zoom_factor(scroll: f32, sensitivity: f32) -> f32 {
+    1. - scroll * sensitivity
+}
+
+/// Converts a position in logical window pixels to a position relative to
+/// `view_rect`, ranging from -1 to 1 on both axes, with y pointing up.
+fn normalize_view_pos(pos: Vec2, view_rect: Rect) -> Vec2 {
+    let p = ((pos - view_rect.min) / view_rect.size()) * 2. - Vec2::ONE;
+    vec2(p.x, -p.y)
+}
+
+/// Converts a normalized view position to world space, given the camera's
+/// position and the projection's `area` in world units.
+///
+/// Any `ScalingMode` or `viewport_origin` is accounted for by the area.
+fn normalized_view_pos_to_world(normalized_view_pos: Vec2, cam_pos: Vec2, proj_area: Rect) -> Vec2 {
+    cam_pos + proj_area.center() + normalized_view_pos * proj_area.half_size()
+}
+
+/// Converts a world position to a normalized view position, the inverse of
+/// `normalized_view_pos_to_world`
+fn world_to_normalized_view_pos(world_pos: Vec2, cam_pos: Vec2, proj_area: Rect) -> Vec2 {
+    (world_pos - cam_pos - proj_area.center()) / proj_area.half_size()
+}
+
+/// Converts a normalized view position back to logical window pixels, the
+/// inverse of `normalize_view_pos`
+fn denormalize_view_pos(normalized_view_pos: Vec2, view_rect: Rect) -> Vec2 {
+    let p = vec2(normalized_view_pos.x, -normalized_view_pos.y);
+    view_rect.min + (p + Vec2::ONE) / 2. * view_rect.size()
+}
+
+/// Rotates an offset along the axes of the camera's view into world space
+fn view_to_world_offset(transform: &Transform, offset: Vec2) -> Vec2 {
+    (transform.rotation * offset.extend(0.)).truncate()
+}
+
+/// The world position shown at `pos`, in logical window pixels, by a camera
+/// rendering to `view_rect`
+fn view_pos_to_world(
+    pos: Vec2,
+    view_rect: Rect,
+    proj: &OrthographicProjection,
+    transform: &Transform,
+) -> Vec2 {
+    let offset =
+        normalized_view_pos_to_world(normalize_view_pos(pos, view_rect), Vec2::ZERO, proj.area);
+    transform.translation.truncate() + view_to_world_offset(transform, offset)
+}
+
+/// The position in logical window pixels at which a camera rendering to
+/// `view_rect` shows `world_pos`, the inverse of `view_pos_to_world`
+fn world_to_view_pos(
+    world_pos: Vec2,
+    view_rect: Rect,
+    proj: &OrthographicProjection,
+    transform: &Transform,
+) -> Vec2 {
+    let world_offset = world_pos - transform.translation.truncate();
+    let offset = (transform.rotation.inverse() * world_offset.extend(0.)).truncate();
+    denormalize_view_pos(
+        world_to_normalized_view_pos(offset, Vec2::ZERO, proj.area),
+        view_rect,
+    )
+}
+
+/// Conversions between window and world positions for `PanCam` cameras
+///
+/// These are the conversions the plugin uses itself, e.g. for zooming to the
+/// cursor, so app code agrees with it for any `ScalingMode`, viewport origin or
+/// rotation. `view_rect` is the area of the window the camera renders to, see
+/// [`PanCam::view_rect`].
+pub trait PanCamProjectionExt {
+    /// The world position shown at `screen_pos`, in logical window pixels
+    fn screen_to_world(&self, transform: &Transform, view_rect: Rect, screen_pos: Vec2) -> Vec2;
+
+    /// The position in logical window pixels at which `world_pos` is shown
+    fn world_to_screen(&self, transform: &Transform, view_rect: Rect, world_pos: Vec2) -> Vec2;
+}
+
+impl PanCamProjectionExt for OrthographicProjection {
+    fn screen_to_world(&self, transform: &Transform, view_rect: Rect, screen_pos: Vec2) -> Vec2 {
+        view_pos_to_world(screen_pos, view_rect, self, transform)
+    }
+
+    fn world_to_screen(&self, transform: &Transform, view_rect: Rect, world_pos: Vec2) -> Vec2 {
+        world_to_view_pos(world_pos, view_rect, self, transform)
+    }
+}
+
+/// The world position of the cursor for a `PanCam` camera, updated every frame
+///
+/// Uses the same conversion as zooming to the cursor, so hover logic stays
+/// consistent with zoom anchoring. `None` when the cursor is outside the window
+/// or outside the camera's view area.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Deref, Reflect)]
+#[reflect(Component)]
+pub struct CursorWorldPos(pub Option<Vec2>);
+
+/// Updates `CursorWorldPos` for every `PanCam` camera
+pub fn update_cursor_world_pos(
+    mut query: Query<(
+        &PanCam,
+        &Camera,
+        &OrthographicProjection,
+        &Transform,
+        &mut CursorWorldPos,
+    )>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    let primary_window = primary_window.get_single().ok();
+    for (pan_cam, camera, proj, transform, mut cursor_world_pos) in &mut query {
+        let window = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok());
+        let world_pos = window.and_then(|window| {
+            let view_rect = pan_cam.view_rect(camera, window);
+            let cursor_pos = window.cursor_position()?;
+            if !view_rect.contains(cursor_pos) {
+                return None;
+            }
+            Some(view_pos_to_world(cursor_pos, view_rect, proj, transform))
+        });
+        cursor_world_pos.set_if_neq(CursorWorldPos(world_pos));
+    }
+}
+
+/// What scrolling the mouse wheel does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollAction {
+    /// Zoom the camera
+    Zoom,
+    /// Pan the camera up and down
+    PanVertical,
+    /// Pan the camera left and right
+    PanHorizontal,
+    /// Ignore the scroll
+    None,
+}
+
+/// Maps scrolling with different modifier keys held to what it does
+///
+/// When both are held, control takes precedence over shift. Horizontal
+/// scrolling, from tilting the mouse wheel or swiping sideways on a trackpad,
+/// is mapped separately, regardless of modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollMapping {
+    /// Scrolling with no modifier held
+    pub plain: ScrollAction,
+    /// Scrolling with shift held
+    pub shift: ScrollAction,
+    /// Scrolling with control held
+    pub ctrl: ScrollAction,
+    /// Scrolling horizontally
+    ///
+    /// Scrolling right counts as scrolling down.
+    pub horizontal: ScrollAction,
+}
+
+impl Default for ScrollMapping {
+    /// Scrolling always zooms, and horizontal scrolling pans horizontally
+    fn default() -> Self {
+        Self {
+            plain: ScrollAction::Zoom,
+            shift: ScrollAction::Zoom,
+            ctrl: ScrollAction::Zoom,
+            horizontal: ScrollAction::PanHorizontal,
+        }
+    }
+}
+
+impl ScrollMapping {
+    /// Scrolling pans vertically, shift+scroll and horizontal scrolling pan
+    /// horizontally and ctrl+scroll zooms, like in most document viewers
+    pub fn document() -> Self {
+        Self {
+            plain: ScrollAction::PanVertical,
+            shift: ScrollAction::PanHorizontal,
+            ctrl: ScrollAction::Zoom,
+            horizontal: ScrollAction::PanHorizontal,
+        }
+    }
+
+    fn action(&self, ctrl: bool, shift: bool) -> ScrollAction {
+        if ctrl {
+            self.ctrl
+        } else if shift {
+            self.shift
+        } else {
+            self.plain
+        }
+    }
+}
+
+/// How scrolling in pixels, which comes from trackpads, is treated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollBehavior {
+    /// Like mouse wheel scrolling, mapped by the `scroll_mapping`
+    #[default]
+    Uniform,
+    /// Swiping with two fingers pans in both directions, while scrolling with
+    /// control held does what `scroll_mapping.ctrl` does, zooming by default
+    ///
+    /// Browsers report pinching on a trackpad as scrolling with control held.
+    /// Mouse wheel scrolling is still mapped by the `scroll_mapping`. Native
+    /// pinch gestures are handled by the `gestures` feature.
+    Trackpad,
+}
+
+impl ScrollBehavior {
+    /// The vertical and horizontal actions of pixel scrolling, or `None` when
+    /// it's mapped like the mouse wheel
+    fn pixel_actions(
+        &self,
+        mapping: &ScrollMapping,
+        ctrl: bool,
+    ) -> Option<(ScrollAction, ScrollAction)> {
+        match self {
+            ScrollBehavior::Uniform => None,
+            ScrollBehavior::Trackpad => {
+                if ctrl {
+                    Some((mapping.ctrl, ScrollAction::None))
+                } else {
+                    Some((ScrollAction::PanVertical, ScrollAction::PanHorizontal))
+                }
+            }
+        }
+    }
+}
+
+/// Where dragging reads the mouse's movement from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PanInputSource {
+    /// The change in cursor position, which includes the platform's pointer
+    /// acceleration, so the content stays under the cursor
+    #[default]
+    CursorPosition,
+    /// Raw `MouseMotion` events, which keep coming while the cursor is locked
+    /// or hidden with `CursorGrabMode`, e.g. in game modes
+    ///
+    /// Pointer acceleration isn't applied, so the content doesn't stay exactly
+    /// under the cursor.
+    MouseMotion,
+}
+
+/// The pixels mouse drags are measured in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DragPixelSpace {
+    /// Logical pixels, so the content stays under the cursor on any monitor
+    #[default]
+    Logical,
+    /// Physical pixels, each panning as far as a logical pixel would
+    ///
+    /// Moving the mouse the same distance then pans the same distance on
+    /// monitors with different DPI, but the content doesn't stay under the
+    /// cursor on high DPI monitors.
+    Physical,
+}
+
+/// The fastest a camera can pan, see `PanCam::max_pan_speed`
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MaxPanSpeed {
+    /// In world units per second
+    World(f32),
+    /// In screen pixels per second, so the limit is the same at any zoom
+    Screen(f32),
+}
+
+impl MaxPanSpeed {
+    /// The limit in world units per second, at projection scale `scale`
+    fn world_units_per_second(&self, scale: f32) -> f32 {
+        match *self {
+            MaxPanSpeed::World(speed) => speed,
+            MaxPanSpeed::Screen(speed) => speed * scale,
+        }
+    }
+}
+
+/// The longest frame time, in seconds, `PanCam::max_pan_speed` allows movement
+/// for
+const MAX_PAN_SPEED_FRAME_TIME: f32 = 0.1;
+
+/// Cameras controlled by the mouse and keyboard bindings of their `PanCam`,
+/// rather than by `PanCamInput` actions
+#[cfg(feature = "leafwing")]
+type RawInputFilter = Without<leafwing_input_manager::action_state::ActionState<PanCamInput>>;
+#[cfg(not(feature = "leafwing"))]
+type RawInputFilter = ();
+
+/// Returns the camera's viewport in logical pixels, or `None` when it renders
+/// to the whole window
+///
+/// The viewport is converted with the window's current scale factor rather
+/// than the one cached by the camera, which lags a frame behind when the
+/// window moves to a monitor with a different DPI.
+fn viewport_rect(camera: &Camera, window: &Window) -> Option<Rect> {
+    camera.viewport.as_ref().map(|viewport| {
+        let scale_factor = window.scale_factor();
+        Rect::from_corners(
+            viewport.physical_position.as_vec2() / scale_factor,
+            (viewport.physical_position + viewport.physical_size).as_vec2() / scale_factor,
+        )
+    })
+}
+
+/// Whether the cursor is over the camera's viewport, so cameras sharing a
+/// window side by side only react to input aimed at them
+fn cursor_in_viewport(camera: &Camera, window: &Window, cursor_pos: Vec2) -> bool {
+    viewport_rect(camera, window).is_none_or(|viewport| viewport.contains(cursor_pos))
+}
+
+/// Returns the window entity a camera renders to, if it renders to a window
+fn camera_window(camera: &Camera, primary_window: Option<Entity>) -> Option<Entity> {
+    match camera.target.normalize(primary_window)? {
+        NormalizedRenderTarget::Window(window_ref) => Some(window_ref.entity()),
+        _ => None,
+    }
+}
+
+/// Scrolling in a window during a frame
+#[derive(Default)]
+struct WindowScroll {
+    /// Mouse wheel scrolling
+    lines: Vec2,
+    /// Trackpad scrolling
+    pixels: Vec2,
+    /// Whether any of the scrolling came from a mouse wheel
+    from_wheel: bool,
+}
+
+impl WindowScroll {
+    /// The horizontal and vertical scroll offset, in pixels
+    fn offset(&self, pan_cam: &PanCam, pixels_per_line: f32) -> Vec2 {
+        self.scaled_pixels(pan_cam) + self.lines * pixels_per_line
+    }
+
+    /// The trackpad scrolling, with the camera's `pixel_scroll_multiplier`
+    fn scaled_pixels(&self, pan_cam: &PanCam) -> Vec2 {
+        self.pixels * pan_cam.pixel_scroll_multiplier
+    }
 }
 
 /// `max_scale_within_bounds` is used to find the maximum safe zoom out/projection
@@ -233,213 +1423,3592 @@ fn max_scale_within_bounds(
     proj: &OrthographicProjection,
     window_size: Vec2, //viewport?
 ) -> Vec2 {
+    bounded_area_size / base_world_size(proj, window_size)
+}
+
+/// The world size shown by a projection at scale 1 in a view of the given
+/// size, regardless of whether the projection's area has been updated yet
+fn base_world_size(proj: &OrthographicProjection, view_size: Vec2) -> Vec2 {
     let mut proj = proj.clone();
     proj.scale = 1.;
-    proj.update(window_size.x, window_size.y);
-    let base_world_size = proj.area.size();
-    bounded_area_size / base_world_size
+    proj.update(view_size.x, view_size.y);
+    proj.area.size()
 }
 
 /// Makes sure that the camera projection scale stays in the provided bounds
 /// and range.
+///
+/// `max_empty_fraction` is the fraction of the view that may be outside the
+/// bounds when zoomed out as far as the bounds allow.
 fn constrain_proj_scale(
     proj: &mut OrthographicProjection,
     bounded_area_size: Vec2,
     scale_range: &RangeInclusive<f32>,
     window_size: Vec2,
+    max_empty_fraction: f32,
 ) {
-    proj.scale = proj.scale.clamp(*scale_range.start(), *scale_range.end());
+    let max_scale = max_allowed_scale(
+        proj,
+        bounded_area_size,
+        *scale_range.end(),
+        window_size,
+        max_empty_fraction,
+    );
+    proj.scale = proj.scale.max(*scale_range.start()).min(max_scale);
+}
 
+/// The largest scale allowed by both `max_scale` and the bounds
+fn max_allowed_scale(
+    proj: &OrthographicProjection,
+    bounded_area_size: Vec2,
+    max_scale: f32,
+    window_size: Vec2,
+    max_empty_fraction: f32,
+) -> f32 {
     // If there is both a min and max boundary, that limits how far we can zoom.
     // Make sure we don't exceed that
     if bounded_area_size.x.is_finite() || bounded_area_size.y.is_finite() {
-        let max_safe_scale = max_scale_within_bounds(bounded_area_size, proj, window_size);
-        proj.scale = proj.scale.min(max_safe_scale.x).min(max_safe_scale.y);
+        let max_safe_scale = max_scale_within_bounds(bounded_area_size, proj, window_size)
+            / (1. - max_empty_fraction.clamp(0., 0.99));
+        max_scale.min(max_safe_scale.x).min(max_safe_scale.y)
+    } else {
+        max_scale
     }
 }
 
 /// Clamps a camera position to a safe zone. "Safe" means that each screen
 /// corner is constrained to the corresponding bound corner.
 ///
-/// Since bevy doesn't provide a `shrink` method on a `Rect` yet, we have to
-/// operate on `Aabb2d` type.
+/// On axes where the view is larger than the bounds, the bounds are instead
+/// kept entirely within the view.
 fn clamp_to_safe_zone(pos: Vec2, aabb: Aabb2d, bounded_area_size: Vec2) -> Vec2 {
-    let aabb = aabb.shrink(bounded_area_size / 2.);
-    pos.clamp(aabb.min, aabb.max)
+    let (min, max) = safe_zone(aabb, bounded_area_size);
+    pos.clamp(min, max)
 }
 
-pub fn do_camera_movement(
-    primary_window: Query<&Window, With<PrimaryWindow>>,
-    mouse_buttons: Res<ButtonInput<MouseButton>>,
-    keyboard_buttons: Res<ButtonInput<KeyCode>>,
-    mut query: Query<(&PanCam, &Camera, &mut Transform, &OrthographicProjection)>,
-    mut last_pos: Local<Option<Vec2>>,
-    time: Res<Time>,
+/// Returns the (min, max) corners of the safe zone, see `clamp_to_safe_zone`
+fn safe_zone(aabb: Aabb2d, bounded_area_size: Vec2) -> (Vec2, Vec2) {
+    let half_size = bounded_area_size / 2.;
+    let a = aabb.min + half_size;
+    let b = aabb.max - half_size;
+    (a.min(b), a.max(b))
+}
+
+/// Sets the scale limits of cameras spawned with
+/// [`lock_zoom_on_spawn`](PanCam::lock_zoom_on_spawn) to their scale
+fn lock_zoom_on_spawn(
+    trigger: Trigger<OnAdd, PanCam>,
+    mut query: Query<(&mut PanCam, &OrthographicProjection)>,
 ) {
-    let Ok(window) = primary_window.get_single() else {
+    let Ok((mut pan_cam, proj)) = query.get_mut(trigger.entity()) else {
         return;
     };
-    let window_size = window.size();
+    if pan_cam.lock_zoom_on_spawn {
+        pan_cam.min_scale = proj.scale;
+        pan_cam.max_scale = proj.scale;
+    }
+}
 
-    // Use position instead of MouseMotion, otherwise we don't get acceleration
-    // movement
-    let current_pos = match window.cursor_position() {
-        Some(c) => vec2(c.x, -c.y),
-        None => return,
-    };
-    let delta_device_pixels = current_pos - last_pos.unwrap_or(current_pos);
+/// Re-applies scale limits and bounds when a camera's `PanCam` settings change
+/// or the window is resized, since both change the limits derived from them
+///
+/// Cameras whose `PanCam` didn't change are skipped unless a window was resized,
+/// so this is cheap when nothing changes.
+pub fn enforce_camera_limits(
+    mut query: Query<(
+        Ref<PanCam>,
+        &Camera,
+        &mut OrthographicProjection,
+        &mut Transform,
+    )>,
+    mut resized_events: EventReader<WindowResized>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    let resized = resized_events.read().count() > 0;
+
+    let primary_window = primary_window.get_single().ok();
 
-    for (pan_cam, camera, mut transform, projection) in &mut query {
-        if !pan_cam.enabled {
+    for (pan_cam, camera, mut proj, mut transform) in &mut query {
+        if !resized && !pan_cam.is_changed() {
             continue;
         }
 
-        let proj_area_size = projection.area.size();
-
-        let mouse_delta = if !pan_cam
-            .grab_buttons
-            .iter()
-            .any(|btn| mouse_buttons.pressed(*btn) && !mouse_buttons.just_pressed(*btn))
-        {
-            Vec2::ZERO
-        } else {
-            let viewport_size = camera.logical_viewport_size().unwrap_or(window_size);
-            delta_device_pixels * proj_area_size / viewport_size
+        let Some(window) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
         };
 
-        let direction = pan_cam.move_keys.direction(&keyboard_buttons);
-
-        let keyboard_delta =
-            time.delta_secs() * direction.normalize_or_zero() * pan_cam.speed * projection.scale;
-        let delta = mouse_delta - keyboard_delta;
+        let view_size = pan_cam.view_rect(camera, window).size();
+        let old_scale = proj.scale;
+        let mut scale = proj.clone();
+        pan_cam.constrain_scale(&mut scale, view_size);
+        if scale.scale != old_scale {
+            proj.scale = scale.scale;
+        }
+
+        // The projection area is only updated later in the frame, so account
+        // for the scale change here
+        let area_size = proj.area.size() / old_scale * proj.scale;
+        let cam_pos = transform.translation.truncate();
+        let clamped = pan_cam.clamp_position(cam_pos, cam_pos, area_size);
+        if clamped != cam_pos {
+            transform.translation = clamped.extend(transform.translation.z);
+        }
+    }
+}
+
+/// Drops the in-progress input of cameras that were just deactivated, so they
+/// don't resume a drag, glide or zoom when activated again
+#[allow(clippy::type_complexity)]
+pub fn pause_inactive_cameras(
+    mut query: Query<
+        (
+            &Camera,
+            &mut GrabState,
+            &mut EdgeScrollState,
+            &mut InertiaState,
+            &mut ZoomSmoothingState,
+            &mut PageState,
+        ),
+        Changed<Camera>,
+    >,
+) {
+    for (
+        camera,
+        mut grab_state,
+        mut edge_scroll_state,
+        mut inertia_state,
+        mut zoom_smoothing,
+        mut page_state,
+    ) in &mut query
+    {
+        if camera.is_active {
+            continue;
+        }
+        *grab_state = GrabState::default();
+        *edge_scroll_state = EdgeScrollState::default();
+        inertia_state.stop();
+        *zoom_smoothing = ZoomSmoothingState::default();
+        *page_state = PageState::default();
+    }
+}
+
+/// Run condition for [`do_camera_movement`], which has nothing to do without
+/// mouse or keyboard buttons held or just released, unless a camera scrolls
+/// at the edges of its view
+///
+/// Button input isn't tied to a window, so this only skips frames without any
+/// input. Cameras in windows without focus are skipped in the system itself.
+///
+/// Skipping frames without input is safe, as a drag never moves the camera on
+/// the frame its button is pressed, when the last cursor position is caught up.
+fn movement_input_present(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    keyboard_buttons: Res<ButtonInput<KeyCode>>,
+    cameras: Query<&PanCam>,
+) -> bool {
+    mouse_buttons.get_pressed().next().is_some()
+        || mouse_buttons.get_just_released().next().is_some()
+        || keyboard_buttons.get_pressed().next().is_some()
+        || cameras.iter().any(|pan_cam| pan_cam.edge_scroll.is_some())
+}
+
+/// Pans cameras by dragging with the grab buttons and with the move keys
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn do_camera_movement(
+    mut windows: Query<(Entity, &mut Window)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    keyboard_buttons: Res<ButtonInput<KeyCode>>,
+    mut query: Query<
+        (
+            Entity,
+            &PanCam,
+            &mut GrabState,
+            &mut EdgeScrollState,
+            &mut InertiaState,
+            &mut OverscrollState,
+            &Camera,
+            &mut Transform,
+            &OrthographicProjection,
+        ),
+        RawInputFilter,
+    >,
+    mut last_pos: Local<HashMap<Entity, (Vec2, f32)>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    time: Res<Time>,
+    mut stats: ResMut<PanCamStats>,
+    mut intended_pans: EventWriter<IntendedPan>,
+    mut pans_started: EventWriter<PanStarted>,
+    mut pans_ended: EventWriter<PanEnded>,
+    blockers: Res<PanCamInputBlockers>,
+    settings: Res<PanCamSettings>,
+) {
+    let primary_window = primary_window.get_single().ok();
+
+    // Mouse motion has y pointing down like window coordinates
+    let motion: Vec2 = mouse_motion.read().map(|ev| ev.delta).sum();
+    let motion = vec2(motion.x, -motion.y);
+
+    let mut wrapping_windows = Vec::new();
+
+    let buttons_active = mouse_buttons.get_pressed().next().is_some()
+        || mouse_buttons.get_just_released().next().is_some()
+        || keyboard_buttons.get_pressed().next().is_some();
+
+    for (
+        entity,
+        pan_cam,
+        mut grab_state,
+        mut edge_scroll_state,
+        mut inertia_state,
+        mut overscroll_state,
+        camera,
+        mut transform,
+        projection,
+    ) in &mut query
+    {
+        if !pan_cam.enabled || !camera.is_active || !pan_cam.pan_enabled {
+            continue;
+        }
+
+        // Each camera follows the cursor in the window it renders to
+        let Some((window_entity, window)) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+
+        let view_rect = pan_cam.view_rect(camera, window);
+
+        // The cursor position includes pointer acceleration, unlike
+        // `MouseMotion`, but isn't available while the cursor is locked
+        let (cursor_pos, delta_device_pixels) = match pan_cam.pan_input {
+            PanInputSource::CursorPosition => {
+                let Some(cursor_pos) = window.cursor_position() else {
+                    continue;
+                };
+                let current_pos = vec2(cursor_pos.x, -cursor_pos.y);
+                // Logical positions change without the cursor moving when the
+                // scale factor changes, e.g. on a monitor with a different DPI
+                let last = last_pos
+                    .get(&window_entity)
+                    .filter(|(_, scale_factor)| *scale_factor == window.scale_factor())
+                    .map_or(current_pos, |(pos, _)| *pos);
+                let delta = match pan_cam.drag_pixel_space {
+                    DragPixelSpace::Logical => current_pos - last,
+                    DragPixelSpace::Physical => (current_pos - last) * window.scale_factor(),
+                };
+                (cursor_pos, delta)
+            }
+            PanInputSource::MouseMotion => (
+                window.cursor_position().unwrap_or(view_rect.center()),
+                motion,
+            ),
+        };
+
+        // Without any of this input, only edge scrolling can move a camera that
+        // isn't already being dragged, so other cameras are skipped early.
+        // Buttons and motion only reach the focused window, so cameras in
+        // other windows are skipped too.
+        let input_active = window.focused && (delta_device_pixels != Vec2::ZERO || buttons_active);
+        if !input_active && !grab_state.grabbing && pan_cam.edge_scroll.is_none() {
+            continue;
+        }
+
+        let proj_area_size = visible_area_size(projection, &transform);
+        let blocked = blockers.camera_blocked_input(entity);
+
+        // A press outside the camera's viewport, or while pointer panning is
+        // blocked, can't grab it until the grab buttons are released, even if
+        // the cursor is dragged into it
+        if !pan_cam
+            .grab_buttons
+            .any_pressed(&mouse_buttons, &keyboard_buttons)
+        {
+            grab_state.blocked = false;
+        } else if !grab_state.grabbing
+            && pan_cam
+                .grab_buttons
+                .any_just_pressed(&mouse_buttons, &keyboard_buttons)
+            && (!cursor_in_viewport(camera, window, cursor_pos) || blocked.pan)
+        {
+            grab_state.blocked = true;
+        }
+
+        let was_grabbing = grab_state.grabbing;
+        let grabbing = if grab_state.blocked {
+            grab_state.grabbing = false;
+            false
+        } else {
+            grab_state.update(
+                &pan_cam.grab_buttons,
+                pan_cam.release_ends_grab,
+                &mouse_buttons,
+                &keyboard_buttons,
+            )
+        };
+        if grabbing {
+            grab_state.held += time.delta();
+        } else if !grab_state.grabbing {
+            grab_state.held = Duration::ZERO;
+            grab_state.dragged_pixels = Vec2::ZERO;
+            grab_state.dragged_world = Vec2::ZERO;
+            if grab_state.panning {
+                grab_state.panning = false;
+                pans_ended.send(PanEnded { entity });
+            }
+        }
+        let grabbing = grabbing && grab_state.held >= pan_cam.pan_hold_delay;
+        if grabbing && pan_cam.wrap_cursor {
+            wrapping_windows.push(window_entity);
+        }
+
+        let drag_delta = delta_device_pixels * proj_area_size / view_rect.size();
+        let drag_delta = if pan_cam.invert_drag {
+            -drag_delta
+        } else {
+            drag_delta
+        };
+        let mouse_delta =
+            if grabbing && grab_state.past_deadzone(pan_cam, delta_device_pixels, drag_delta) {
+                if !grab_state.panning {
+                    grab_state.panning = true;
+                    pans_started.send(PanStarted { entity });
+                }
+                drag_delta * pan_cam.pan_speed
+            } else {
+                Vec2::ZERO
+            };
+
+        // Grabbing stops any glide, and releasing a drag starts one
+        if let Some(inertia) = pan_cam.inertia {
+            if grabbing {
+                inertia_state.record(time.delta_secs(), -mouse_delta, inertia.sample_time, false);
+            } else if grab_state.grabbing {
+                inertia_state.stop();
+            } else if was_grabbing {
+                inertia_state.release();
+            }
+        }
+
+        let direction = if pan_cam.key_focus.allows(camera, window) && !blocked.keys {
+            pan_cam.move_keys.direction(&keyboard_buttons)
+        } else {
+            Vec2::ZERO
+        };
+
+        let keyboard_delta = time.delta_secs()
+            * direction.normalize_or_zero()
+            * pan_cam.speed
+            * pan_cam.key_pan_speed
+            * projection.scale;
+
+        let edge_scroll_delta = match pan_cam.edge_scroll {
+            Some(edge_scroll) => {
+                let target = if blocked.pan {
+                    Vec2::ZERO
+                } else {
+                    edge_scroll.target_velocity(cursor_pos, view_rect)
+                };
+                edge_scroll_state.velocity = if settings.reduced_motion {
+                    target
+                } else {
+                    edge_scroll.ramp(edge_scroll_state.velocity, target, time.delta_secs())
+                };
+                time.delta_secs()
+                    * edge_scroll_state.velocity
+                    * edge_scroll.speed
+                    * pan_cam.key_pan_speed
+                    * projection.scale
+            }
+            None => Vec2::ZERO,
+        };
+
+        // Dragging, keyboard panning and edge scrolling add up to a single
+        // movement, which is then clamped to the bounds once
+        let delta = pan_cam.limit_pan_speed(
+            mouse_delta - keyboard_delta - edge_scroll_delta,
+            time.delta_secs(),
+            projection.scale,
+        );
 
         if delta == Vec2::ZERO {
             continue;
         }
 
-        // The proposed new camera position
-        let proposed_cam_pos = transform.translation.truncate() - delta;
+        if pan_cam.passive {
+            intended_pans.send(IntendedPan {
+                entity,
+                delta: -delta,
+            });
+            continue;
+        }
+
+        let distance = match pan_cam.overscroll {
+            Some(overscroll) if grabbing && pan_cam.rail.is_none() => pan_camera_elastic(
+                pan_cam,
+                &overscroll,
+                &mut overscroll_state,
+                &mut transform,
+                -delta,
+                proj_area_size,
+            ),
+            _ => pan_camera(pan_cam, &mut transform, -delta, proj_area_size),
+        };
+
+        if stats.enabled {
+            stats.total_pan_distance += distance;
+        }
+    }
+
+    for (window_entity, mut window) in &mut windows {
+        let Some(mut cursor_pos) = window.cursor_position() else {
+            continue;
+        };
+        // The warp isn't cursor movement, so the next frame's drag continues
+        // from the warped position
+        if wrapping_windows.contains(&window_entity) {
+            let wrapped = wrap_cursor(cursor_pos, window.size());
+            if wrapped != cursor_pos {
+                window.set_cursor_position(Some(wrapped));
+                cursor_pos = wrapped;
+            }
+        }
+        last_pos.insert(
+            window_entity,
+            (vec2(cursor_pos.x, -cursor_pos.y), window.scale_factor()),
+        );
+    }
+}
+
+/// How close to a window edge, in logical pixels, the cursor wraps around to
+/// the opposite edge when `wrap_cursor` is set
+const CURSOR_WRAP_MARGIN: f32 = 1.;
+
+/// Moves a cursor at an edge of a window of `window_size` to the opposite edge
+fn wrap_cursor(pos: Vec2, window_size: Vec2) -> Vec2 {
+    let wrap = |pos: f32, size: f32| {
+        if pos <= CURSOR_WRAP_MARGIN {
+            size - CURSOR_WRAP_MARGIN - 1.
+        } else if pos >= size - CURSOR_WRAP_MARGIN {
+            CURSOR_WRAP_MARGIN + 1.
+        } else {
+            pos
+        }
+    };
+    vec2(wrap(pos.x, window_size.x), wrap(pos.y, window_size.y))
+}
+
+/// Moves the camera by `movement` in world units along the axes of its view,
+/// following its rail and staying within its bounds. Returns the distance
+/// actually moved.
+///
+/// The transform is only marked as changed if the camera actually moved.
+pub(crate) fn pan_camera(
+    pan_cam: &PanCam,
+    transform: &mut Mut<Transform>,
+    movement: Vec2,
+    proj_area_size: Vec2,
+) -> f32 {
+    let old_cam_pos = transform.translation.truncate();
+    let new_cam_pos = panned_position(pan_cam, transform, movement, proj_area_size);
+    if new_cam_pos != old_cam_pos {
+        transform.translation = new_cam_pos.extend(transform.translation.z);
+    }
+    new_cam_pos.distance(old_cam_pos)
+}
+
+/// The position `pan_camera` would move the camera to, without moving it
+pub(crate) fn panned_position(
+    pan_cam: &PanCam,
+    transform: &Transform,
+    movement: Vec2,
+    proj_area_size: Vec2,
+) -> Vec2 {
+    // The proposed new camera position
+    let old_cam_pos = transform.translation.truncate();
+    let (safe_min, safe_max) = safe_zone(pan_cam.aabb(proj_area_size), proj_area_size);
+    let movement = view_to_world_offset(transform, movement);
+    let movement = Vec2::select(pan_cam.pan_axes, movement, Vec2::ZERO);
+    let movement = pan_cam
+        .edge_softness
+        .resist(old_cam_pos, movement, safe_min, safe_max);
+    let proposed_cam_pos = match &pan_cam.rail {
+        Some(rail) => move_along_rail(rail, old_cam_pos, movement),
+        None => old_cam_pos + movement,
+    };
+    pan_cam.clamp_position(old_cam_pos, proposed_cam_pos, proj_area_size)
+}
+
+/// Moves `pos` along the polyline `rail` by the component of `delta` that is
+/// tangential to the rail segment closest to `pos`.
+///
+/// The result always lies on the rail, and movement stops at the rail's ends.
+/// An empty rail leaves the movement unconstrained.
+fn move_along_rail(rail: &[Vec2], pos: Vec2, delta: Vec2) -> Vec2 {
+    let Some(&first) = rail.first() else {
+        return pos + delta;
+    };
+
+    // Find the closest point on the rail, as a distance along it
+    let mut closest = (f32::INFINITY, 0., Vec2::ZERO);
+    let mut length = 0.;
+    for segment in rail.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let segment_vec = end - start;
+        let segment_length = segment_vec.length();
+        let t = if segment_length > 0. {
+            ((pos - start).dot(segment_vec) / segment_length.powi(2)).clamp(0., 1.)
+        } else {
+            0.
+        };
+        let distance = pos.distance(start + segment_vec * t);
+        if distance < closest.0 {
+            let tangent = segment_vec.normalize_or_zero();
+            closest = (distance, length + t * segment_length, tangent);
+        }
+        length += segment_length;
+    }
+
+    let (_, along, tangent) = closest;
+    let target = (along + delta.dot(tangent)).clamp(0., length);
+
+    // Walk the rail to the target distance
+    let mut remaining = target;
+    for segment in rail.windows(2) {
+        let segment_length = segment[0].distance(segment[1]);
+        if remaining <= segment_length && segment_length > 0. {
+            return segment[0].lerp(segment[1], remaining / segment_length);
+        }
+        remaining -= segment_length;
+    }
+    *rail.last().unwrap_or(&first)
+}
+
+/// How close to each bound panning starts to slow down, in world units
+///
+/// Within this distance of a bound, panning towards it slows down the closer
+/// the camera gets, so the edge feels soft. `0.` makes an edge hard, stopping
+/// the camera abruptly at the bound.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeSoftness {
+    /// Softness of the `min_x` bound
+    pub left: f32,
+    /// Softness of the `max_x` bound
+    pub right: f32,
+    /// Softness of the `min_y` bound
+    pub bottom: f32,
+    /// Softness of the `max_y` bound
+    pub top: f32,
+}
+
+impl EdgeSoftness {
+    /// Scales down `movement` from `pos` on axes where it moves towards an edge
+    /// of the safe zone, in proportion to how far into the soft band it is
+    fn resist(&self, pos: Vec2, movement: Vec2, safe_min: Vec2, safe_max: Vec2) -> Vec2 {
+        let resist_axis =
+            |movement: f32, to_min: f32, to_max: f32, min_band: f32, max_band: f32| {
+                let (distance, band) = if movement < 0. {
+                    (to_min, min_band)
+                } else {
+                    (to_max, max_band)
+                };
+                if band > 0. {
+                    movement * (distance / band).clamp(0., 1.)
+                } else {
+                    movement
+                }
+            };
+        vec2(
+            resist_axis(
+                movement.x,
+                pos.x - safe_min.x,
+                safe_max.x - pos.x,
+                self.left,
+                self.right,
+            ),
+            resist_axis(
+                movement.y,
+                pos.y - safe_min.y,
+                safe_max.y - pos.y,
+                self.bottom,
+                self.top,
+            ),
+        )
+    }
+}
+
+/// Extra space around the bounds that the camera can pan into, e.g. to show a
+/// gutter around a document
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BoundsMargin {
+    /// A margin in world units
+    World(f32),
+    /// A margin as a fraction of the visible area's size, so it takes up the
+    /// same part of the screen at any zoom
+    View(f32),
+}
+
+impl BoundsMargin {
+    /// The margin in world units on each axis, for a camera showing an area of
+    /// `proj_area_size`
+    fn world_size(&self, proj_area_size: Vec2) -> Vec2 {
+        match *self {
+            BoundsMargin::World(margin) => Vec2::splat(margin),
+            BoundsMargin::View(fraction) => fraction * proj_area_size,
+        }
+    }
+}
+
+/// Varies zoom sensitivity with how far the current scale is from a
+/// comfortable reference scale
+///
+/// Far from the reference, zoom steps are large so the user gets there
+/// quickly, while close to it they get finer so it's easy to land precisely.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZoomSensitivityCurve {
+    /// The scale at which zooming is the finest
+    pub reference_scale: f32,
+    /// Sensitivity multiplier at the reference scale
+    pub near: f32,
+    /// Sensitivity multiplier approached far away from the reference scale
+    pub far: f32,
+    /// How quickly sensitivity goes from `near` to `far`
+    ///
+    /// Distance from the reference scale is measured as the factor between the
+    /// two, so with the default of `1.` the sensitivity has covered about two
+    /// thirds of the way to `far` when the scale is `e` (~2.7) times larger or
+    /// smaller than the reference.
+    pub falloff: f32,
+}
+
+impl ZoomSensitivityCurve {
+    /// The sensitivity multiplier to use at the given projection scale
+    pub fn multiplier(&self, scale: f32) -> f32 {
+        let distance = (scale / self.reference_scale).ln().abs();
+        let t = 1. - (-distance / self.falloff.max(f32::EPSILON)).exp();
+        self.near + (self.far - self.near) * t
+    }
+}
+
+impl Default for ZoomSensitivityCurve {
+    fn default() -> Self {
+        Self {
+            reference_scale: 1.,
+            near: 0.25,
+            far: 2.,
+            falloff: 1.,
+        }
+    }
+}
+
+/// How scrolling changes the projection scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZoomMode {
+    /// The scale changes by `zoom_sensitivity` times itself per pixel, so
+    /// zooming in and back out by the same amount doesn't quite return to the
+    /// same scale
+    #[default]
+    Multiplicative,
+    /// The scale changes by `zoom_sensitivity` per pixel regardless of the
+    /// current scale, e.g. for node editors with a narrow zoom range
+    ///
+    /// A single step zooming in at most halves the scale, so steps larger
+    /// than the scale itself can't make it zero or negative.
+    Linear,
+    /// Each pixel multiplies the scale by the same factor, so zooming in and
+    /// back out by the same amount returns to the same scale, e.g. for maps
+    Logarithmic,
+}
+
+impl ZoomMode {
+    /// The zoom factor for scrolling by `scroll` pixels at the given scale,
+    /// where positive values zoom in
+    pub fn zoom_factor(&self, scale: f32, scroll: f32, sensitivity: f32) -> f32 {
+        match self {
+            ZoomMode::Multiplicative => scroll_zoom_factor(scroll, sensitivity),
+            ZoomMode::Linear => {
+                ((scale - scroll * sensitivity) / scale).max(MIN_LINEAR_ZOOM_FACTOR)
+            }
+            ZoomMode::Logarithmic => (-scroll * sensitivity).exp(),
+        }
+    }
+}
+
+/// The smallest zoom factor of a single `ZoomMode::Linear` step
+const MIN_LINEAR_ZOOM_FACTOR: f32 = 0.5;
+
+/// Makes zooming sticky at integer pixel ratios, e.g. 1x or 2x, so it's easy to
+/// settle on a clean ratio
+///
+/// The pixel ratio is `1 / scale`, i.e. how many logical pixels a world unit
+/// takes up with the default `ScalingMode::WindowSize`. Slow zoom steps that
+/// approach an integer ratio and end near it, or cross it, land exactly on it.
+/// Faster steps, and steps moving away from the ratio, pass through unaffected.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntegerZoomMagnet {
+    /// How close to an integer ratio a zoom step must end to be captured
+    pub band: f32,
+    /// The largest change of the pixel ratio in a single frame that is still
+    /// captured
+    ///
+    /// Zooming faster than this passes through integer ratios.
+    pub strength: f32,
+}
+
+impl IntegerZoomMagnet {
+    /// Returns the scale to use when zooming from `old_scale` to `new_scale`
+    fn apply(&self, old_scale: f32, new_scale: f32) -> f32 {
+        let old_ratio = old_scale.recip();
+        let new_ratio = new_scale.recip();
+        if (new_ratio - old_ratio).abs() > self.strength {
+            return new_scale;
+        }
+
+        // Only capture steps heading towards the ratio, so continued slow
+        // zooming can leave it again
+        let nearest = new_ratio.round().max(1.);
+        let crossed = (old_ratio - nearest) * (new_ratio - nearest) < 0.;
+        let approaching = (new_ratio - nearest).abs() < (old_ratio - nearest).abs();
+        if crossed || (approaching && (new_ratio - nearest).abs() < self.band) {
+            nearest.recip()
+        } else {
+            new_scale
+        }
+    }
+}
+
+impl Default for IntegerZoomMagnet {
+    fn default() -> Self {
+        Self {
+            band: 0.05,
+            strength: 0.1,
+        }
+    }
+}
+
+/// Accumulated pan and zoom totals across all `PanCam` cameras, e.g. for
+/// usage analytics
+///
+/// Nothing is recorded unless `enabled` is set. Insert the resource before
+/// adding the plugin to enable it from the start, or flip the flag at runtime.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct PanCamStats {
+    /// Whether the totals are updated
+    pub enabled: bool,
+    /// Total distance the cameras have been panned, in world units
+    pub total_pan_distance: f32,
+    /// Total absolute change of the cameras' projection scale
+    pub total_zoom_change: f32,
+}
 
-        transform.translation =
-            clamp_to_safe_zone(proposed_cam_pos, pan_cam.aabb(), proj_area_size)
-                .extend(transform.translation.z);
+impl PanCamStats {
+    /// Resets the totals to zero, leaving `enabled` untouched
+    pub fn reset(&mut self) {
+        self.total_pan_distance = 0.;
+        self.total_zoom_change = 0.;
     }
-    *last_pos = Some(current_pos);
 }
 
 /// A component that adds panning camera controls to an orthographic camera
+///
+/// Cameras can be spawned at any point during the app's lifetime. Per-camera
+/// state used by the plugin is added as required components, so it is created
+/// alongside `PanCam` and despawned together with the camera.
 #[derive(Component, Reflect, Clone)]
-#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[reflect(Component, Default)]
+#[require(
+    GrabState,
+    DoubleClickState,
+    EdgeScrollState,
+    InertiaState,
+    ZoomSmoothingState,
+    AnimationState,
+    FitOnStartupState,
+    PageState,
+    PanDeltaState,
+    ResizeState,
+    ReadyState,
+    CursorWorldPos,
+    BoundaryContact,
+    PanCamState,
+    VisibleWorldRect,
+    PanCamInteraction,
+    PanCamSnapshotStack,
+    PanCamBookmarks,
+    ViewChangeState,
+    PixelSnapState,
+    OverscrollState,
+    ZoomFocusState
+)]
 pub struct PanCam {
-    /// The mouse buttons that will be used to drag and pan the camera
-    pub grab_buttons: Vec<MouseButton>,
+    /// The mouse buttons that will be used to drag and pan the camera,
+    /// optionally only while a key is held
+    ///
+    /// While any of them is held the camera keeps panning, so pressing or
+    /// releasing additional grab buttons mid-drag doesn't interrupt the pan.
+    /// Releasing the key of a chord releases it.
+    pub grab_buttons: GrabButtons,
+    /// When true, releasing any of the `grab_buttons` ends the pan, even if
+    /// other grab buttons are still held
+    ///
+    /// A new pan then only starts when a grab button is pressed again.
+    pub release_ends_grab: bool,
+    /// When true, dragging moves the camera along with the cursor, pushing the
+    /// world away, rather than grabbing the world and moving it with the cursor
+    pub invert_drag: bool,
+    /// Where dragging reads the mouse's movement from
+    ///
+    /// By default, it follows the cursor position, which isn't available while
+    /// the application locks the cursor.
+    pub pan_input: PanInputSource,
+    /// The pixels dragging with the cursor is measured in
+    ///
+    /// By default, the content stays under the cursor.
+    pub drag_pixel_space: DragPixelSpace,
+    /// How long a grab button must be held before dragging pans the camera
+    ///
+    /// Quick drags that are released before this don't pan at all, e.g. so they
+    /// can be used for selection instead.
+    pub pan_hold_delay: Duration,
+    /// How far the cursor must be dragged, in logical pixels, before the camera
+    /// starts panning
+    ///
+    /// Small unintended movements while clicking then don't pan the camera.
+    /// [`PanStarted`] is sent once a drag moves past it.
+    pub pan_deadzone: f32,
+    /// Like `pan_deadzone`, but in world units, so it covers the same part of
+    /// the world at any zoom level
+    ///
+    /// When greater than `0.`, it's used instead of `pan_deadzone`.
+    pub pan_deadzone_world: f32,
     /// The keyboard keys that will be used to move the camera
     pub move_keys: DirectionKeys,
+    /// The keyboard keys that pan the camera by a page, i.e. about the size of
+    /// its view, per press
+    ///
+    /// Defaults to no keys, see [`DirectionKeys::page_up_down`].
+    pub page_keys: DirectionKeys,
+    /// The fraction of the view still visible after paging
+    ///
+    /// With the default of `0.1`, a page moves the camera by 90% of the view's
+    /// size, so some of the previous view remains visible for orientation.
+    pub page_overlap: f32,
+    /// How long paging animates the camera for, in seconds
+    pub page_duration: f32,
+    /// The keyboard keys that zoom the camera while held
+    ///
+    /// Defaults to no keys, see [`ZoomKeys::plus_minus`].
+    pub zoom_keys: ZoomKeys,
+    /// How many times per second holding a zoom key halves or doubles the
+    /// projection scale
+    pub key_zoom_speed: f32,
+    /// When true, zooming with the keyboard is towards the cursor rather than
+    /// the middle of the screen
+    ///
+    /// Has no effect unless `zoom_focus` is `ZoomFocus::Cursor`.
+    pub key_zoom_to_cursor: bool,
+    /// Which cameras react to `move_keys`, `page_keys`, `zoom_keys`,
+    /// `rotate_keys`, the gamepad, `PanCamInput` pan and zoom actions and
+    /// touches, e.g. only the hovered one in split-screen setups
+    ///
+    /// Defaults to `KeyFocus::Always`, so every camera reacts; split-screen
+    /// setups have to opt in on each camera.
+    pub key_focus: KeyFocus,
+    /// The keyboard keys that rotate the camera while held
+    pub rotate_keys: RotateKeys,
+    /// How fast holding a rotate key rotates the camera, in radians per second
+    pub rotate_speed: f32,
+    /// When true, rotating with the keyboard is around the cursor rather than
+    /// the middle of the screen
+    pub rotate_to_cursor: bool,
     /// Speed for keyboard movement
     ///
     /// This is multiplied with the projection scale of the camera so the
     /// speed stays proportional to the current "zoom" level
     pub speed: f32,
+    /// Multiplies how far dragging pans, with `1.` keeping the content under
+    /// the cursor or finger
+    ///
+    /// Lower values make panning feel heavier, e.g. `0.5` for precise nudging.
+    pub pan_speed: f32,
+    /// Multiplies how fast keyboard panning and edge scrolling pan
+    pub key_pan_speed: f32,
+    /// The fastest dragging, keyboard panning, edge scrolling, gliding and the
+    /// gamepad can pan the camera
+    ///
+    /// Keeps the view from jumping when a hitch or the window regaining focus
+    /// delivers a large cursor movement at once. Frames longer than a tenth
+    /// of a second are limited like a tenth of a second. `None` doesn't limit
+    /// it.
+    pub max_pan_speed: Option<MaxPanSpeed>,
     /// Whether camera currently responds to user input
     pub enabled: bool,
-    /// When true, zooming the camera will center on the mouse cursor
+    /// When true, the camera isn't moved or zoomed by user input; instead
+    /// [`IntendedPan`] and [`IntendedZoom`] events are sent, for applying the
+    /// changes with a custom camera controller
+    pub passive: bool,
+    /// Whether the camera can be panned, by dragging or with the keyboard
+    pub pan_enabled: bool,
+    /// The axes panning applies to
+    ///
+    /// Disabling an axis keeps the camera from moving along it when panning
+    /// or zooming towards the cursor, e.g. for side-scroller level editors or
+    /// timelines. The bounds can still move the camera along it.
+    pub pan_axes: BVec2,
+    /// Whether the camera can be zoomed
+    pub zoom_enabled: bool,
+    /// What zooming keeps in place on screen
     ///
-    /// When false, the camera will stay in place, zooming towards the
-    /// middle of the screen
+    /// By default, the world position under the cursor stays in place. This
+    /// replaces `zoom_to_cursor`: `zoom_to_cursor: false` is now
+    /// `zoom_focus: ZoomFocus::ScreenCenter`.
+    pub zoom_focus: ZoomFocus,
+    /// When false, zooming is towards the middle of the screen even if
+    /// `zoom_focus` is `ZoomFocus::Cursor`
+    ///
+    /// Has no effect on other focuses.
+    #[deprecated(
+        since = "0.16.0",
+        note = "use `zoom_focus: ZoomFocus::ScreenCenter` instead"
+    )]
     pub zoom_to_cursor: bool,
+    /// When true, only mouse wheel scrolling zooms to the cursor, while
+    /// trackpad scrolling zooms towards the middle of the screen
+    ///
+    /// Scrolling is considered to come from a trackpad when it is reported in
+    /// pixels rather than lines. Has no effect unless `zoom_focus` is
+    /// `ZoomFocus::Cursor`.
+    pub zoom_to_cursor_wheel_only: bool,
+    /// How strongly zooming to the cursor is anchored at the cursor, from `0.`
+    /// to `1.`
+    ///
+    /// With `1.`, the world position under the cursor stays in place, while with
+    /// `0.` zooming is towards the middle of the screen. Values in between keep
+    /// the point that far from the middle towards the cursor in place. Has no
+    /// effect unless `zoom_focus` is `ZoomFocus::Cursor`.
+    pub zoom_to_cursor_strength: f32,
+    /// When true, scrolling down zooms in rather than out
+    pub invert_zoom: bool,
+    /// About how many seconds zooming with the mouse wheel takes to reach the
+    /// zoom level scrolled to
+    ///
+    /// After this time, 99% of the zoom has been applied, always keeping the
+    /// world position under the cursor in place. `0.` zooms instantly. Has no
+    /// effect when zooming only some of the `zoom_axes`. `None` uses
+    /// [`PanCamSettings::zoom_smoothing`].
+    pub zoom_smoothing: Option<f32>,
+    /// How zooming works while the camera follows an entity with a
+    /// [`PanCamFollow`]
+    pub zoom_while_following: FollowZoomMode,
+    /// The axes zooming applies to
+    ///
+    /// By default both axes zoom together, by changing the projection's scale.
+    /// When only one axis is enabled, zooming instead stretches the camera's
+    /// transform scale along it, e.g. to zoom a timeline horizontally while
+    /// keeping its vertical extent. In that case `min_scale` and `max_scale`
+    /// limit the effective scale along the zoomed axis, bounds are applied to
+    /// the stretched view, and other features that size the view, such as
+    /// fitting to content or the bounds limiting the zoom, ignore the stretch.
+    pub zoom_axes: BVec2,
+    /// What scrolling does depending on the modifier keys held
+    ///
+    /// By default, scrolling always zooms.
+    pub scroll_mapping: ScrollMapping,
+    /// How trackpad scrolling, reported in pixels rather than lines, is
+    /// treated
+    ///
+    /// By default, it's mapped like mouse wheel scrolling.
+    pub scroll_behavior: ScrollBehavior,
+    /// A key that has to be held for scrolling to zoom, e.g. control
+    ///
+    /// Without it held, scrolling that would zoom is left to the application,
+    /// e.g. for scrolling lists. `None` always lets scrolling zoom.
+    pub zoom_modifier: Option<KeyCode>,
+    /// How much trackpad pinch gestures zoom
+    ///
+    /// Only used with the `gestures` feature, on platforms that report pinch
+    /// gestures.
+    pub pinch_zoom_sensitivity: f32,
+    /// Makes zoom sensitivity depend on the distance to a reference scale
+    ///
+    /// When `None`, every scroll step changes the scale by the same factor.
+    pub zoom_sensitivity_curve: Option<ZoomSensitivityCurve>,
+    /// How much scrolling zooms, as the fraction the scale changes by per
+    /// pixel scrolled
+    ///
+    /// With `ZoomMode::Linear`, this is the amount the scale changes by instead.
+    /// `None` uses [`PanCamSettings::zoom_sensitivity`].
+    pub zoom_sensitivity: Option<f32>,
+    /// How scrolling changes the scale
+    pub zoom_mode: ZoomMode,
+    /// How many pixels a line of mouse wheel scrolling counts as, for zooming
+    /// and scroll panning
+    ///
+    /// Trackpads usually scroll in pixels, so this lets mouse wheels be tuned
+    /// separately. `None` uses [`PanCamSettings::pixels_per_line`].
+    pub pixels_per_line: Option<f32>,
+    /// Multiplies trackpad scrolling, which is in pixels, for zooming and
+    /// scroll panning
+    ///
+    /// Platforms report very different pixel amounts for the same gesture, so
+    /// this lets trackpads be tuned separately from mouse wheels.
+    pub pixel_scroll_multiplier: f32,
+    /// How many pixels of zoom scrolling in one direction are ignored, to keep
+    /// noisy trackpads from making the zoom jitter
+    ///
+    /// Smaller scrolls accumulate until they add up past it, and then zoom by
+    /// all of it, so slow scrolling still zooms. Scrolling back the other way
+    /// starts over. A mouse wheel notch is `pixels_per_line` pixels.
+    pub scroll_deadzone: f32,
+    /// Makes scroll zooming sticky at integer pixel ratios
+    ///
+    /// `None` disables it.
+    pub integer_zoom_magnet: Option<IntegerZoomMagnet>,
+    /// Scales that scrolling snaps to, one step per mouse wheel notch
+    ///
+    /// Trackpad scrolling steps once per `pixels_per_line` scrolled. `None`
+    /// zooms continuously.
+    pub zoom_steps: Option<ZoomSteps>,
+    /// The projection scale shown as 100% zoom by `PanCamState::zoom_percent`
+    pub zoom_percent_scale: f32,
+    /// The area of the window the camera renders to, in logical pixels
+    ///
+    /// Cursor positions are normalized against this area, e.g. when zooming
+    /// towards the cursor. Set it when the camera's content doesn't fill its
+    /// viewport, such as when letterboxing to a fixed aspect ratio. When
+    /// `None`, the camera's viewport, or else the whole window, is used.
+    pub render_rect: Option<Rect>,
+    /// Centers the view on the clicked position when double-clicking
+    ///
+    /// `None` disables it.
+    pub double_click_center: Option<DoubleClickCenter>,
+    /// Centers the view on the clicked position when clicking with a chord.
+    /// `None` disables it.
+    pub click_to_travel: Option<ClickToTravel>,
+    /// Zooms in when double-tapping with one finger, and out when
+    /// double-tapping with two
+    ///
+    /// `None` disables it. Requires the `touch` feature.
+    pub double_tap_zoom: Option<DoubleTapZoom>,
+    /// The view `PanCamAction::Reset` returns to
+    ///
+    /// When `None`, resetting returns to the world origin at scale 1.
+    pub home: Option<PanCamSnapshot>,
+    /// Keys that animate the camera back to its `home` view
+    ///
+    /// `None` disables them. See also [`PanCamCommandsExt::reset_view`].
+    pub reset_view: Option<ResetView>,
+    /// When true, the cursor wraps around to the opposite edge of the window
+    /// when it reaches an edge while dragging, so the drag can go on
+    /// indefinitely
+    pub wrap_cursor: bool,
+    /// When true, the cursor shows a grabbing icon while dragging the camera
+    ///
+    /// Requires the `cursor_icon` feature.
+    pub change_cursor_icon: bool,
+    /// When true, the camera's translation is rounded to whole physical pixels
+    /// at the end of each frame, so pixel art doesn't shimmer while panning
+    ///
+    /// Movement is still computed from the unrounded translation.
+    pub snap_to_pixels: bool,
+    /// Aligns the camera's translation to a grid, continuously or when a drag
+    /// is released
+    ///
+    /// `None` doesn't snap.
+    pub grid_snap: Option<GridSnap>,
+    /// Scrolls the camera when the cursor is near the edges of its view
+    ///
+    /// `None` disables edge scrolling.
+    pub edge_scroll: Option<EdgeScroll>,
+    /// Keeps the camera gliding after releasing a drag while moving
+    ///
+    /// `None` stops the camera as soon as the drag is released.
+    pub inertia: Option<PanInertia>,
+    /// Keeps the camera gliding after a touch drag is lifted while moving
+    ///
+    /// `None` stops the camera as soon as the finger is lifted. See
+    /// [`PanInertia::touch`]. Requires the `touch` feature.
+    pub touch_inertia: Option<PanInertia>,
+    /// Pans and zooms the camera with gamepads
+    ///
+    /// `None` disables gamepad controls.
+    pub gamepad: Option<PanCamGamepadConfig>,
+    /// Which world position to keep in place when the camera's view is resized,
+    /// e.g. when the user resizes the window
+    ///
+    /// When `None`, the camera isn't moved on resize. Bounds still apply.
+    pub resize_anchor: Option<ResizeAnchor>,
+    /// How the scale changes when the camera's view is resized
+    ///
+    /// By default, the scale is kept, so resizing changes how much of the
+    /// world is shown.
+    pub resize_policy: ResizePolicy,
+    /// Which world position to keep in place when the window's scale factor
+    /// changes, e.g. when the window is dragged to a monitor with a different
+    /// DPI
+    ///
+    /// When `None`, `resize_anchor` is used for scale factor changes too.
+    pub scale_factor_anchor: Option<ResizeAnchor>,
+    /// Fits the view to content once, as soon as there is content to fit to
+    ///
+    /// Content is either a fixed rectangle or the entities marked with
+    /// [`PanCamContent`]. If there is no content yet, the fit is retried every
+    /// frame until there is. After the initial fit, this does nothing further.
+    pub fit_on_startup: Option<FitConfig>,
+    /// Constrains the camera's center to a path through these world positions
+    ///
+    /// When set, panning moves the camera along the path by the component of
+    /// the movement that follows the closest path segment, rather than freely
+    /// in 2D. Zooming to the cursor is constrained to the path in the same way.
+    /// Bounds are still applied after moving along the path.
+    pub rail: Option<Vec<Vec2>>,
     /// The minimum scale for the camera
     ///
     /// The orthographic projection's scale will be clamped at this value when
     /// zooming in. Pass `f32::NEG_INFINITY` to disable clamping.
+    #[cfg_attr(feature = "serde", serde(with = "serde_limits::lower"))]
     pub min_scale: f32,
     /// The maximum scale for the camera
     ///
     /// The orthographic projection's scale will be clamped at this value when
     /// zooming out. Pass `f32::INFINITY` to disable clamping.
+    #[cfg_attr(feature = "serde", serde(with = "serde_limits::upper"))]
     pub max_scale: f32,
+    /// Whether both scale limits are set to the scale the camera has when it's
+    /// spawned, locking its zoom there, see [`PanCam::lock_zoom_at`]
+    pub lock_zoom_on_spawn: bool,
+    /// The largest fraction of the view that may show what's outside the
+    /// bounds, when zoomed out
+    ///
+    /// With the default of `0.`, the camera can't zoom out further than the
+    /// bounds allow, so nothing outside them is ever shown. With e.g. `0.2`,
+    /// the camera can zoom out until 20% of the view is outside the bounds on
+    /// the most constrained axis, with the bounds kept in view. Only has an
+    /// effect when both the min and max bound are set on an axis.
+    pub max_empty_fraction: f32,
+    /// The smallest fraction of the view the bounds may fill, when zoomed out
+    ///
+    /// This keeps users from zooming out until the content is a speck, while
+    /// still allowing them to see past the bounds. With e.g. `0.2`, the camera
+    /// can zoom out until the bounds span 20% of the view's width or height,
+    /// whichever is more. Only has an effect on axes where both the min and max
+    /// bound are set. `0.` disables the limit.
+    pub min_content_fraction: f32,
+    /// When true, the camera can zoom out until the whole bounded area fits in
+    /// the view, and no further
+    ///
+    /// The limit follows the view's size and aspect ratio, and what's outside
+    /// the bounds is shown along the axis with room to spare. This replaces the
+    /// limits of `max_empty_fraction` and `min_content_fraction`. Only has an
+    /// effect on axes where both the min and max bound are set.
+    pub zoom_out_to_fit_bounds: bool,
+    /// Clamping corrections smaller than this, in world units, are ignored
+    ///
+    /// This keeps a camera resting against a boundary perfectly still, instead
+    /// of jittering by tiny amounts due to floating point imprecision.
+    pub clamp_tolerance: f32,
+    /// How softly panning stops at each of the bounds
+    ///
+    /// By default all edges are hard.
+    pub edge_softness: EdgeSoftness,
+    /// Lets dragging pull the camera past the bounds, springing back on release
+    ///
+    /// `None` stops the camera at the bounds. Has no effect on cameras with a
+    /// `rail`.
+    pub overscroll: Option<Overscroll>,
+    /// Lets the camera pan this far past the bounds, showing a border around
+    /// them
+    ///
+    /// The margin doesn't let the camera zoom out any further. `None` clamps
+    /// the camera at the bounds.
+    pub bounds_margin: Option<BoundsMargin>,
+    /// The area of the world with the content, e.g. a page in a document viewer
+    ///
+    /// When set, panning keeps at least `min_content_visible` of it in view,
+    /// and zooming to the cursor is anchored at the closest point within it.
+    pub content_rect: Option<Rect>,
+    /// The fraction of `content_rect` that stays in view on each axis
+    ///
+    /// When the view is smaller than the content, this is a fraction of the
+    /// view instead.
+    pub min_content_visible: f32,
     /// The minimum x position of the camera window
     ///
     /// The orthographic projection will be clamped to this boundary both when
     /// dragging the window, and zooming out. Pass `f32::NEG_INFINITY` to disable
     /// clamping.
+    #[cfg_attr(feature = "serde", serde(with = "serde_limits::lower"))]
     pub min_x: f32,
     /// The maximum x position of the camera window
     ///
     /// The orthographic projection will be clamped to this boundary both when
     /// dragging the window, and zooming out. Pass `f32::INFINITY` to disable
     /// clamping.
+    #[cfg_attr(feature = "serde", serde(with = "serde_limits::upper"))]
     pub max_x: f32,
     /// The minimum y position of the camera window
     ///
     /// The orthographic projection will be clamped to this boundary both when
     /// dragging the window, and zooming out. Pass `f32::NEG_INFINITY` to disable
     /// clamping.
+    #[cfg_attr(feature = "serde", serde(with = "serde_limits::lower"))]
     pub min_y: f32,
     /// The maximum y position of the camera window
     ///
     /// The orthographic projection will be clamped to this boundary both when
     /// dragging the window, and zooming out. Pass `f32::INFINITY` to disable
     /// clamping.
+    #[cfg_attr(feature = "serde", serde(with = "serde_limits::upper"))]
     pub max_y: f32,
 }
 
 impl PanCam {
-    /// Returns (min, max) bound tuple
-    fn bounds(&self) -> (Vec2, Vec2) {
-        let min = vec2(self.min_x, self.min_y);
-        let max = vec2(self.max_x, self.max_y);
-        (min, max)
+    /// A camera that can only be panned, not zoomed
+    ///
+    /// The remaining fields have their default values and can be customized
+    /// with struct update syntax: `PanCam { speed: 400., ..PanCam::pan_only() }`
+    pub fn pan_only() -> Self {
+        Self {
+            zoom_enabled: false,
+            ..default()
+        }
     }
 
-    /// Returns the bounding `Rect`
-    fn rect(&self) -> Rect {
-        let (min, max) = self.bounds();
-        Rect { min, max }
+    /// A camera that can only be zoomed, not panned
+    ///
+    /// The remaining fields have their default values and can be customized
+    /// with struct update syntax: `PanCam { min_scale: 0.5, ..PanCam::zoom_only() }`
+    pub fn zoom_only() -> Self {
+        Self {
+            pan_enabled: false,
+            ..default()
+        }
     }
 
-    /// Returns the bounding `Aabb2d`
-    fn aabb(&self) -> Aabb2d {
-        let (min, max) = self.bounds();
-        Aabb2d { min, max }
+    /// Locks the camera's zoom while still allowing it to be panned
+    ///
+    /// With `Some(scale)`, both scale limits are set to `scale`, so the camera
+    /// is set to that scale when it's spawned and kept there. With `None`, both
+    /// are set to the scale the camera has when it's spawned. Either way, zoom
+    /// input is ignored.
+    pub fn lock_zoom_at(self, scale: Option<f32>) -> Self {
+        match scale {
+            Some(scale) => Self {
+                zoom_enabled: false,
+                min_scale: scale,
+                max_scale: scale,
+                ..self
+            },
+            None => Self {
+                zoom_enabled: false,
+                lock_zoom_on_spawn: true,
+                ..self
+            },
+        }
     }
 
-    /// Returns the scale inclusive range
-    fn scale_range(&self) -> RangeInclusive<f32> {
-        self.min_scale..=self.max_scale
+    /// Returns the world-space rectangle currently shown by a camera rendering
+    /// to `window`
+    ///
+    /// Rotated views are covered by their bounding box. `PanCam` cameras keep
+    /// this up to date in their [`VisibleWorldRect`].
+    pub fn visible_world_rect(
+        &self,
+        camera: &Camera,
+        projection: &OrthographicProjection,
+        transform: &GlobalTransform,
+        window: &Window,
+    ) -> Rect {
+        world_rect(self.view_area(camera, projection, window), transform)
     }
-}
 
-impl Default for PanCam {
-    fn default() -> Self {
-        Self {
-            move_keys: DirectionKeys::arrows_and_wasd(),
-            speed: 200.,
-            grab_buttons: vec![MouseButton::Left, MouseButton::Right, MouseButton::Middle],
-            enabled: true,
-            zoom_to_cursor: true,
-            min_scale: 0.00001,
-            max_scale: f32::INFINITY,
-            min_x: f32::NEG_INFINITY,
-            max_x: f32::INFINITY,
-            min_y: f32::NEG_INFINITY,
-            max_y: f32::INFINITY,
+    /// Returns whether a world position is currently shown by a camera
+    /// rendering to `window`
+    ///
+    /// Points exactly on the edge of the view count as visible.
+    pub fn is_world_point_visible(
+        &self,
+        point: Vec2,
+        camera: &Camera,
+        projection: &OrthographicProjection,
+        transform: &GlobalTransform,
+        window: &Window,
+    ) -> bool {
+        let view_pos = transform
+            .affine()
+            .inverse()
+            .transform_point3(point.extend(0.));
+        self.view_area(camera, projection, window)
+            .contains(view_pos.truncate())
+    }
+
+    /// The projection's area for the camera's viewport
+    ///
+    /// The projection's own area is only updated for its scale later in the
+    /// frame, so it's recomputed for the viewport size here.
+    fn view_area(
+        &self,
+        camera: &Camera,
+        projection: &OrthographicProjection,
+        window: &Window,
+    ) -> Rect {
+        let view_size = camera
+            .logical_viewport_size()
+            .unwrap_or_else(|| self.view_rect(camera, window).size());
+        let mut projection = projection.clone();
+        projection.update(view_size.x, view_size.y);
+        projection.area
+    }
+
+    /// Returns the smallest rectangle containing all the given world-space
+    /// rectangles, e.g. to use as bounds for scattered content
+    ///
+    /// Returns `None` when there are no rectangles.
+    pub fn bounds_from_aabbs(aabbs: impl Iterator<Item = Rect>) -> Option<Rect> {
+        aabbs.reduce(|a, b| a.union(b))
+    }
+
+    /// Sets the bounds to `rect`
+    pub fn set_bounds(&mut self, rect: Rect) {
+        self.min_x = rect.min.x;
+        self.max_x = rect.max.x;
+        self.min_y = rect.min.y;
+        self.max_y = rect.max.y;
+    }
+
+    /// Returns how far zooming from `old_scale` to `new_scale` moves a camera
+    /// at `current_translation` to keep `anchor_world_pos` in place
+    ///
+    /// This is the anchoring used when zooming to the cursor, before bounds.
+    pub fn zoom_translation_delta(
+        old_scale: f32,
+        new_scale: f32,
+        anchor_world_pos: Vec2,
+        current_translation: Vec2,
+    ) -> Vec2 {
+        (anchor_world_pos - current_translation) * (1. - new_scale / old_scale)
+    }
+
+    /// Returns the normalized view position to keep in place when zooming with
+    /// the cursor at `cursor`, see `normalize_view_pos`
+    fn zoom_anchor(&self, cursor: Option<Vec2>, focus: &ZoomFocusState) -> Option<Vec2> {
+        match self.effective_zoom_focus() {
+            ZoomFocus::Cursor => {
+                cursor.map(|cursor| cursor * self.zoom_to_cursor_strength.clamp(0., 1.))
+            }
+            ZoomFocus::ScreenCenter => None,
+            ZoomFocus::Entity(_) => focus.entity_anchor,
+        }
+    }
+
+    /// Returns `zoom_focus`, with the deprecated `zoom_to_cursor: false` mapped
+    /// to `ZoomFocus::ScreenCenter`
+    #[allow(deprecated)]
+    fn effective_zoom_focus(&self) -> ZoomFocus {
+        match self.zoom_focus {
+            ZoomFocus::Cursor if !self.zoom_to_cursor => ZoomFocus::ScreenCenter,
+            focus => focus,
+        }
+    }
+
+    /// Returns the area of the window the camera renders to, in logical pixels
+    ///
+    /// This is `render_rect` if set, or else the camera's viewport, or else the
+    /// whole window.
+    pub fn view_rect(&self, camera: &Camera, window: &Window) -> Rect {
+        self.render_rect.unwrap_or_else(|| {
+            viewport_rect(camera, window)
+                .unwrap_or_else(|| Rect::from_corners(Vec2::ZERO, window.size()))
+        })
+    }
+
+    /// Clamps a proposed camera position to the bounds
+    ///
+    /// Clamping corrections smaller than `clamp_tolerance` are ignored, so a
+    /// camera resting against a boundary stays put rather than jittering.
+    fn clamp_position(&self, old_pos: Vec2, proposed_pos: Vec2, proj_area_size: Vec2) -> Vec2 {
+        let clamped = clamp_to_safe_zone(proposed_pos, self.aabb(proj_area_size), proj_area_size);
+        let clamped = match self.content_rect {
+            Some(content) => self.clamp_to_content(clamped, content, proj_area_size),
+            None => clamped,
+        };
+        let ignore_correction = clamped.cmpne(proposed_pos)
+            & (clamped - old_pos)
+                .abs()
+                .cmple(Vec2::splat(self.clamp_tolerance));
+        Vec2::select(ignore_correction, old_pos, clamped)
+    }
+
+    /// Clamps the projection's scale to the scale limits and the limits derived
+    /// from the bounds
+    fn constrain_scale(&self, proj: &mut OrthographicProjection, view_size: Vec2) {
+        constrain_proj_scale(
+            proj,
+            self.view_filling_size(),
+            &self.scale_range(),
+            view_size,
+            self.max_empty_fraction,
+        );
+        proj.scale = proj.scale.min(self.max_scale_for_content(proj, view_size));
+    }
+
+    /// The size of the bounds the view has to stay within when zoomed out, or
+    /// infinite when `zoom_out_to_fit_bounds` lets the view grow past them
+    fn view_filling_size(&self) -> Vec2 {
+        if self.zoom_out_to_fit_bounds {
+            Vec2::INFINITY
+        } else {
+            self.rect().size()
+        }
+    }
+
+    /// Shortens `movement` over `dt` seconds to stay within `max_pan_speed`
+    ///
+    /// A frame never allows more movement than [`MAX_PAN_SPEED_FRAME_TIME`]
+    /// would, so long frames and frames without time passing, like the hitches
+    /// the limit is for, can't make the camera jump.
+    pub(crate) fn limit_pan_speed(&self, movement: Vec2, dt: f32, scale: f32) -> Vec2 {
+        let Some(max_pan_speed) = self.max_pan_speed else {
+            return movement;
+        };
+        let dt = if dt > 0. {
+            dt.min(MAX_PAN_SPEED_FRAME_TIME)
+        } else {
+            MAX_PAN_SPEED_FRAME_TIME
+        };
+        movement.clamp_length_max(max_pan_speed.world_units_per_second(scale) * dt)
+    }
+
+    /// The largest scale at which the bounds still fill `min_content_fraction`
+    /// of the view, or all of it along one axis with `zoom_out_to_fit_bounds`
+    fn max_scale_for_content(&self, proj: &OrthographicProjection, view_size: Vec2) -> f32 {
+        let min_content_fraction = if self.zoom_out_to_fit_bounds {
+            1.
+        } else {
+            self.min_content_fraction
+        };
+        if min_content_fraction <= 0. {
+            return f32::INFINITY;
+        }
+        let max_scale =
+            self.rect().size() / base_world_size(proj, view_size) / min_content_fraction;
+        // The content only has to fill the fraction along one axis
+        let max_scale =
+            Vec2::select(max_scale.is_finite_mask(), max_scale, Vec2::NEG_INFINITY).max_element();
+        if max_scale == f32::NEG_INFINITY {
+            f32::INFINITY
+        } else {
+            max_scale
+        }
+    }
+
+    /// Clamps a camera position so the view keeps showing at least
+    /// `min_content_visible` of `content` on both axes
+    fn clamp_to_content(&self, pos: Vec2, content: Rect, proj_area_size: Vec2) -> Vec2 {
+        let half_size = proj_area_size / 2.;
+        let overlap = self.min_content_visible.clamp(0., 1.) * content.size().min(proj_area_size);
+        pos.clamp(
+            content.min + overlap - half_size,
+            content.max - overlap + half_size,
+        )
+    }
+
+    /// Moves a normalized zoom anchor into `content_rect`, if set, for a camera
+    /// at `cam_pos` with the projection's area `proj_area`
+    fn anchor_within_content(&self, anchor: Vec2, cam_pos: Vec2, proj_area: Rect) -> Vec2 {
+        let Some(content) = self.content_rect else {
+            return anchor;
+        };
+        let world_pos = normalized_view_pos_to_world(anchor, cam_pos, proj_area);
+        world_to_normalized_view_pos(
+            world_pos.clamp(content.min, content.max),
+            cam_pos,
+            proj_area,
+        )
+    }
+
+    /// Returns (min, max) bound tuple
+    fn bounds(&self) -> (Vec2, Vec2) {
+        let min = vec2(self.min_x, self.min_y);
+        let max = vec2(self.max_x, self.max_y);
+        (min, max)
+    }
+
+    /// Returns the bounding `Rect`
+    fn rect(&self) -> Rect {
+        let (min, max) = self.bounds();
+        Rect { min, max }
+    }
+
+    /// Returns the bounding `Aabb2d` the camera's view is kept in, including
+    /// `bounds_margin`, for a camera showing an area of `proj_area_size`
+    fn aabb(&self, proj_area_size: Vec2) -> Aabb2d {
+        let (min, max) = self.bounds();
+        let margin = self
+            .bounds_margin
+            .map_or(Vec2::ZERO, |margin| margin.world_size(proj_area_size));
+        Aabb2d {
+            min: min - margin,
+            max: max + margin,
+        }
+    }
+
+    /// Returns the scale inclusive range
+    fn scale_range(&self) -> RangeInclusive<f32> {
+        self.min_scale..=self.max_scale
+    }
+}
+
+impl Default for PanCam {
+    #[allow(deprecated)]
+    fn default() -> Self {
+        Self {
+            move_keys: DirectionKeys::arrows_and_wasd(),
+            page_keys: DirectionKeys::NONE,
+            page_overlap: 0.1,
+            page_duration: 0.2,
+            zoom_keys: ZoomKeys::NONE,
+            key_zoom_speed: 1.,
+            key_zoom_to_cursor: false,
+            key_focus: KeyFocus::Always,
+            rotate_keys: RotateKeys::NONE,
+            rotate_speed: std::f32::consts::FRAC_PI_2,
+            rotate_to_cursor: false,
+            speed: 200.,
+            pan_speed: 1.,
+            key_pan_speed: 1.,
+            max_pan_speed: None,
+            grab_buttons: vec![MouseButton::Left, MouseButton::Right, MouseButton::Middle].into(),
+            release_ends_grab: false,
+            invert_drag: false,
+            pan_input: PanInputSource::default(),
+            drag_pixel_space: DragPixelSpace::default(),
+            pan_hold_delay: Duration::ZERO,
+            pan_deadzone: 0.,
+            pan_deadzone_world: 0.,
+            enabled: true,
+            passive: false,
+            pan_enabled: true,
+            pan_axes: BVec2::TRUE,
+            zoom_enabled: true,
+            zoom_focus: ZoomFocus::Cursor,
+            zoom_to_cursor: true,
+            zoom_to_cursor_wheel_only: false,
+            zoom_to_cursor_strength: 1.,
+            invert_zoom: false,
+            zoom_smoothing: None,
+            zoom_axes: BVec2::TRUE,
+            zoom_while_following: FollowZoomMode::Target,
+            scroll_mapping: ScrollMapping::default(),
+            scroll_behavior: ScrollBehavior::default(),
+            zoom_modifier: None,
+            pinch_zoom_sensitivity: 1.,
+            zoom_sensitivity_curve: None,
+            zoom_sensitivity: None,
+            zoom_mode: ZoomMode::default(),
+            pixels_per_line: None,
+            pixel_scroll_multiplier: 1.,
+            scroll_deadzone: 0.,
+            integer_zoom_magnet: None,
+            zoom_steps: None,
+            zoom_percent_scale: 1.,
+            render_rect: None,
+            double_click_center: None,
+            click_to_travel: None,
+            double_tap_zoom: None,
+            home: None,
+            reset_view: None,
+            wrap_cursor: false,
+            change_cursor_icon: false,
+            snap_to_pixels: false,
+            grid_snap: None,
+            edge_scroll: None,
+            inertia: None,
+            touch_inertia: None,
+            gamepad: None,
+            resize_anchor: None,
+            resize_policy: ResizePolicy::default(),
+            scale_factor_anchor: None,
+            fit_on_startup: None,
+            rail: None,
+            min_scale: 0.00001,
+            max_scale: f32::INFINITY,
+            lock_zoom_on_spawn: false,
+            max_empty_fraction: 0.,
+            min_content_fraction: 0.,
+            zoom_out_to_fit_bounds: false,
+            clamp_tolerance: 1e-4,
+            edge_softness: EdgeSoftness::default(),
+            overscroll: None,
+            bounds_margin: None,
+            content_rect: None,
+            min_content_visible: 0.25,
+            min_x: f32::NEG_INFINITY,
+            max_x: f32::INFINITY,
+            min_y: f32::NEG_INFINITY,
+            max_y: f32::INFINITY,
         }
     }
 }
 
+/// The world-space bounding box of a view's `area`, seen through `transform`
+fn world_rect(area: Rect, transform: &GlobalTransform) -> Rect {
+    let corners = [
+        area.min,
+        vec2(area.max.x, area.min.y),
+        area.max,
+        vec2(area.min.x, area.max.y),
+    ]
+    .map(|corner| transform.transform_point(corner.extend(0.)).truncate());
+    corners.into_iter().fold(
+        Rect::from_corners(corners[0], corners[0]),
+        |rect, corner| rect.union_point(corner),
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use std::f32::INFINITY;
+    use std::time::Duration;
 
-    use bevy::prelude::OrthographicProjection;
+    use bevy::{
+        prelude::OrthographicProjection,
+        render::camera::{RenderTarget, ScalingMode},
+        time::TimeUpdateStrategy,
+        window::WindowRef,
+    };
 
     use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn idle_and_clamped_zoom_leave_camera_unchanged() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                min_scale: 1.,
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+        update(&mut app);
+        let last_changed = |app: &App| {
+            let entity = app.world().entity(cam);
+            (
+                entity.get_ref::<Transform>().unwrap().last_changed(),
+                entity
+                    .get_ref::<OrthographicProjection>()
+                    .unwrap()
+                    .last_changed(),
+            )
+        };
+        let before = last_changed(&app);
+
+        update(&mut app);
+        // Zooming in past the minimum scale
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert_eq!(last_changed(&app), before);
+    }
+
+    #[test]
+    fn inactive_cameras_ignore_input() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+
+        let set_active = |app: &mut App, is_active: bool| {
+            app.world_mut().get_mut::<Camera>(cam).unwrap().is_active = is_active;
+        };
+        set_active(&mut app, false);
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.);
+
+        set_active(&mut app, true);
+        update(&mut app);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+    }
+
+    #[test]
+    fn stats_accumulate_only_when_enabled() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        drag_right(&mut app, cam);
+        assert_eq!(app.world().resource::<PanCamStats>().total_pan_distance, 0.);
+
+        app.world_mut().resource_mut::<PanCamStats>().enabled = true;
+        drag_right(&mut app, cam);
+        drag_right(&mut app, cam);
+        scroll(&mut app, 1.);
+        update(&mut app);
+
+        let stats = app.world().resource::<PanCamStats>();
+        assert_eq!(stats.total_pan_distance, 20.);
+        assert!((stats.total_zoom_change - 0.1).abs() < 1e-5);
+
+        app.world_mut().resource_mut::<PanCamStats>().reset();
+        let stats = app.world().resource::<PanCamStats>();
+        assert!(stats.enabled);
+        assert_eq!(stats.total_pan_distance, 0.);
+        assert_eq!(stats.total_zoom_change, 0.);
+    }
+
+    #[test]
+    fn rail_moves_along_segment_by_tangential_component() {
+        let rail = [vec2(0., 0.), vec2(100., 0.)];
+        assert!(
+            move_along_rail(&rail, vec2(10., 0.), vec2(5., 30.)).abs_diff_eq(vec2(15., 0.), 1e-4)
+        );
+    }
+
+    #[test]
+    fn rail_movement_continues_onto_next_segment() {
+        let rail = [vec2(0., 0.), vec2(10., 0.), vec2(10., 10.)];
+        assert_eq!(
+            move_along_rail(&rail, vec2(5., 0.), vec2(10., 0.)),
+            vec2(10., 5.)
+        );
+    }
+
+    #[test]
+    fn rail_movement_stops_at_ends() {
+        let rail = [vec2(0., 0.), vec2(10., 0.)];
+        assert_eq!(
+            move_along_rail(&rail, vec2(5., 0.), vec2(-50., 0.)),
+            vec2(0., 0.)
+        );
+        assert_eq!(
+            move_along_rail(&rail, vec2(5., 0.), vec2(50., 0.)),
+            vec2(10., 0.)
+        );
+    }
+
+    #[test]
+    fn zoom_sensitivity_is_finest_at_reference_scale() {
+        let curve = ZoomSensitivityCurve::default();
+        assert_eq!(curve.multiplier(curve.reference_scale), curve.near);
+        assert!(curve.multiplier(2.) < curve.multiplier(20.));
+        assert!(curve.multiplier(0.5) < curve.multiplier(0.05));
+        assert!(curve.multiplier(1000.) <= curve.far);
+        assert!(curve.multiplier(1000.) > 0.9 * curve.far);
+    }
+
+    #[test]
+    fn zoom_steps_shrink_near_reference_scale() {
+        let step_at = |start_scale: f32| {
+            let mut app = test_app();
+            let pan_cam = PanCam {
+                zoom_sensitivity_curve: Some(ZoomSensitivityCurve::default()),
+                ..default()
+            };
+            let cam = spawn_cam(&mut app, pan_cam);
+            let mut proj = app
+                .world_mut()
+                .get_mut::<OrthographicProjection>(cam)
+                .unwrap();
+            proj.scale = start_scale;
+            scroll(&mut app, 1.);
+            update(&mut app);
+            1. - scale(&app, cam) / start_scale
+        };
+        assert!(step_at(30.) > 2. * step_at(1.2));
+    }
+
+    #[test]
+    fn off_rail_drag_slides_camera_along_rail() {
+        let mut app = test_app();
+        let pan_cam = PanCam {
+            rail: Some(vec![vec2(-100., -100.), vec2(100., 100.)]),
+            ..default()
+        };
+        let cam = start_grab(&mut app, pan_cam, MouseButton::Left);
+        let moved = drag_right(&mut app, cam);
+        // dragging right moves the camera left, so down the diagonal rail
+        let expected = vec2(-10., -10.) / 2.;
+        assert!((moved - expected).length() < 1e-4);
+    }
+
+    #[test]
+    fn late_spawned_camera_behaves_like_early_one() {
+        let mut app = test_app();
+        let early = spawn_cam(&mut app, PanCam::default());
+        set_cursor(&mut app, vec2(70., 40.));
+        for _ in 0..10 {
+            update(&mut app);
+        }
+        let late = spawn_cam(&mut app, PanCam::default());
+        update(&mut app);
+        assert!(app.world().get::<GrabState>(late).is_some());
+
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        drag_right(&mut app, early);
+        scroll(&mut app, 2.);
+        update(&mut app);
+
+        assert_ne!(translation(&app, early), Vec2::ZERO);
+        assert_eq!(translation(&app, early), translation(&app, late));
+        assert_ne!(scale(&app, early), 1.);
+        assert_eq!(scale(&app, early), scale(&app, late));
+
+        app.world_mut().despawn(late);
+        update(&mut app);
+        assert_eq!(
+            app.world_mut()
+                .query::<&GrabState>()
+                .iter(app.world())
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn zoom_to_cursor_anchors_within_letterboxed_render_rect() {
+        let mut app = test_app();
+        let pan_cam = PanCam {
+            render_rect: Some(Rect::new(0., 25., 100., 75.)),
+            ..default()
+        };
+        let cam = spawn_cam(&mut app, pan_cam);
+        *app.world_mut()
+            .get_mut::<OrthographicProjection>(cam)
+            .unwrap() = mock_proj(vec2(100., 50.));
+
+        // halfway between the center and the right edge of the letterbox
+        set_cursor(&mut app, vec2(75., 50.));
+        let world_under_cursor = 25.;
+        scroll(&mut app, 1.);
+        update(&mut app);
+
+        let cam_x = translation(&app, cam).x;
+        let new_world_under_cursor = cam_x + 0.5 * 50. * scale(&app, cam);
+        assert!((new_world_under_cursor - world_under_cursor).abs() < 1e-4);
+        assert_eq!(translation(&app, cam).y, 0.);
+    }
+
+    #[cfg(feature = "bevy_egui")]
+    #[test]
+    fn wheel_zoom_over_transparent_egui_area_anchors_at_cursor() {
+        use bevy_egui::egui;
+
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        // halfway between the center and the right edge
+        set_cursor(&mut app, vec2(75., 50.));
+        // a HUD drawn over the whole window that doesn't take pointer input
+        let ctx = spawn_egui_context(&mut app, vec2(75., 50.), |ctx| {
+            egui::Area::new(egui::Id::new("hud"))
+                .fixed_pos(egui::Pos2::ZERO)
+                .interactable(false)
+                .show(ctx, |ui| ui.allocate_space(egui::vec2(100., 100.)));
+        });
+        assert!(!ctx.wants_pointer_input());
+        update(&mut app);
+
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!((scale(&app, cam) - 0.9).abs() < 1e-6);
+        // the world position under the cursor stays in place
+        assert!((translation(&app, cam).x + 25. * 0.9 - 25.).abs() < 1e-4);
+    }
+
+    #[cfg(feature = "bevy_egui")]
+    #[test]
+    fn wheel_zoom_over_egui_panel_is_only_blocked_by_scroll_areas() {
+        use bevy_egui::egui;
+
+        let zoom_over_panel = |content: fn(&mut egui::Ui)| {
+            let mut app = test_app();
+            let cam = spawn_cam(&mut app, PanCam::default());
+            set_cursor(&mut app, vec2(25., 50.));
+            let ctx = spawn_egui_context(&mut app, vec2(25., 50.), move |ctx| {
+                egui::SidePanel::left("panel")
+                    .exact_width(50.)
+                    .resizable(false)
+                    .show(ctx, content);
+            });
+            assert!(ctx.is_pointer_over_area());
+            update(&mut app);
+            scroll(&mut app, 1.);
+            update(&mut app);
+            scale(&app, cam)
+        };
+
+        assert!((zoom_over_panel(|_| {}) - 0.9).abs() < 1e-6);
+        assert_eq!(
+            zoom_over_panel(|ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    ui.allocate_space(egui::vec2(40., 300.));
+                });
+            }),
+            1.
+        );
+    }
+
+    #[cfg(feature = "bevy_egui")]
+    #[test]
+    fn wheel_zoom_over_resizable_egui_panel_is_only_blocked_at_its_edge() {
+        use bevy_egui::egui;
+
+        let zoom_at = |x: f32| {
+            let mut app = test_app();
+            let cam = spawn_cam(&mut app, PanCam::default());
+            set_cursor(&mut app, vec2(x, 50.));
+            spawn_egui_context(&mut app, vec2(x, 50.), |ctx| {
+                egui::SidePanel::left("panel")
+                    .exact_width(50.)
+                    .resizable(true)
+                    .show(ctx, |_| {});
+            });
+            update(&mut app);
+            scroll(&mut app, 1.);
+            update(&mut app);
+            scale(&app, cam)
+        };
+
+        assert!((zoom_at(25.) - 0.9).abs() < 1e-6);
+        // the resize handle only senses drags, like a scroll area
+        assert_eq!(zoom_at(50.), 1.);
+    }
+
+    #[cfg(feature = "bevy_egui")]
+    #[test]
+    fn egui_keyboard_focus_blocks_keys_but_not_wheel_zoom() {
+        use bevy_egui::egui;
+
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        set_cursor(&mut app, vec2(75., 50.));
+        let text_field = egui::Id::new("text_field");
+        let ctx = spawn_egui_context(&mut app, vec2(75., 50.), move |ctx| {
+            ctx.memory_mut(|memory| memory.request_focus(text_field));
+            egui::Area::new(egui::Id::new("form"))
+                .fixed_pos(egui::Pos2::ZERO)
+                .show(ctx, |ui| {
+                    ui.add(egui::TextEdit::singleline(&mut String::new()).id(text_field))
+                });
+        });
+        assert!(ctx.wants_keyboard_input());
+        update(&mut app);
+        assert!(app.world().resource::<PanCamInputBlockers>().blocks_keys());
+
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!((scale(&app, cam) - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn zoom_to_cursor_wheel_only_centers_trackpad_zoom() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_to_cursor_wheel_only: true,
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(75., 50.));
+
+        scroll_in(&mut app, MouseScrollUnit::Pixel, 100.);
+        update(&mut app);
+        assert!(scale(&app, cam) < 1.);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+
+        scroll_in(&mut app, MouseScrollUnit::Line, 1.);
+        update(&mut app);
+        assert!(translation(&app, cam).x > 0.);
+    }
+
+    #[test]
+    fn pixel_scroll_multiplier_only_scales_trackpad_scrolling() {
+        let scrolled_scale = |pixel_scroll_multiplier: f32, unit: MouseScrollUnit, amount: f32| {
+            let mut app = test_app();
+            let cam = spawn_cam(
+                &mut app,
+                PanCam {
+                    pixel_scroll_multiplier,
+                    ..default()
+                },
+            );
+            scroll_in(&mut app, unit, amount);
+            update(&mut app);
+            scale(&app, cam)
+        };
+        assert_eq!(
+            scrolled_scale(2., MouseScrollUnit::Pixel, 50.),
+            scrolled_scale(1., MouseScrollUnit::Pixel, 100.)
+        );
+        assert_eq!(
+            scrolled_scale(2., MouseScrollUnit::Line, 1.),
+            scrolled_scale(1., MouseScrollUnit::Line, 1.)
+        );
+    }
+
+    #[test]
+    fn scroll_deadzone_ignores_jitter_and_accumulates_small_scrolls() {
+        let scrolled_scale = |scroll_deadzone: f32, pixels: &[f32]| {
+            let mut app = test_app();
+            let cam = spawn_cam(
+                &mut app,
+                PanCam {
+                    scroll_deadzone,
+                    ..default()
+                },
+            );
+            let mut scales = Vec::new();
+            for &pixels in pixels {
+                scroll_in(&mut app, MouseScrollUnit::Pixel, pixels);
+                update(&mut app);
+                scales.push(scale(&app, cam));
+            }
+            scales
+        };
+        assert_eq!(
+            scrolled_scale(10., &[4., 4., -4., 4., 4.]),
+            [1., 1., 1., 1., 1.]
+        );
+        let scales = scrolled_scale(10., &[4., 4., 4.]);
+        assert_eq!(scales[..2], [1., 1.]);
+        assert_eq!(scales[2], scrolled_scale(0., &[12.])[0]);
+    }
+
+    #[test]
+    fn scaling_modes_pan_and_zoom_to_cursor_in_world_units() {
+        let fixed_vertical = OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical {
+                viewport_height: 10.,
+            },
+            ..OrthographicProjection::default_2d()
+        };
+        let projections = [
+            OrthographicProjection {
+                scaling_mode: ScalingMode::FixedHorizontal {
+                    viewport_width: 20.,
+                },
+                ..OrthographicProjection::default_2d()
+            },
+            OrthographicProjection {
+                scaling_mode: ScalingMode::Fixed {
+                    width: 30.,
+                    height: 10.,
+                },
+                ..OrthographicProjection::default_2d()
+            },
+            OrthographicProjection {
+                viewport_origin: Vec2::ZERO,
+                ..OrthographicProjection::default_2d()
+            },
+            OrthographicProjection {
+                viewport_origin: vec2(0.25, 1.),
+                ..fixed_vertical.clone()
+            },
+            fixed_vertical,
+        ];
+
+        for mut proj in projections {
+            proj.update(100., 100.);
+            let world_width = proj.area.width();
+            let mut app = test_app();
+            let cam = spawn_cam(&mut app, PanCam::default());
+            *app.world_mut()
+                .get_mut::<OrthographicProjection>(cam)
+                .unwrap() = proj;
+
+            set_cursor(&mut app, vec2(80., 10.));
+            update(&mut app);
+            mouse(&mut app).press(MouseButton::Left);
+            update(&mut app);
+            let delta = drag_right(&mut app, cam);
+            assert!((delta - vec2(-world_width / 10., 0.)).length() < 1e-4);
+            mouse(&mut app).release(MouseButton::Left);
+            update(&mut app);
+
+            let world_under_cursor = |app: &App| {
+                view_pos_to_world(
+                    vec2(90., 10.),
+                    Rect::new(0., 0., 100., 100.),
+                    app.world().get::<OrthographicProjection>(cam).unwrap(),
+                    app.world().get::<Transform>(cam).unwrap(),
+                )
+            };
+            let before = world_under_cursor(&app);
+            assert_eq!(cursor_world_pos(&app, cam), Some(before));
+            scroll(&mut app, 1.);
+            update(&mut app);
+            // updates the projection's area like the camera system would
+            app.world_mut()
+                .get_mut::<OrthographicProjection>(cam)
+                .unwrap()
+                .update(100., 100.);
+            assert!(scale(&app, cam) < 1.);
+            assert!((world_under_cursor(&app) - before).length() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn user_systems_ordered_before_the_set_apply_the_same_frame() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_systems(
+            Update,
+            (|keys: Res<ButtonInput<KeyCode>>, mut pan_cams: Query<&mut PanCam>| {
+                for mut pan_cam in &mut pan_cams {
+                    pan_cam.enabled = !keys.pressed(KeyCode::Space);
+                }
+            })
+            .before(PanCamSystemSet),
+        );
+        let cam = spawn_cam(&mut app, PanCam::default());
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+
+        keys(&mut app).press(KeyCode::Space);
+        keys(&mut app).press(KeyCode::ArrowRight);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+
+        keys(&mut app).release(KeyCode::Space);
+        update(&mut app);
+        assert!(translation(&app, cam).x > 0.);
+    }
+
+    #[test]
+    fn pan_speed_scales_drags_and_key_pan_speed_scales_keyboard_panning() {
+        let drag = |pan_speed: f32| {
+            let mut app = test_app();
+            let cam = start_grab(
+                &mut app,
+                PanCam {
+                    pan_speed,
+                    ..default()
+                },
+                MouseButton::Left,
+            );
+            drag_right(&mut app, cam)
+        };
+        assert_eq!(drag(0.5), drag(1.) * 0.5);
+
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                speed: 100.,
+                key_pan_speed: 0.5,
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+        keys(&mut app).press(KeyCode::ArrowRight);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(5., 0.));
+    }
+
+    #[test]
+    fn max_pan_speed_limits_large_cursor_jumps() {
+        let drag_after_hitch = |max_pan_speed: MaxPanSpeed, scale: f32| {
+            let mut app = test_app();
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+            let cam = spawn_cam(
+                &mut app,
+                PanCam {
+                    max_pan_speed: Some(max_pan_speed),
+                    ..default()
+                },
+            );
+            set_view(&mut app, cam, Vec2::ZERO, scale);
+            set_cursor(&mut app, vec2(10., 50.));
+            update(&mut app);
+            mouse(&mut app).press(MouseButton::Left);
+            update(&mut app);
+            set_cursor(&mut app, vec2(90., 50.));
+            update(&mut app);
+            translation(&app, cam)
+        };
+        assert_eq!(
+            drag_after_hitch(MaxPanSpeed::World(100.), 1.),
+            vec2(-10., 0.)
+        );
+        assert_eq!(
+            drag_after_hitch(MaxPanSpeed::World(1000.), 1.),
+            vec2(-80., 0.)
+        );
+        assert_eq!(
+            drag_after_hitch(MaxPanSpeed::Screen(100.), 2.),
+            vec2(-20., 0.)
+        );
+    }
+
+    #[test]
+    fn max_pan_speed_limits_long_frames_like_short_ones() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                max_pan_speed: Some(MaxPanSpeed::World(100.)),
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(10., 50.));
+        update(&mut app);
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        set_cursor(&mut app, vec2(90., 50.));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(-10., 0.));
+    }
+
+    #[test]
+    fn zoom_translation_delta_matches_zoom_to_cursor() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        set_view(&mut app, cam, vec2(30., -10.), 2.);
+        set_cursor(&mut app, vec2(80., 10.));
+        // 0.6 and 0.8 of the half extents right and up of the center, with the
+        // projection area not updated for the new scale as there's no renderer
+        let anchor = vec2(30. + 0.6 * 50., -10. + 0.8 * 50.);
+        scroll(&mut app, 1.);
+        update(&mut app);
+
+        let expected =
+            PanCam::zoom_translation_delta(2., scale(&app, cam), anchor, vec2(30., -10.));
+        let moved = translation(&app, cam) - vec2(30., -10.);
+        assert!((moved - expected).length() < 1e-4);
+        assert_ne!(expected, Vec2::ZERO);
+    }
+
+    #[test]
+    fn half_zoom_to_cursor_strength_anchors_halfway() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_to_cursor_strength: 0.5,
+                ..default()
+            },
+        );
+        // the cursor is over world x 40, halfway to it is 20
+        set_cursor(&mut app, vec2(90., 50.));
+        scroll(&mut app, 1.);
+        update(&mut app);
+
+        let world_x = translation(&app, cam).x + 0.4 * 50. * scale(&app, cam);
+        assert!((world_x - 20.).abs() < 1e-4);
+    }
+
+    #[test]
+    fn cursor_world_pos_follows_cursor_and_camera() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        update(&mut app);
+        assert_eq!(cursor_world_pos(&app, cam), None);
+
+        set_cursor(&mut app, vec2(75., 25.));
+        update(&mut app);
+        assert_eq!(cursor_world_pos(&app, cam), Some(vec2(25., 25.)));
+
+        app.world_mut()
+            .get_mut::<Transform>(cam)
+            .unwrap()
+            .translation = Vec3::new(100., 0., 0.);
+        update(&mut app);
+        assert_eq!(cursor_world_pos(&app, cam), Some(vec2(125., 25.)));
+    }
+
+    #[test]
+    fn screen_world_conversions_match_cursor_world_pos() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        let mut proj = OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical {
+                viewport_height: 10.,
+            },
+            viewport_origin: vec2(0.25, 1.),
+            ..OrthographicProjection::default_2d()
+        };
+        proj.update(100., 100.);
+        app.world_mut().entity_mut(cam).insert((
+            proj,
+            Transform::from_xyz(3., -2., 0.).with_rotation(Quat::from_rotation_z(0.5)),
+        ));
+        set_cursor(&mut app, vec2(70., 20.));
+        update(&mut app);
+
+        let view_rect = Rect::new(0., 0., 100., 100.);
+        let proj = app.world().get::<OrthographicProjection>(cam).unwrap();
+        let transform = app.world().get::<Transform>(cam).unwrap();
+        let world_pos = proj.screen_to_world(transform, view_rect, vec2(70., 20.));
+        assert_eq!(cursor_world_pos(&app, cam), Some(world_pos));
+        let screen_pos = proj.world_to_screen(transform, view_rect, world_pos);
+        assert!((screen_pos - vec2(70., 20.)).length() < 1e-3);
+    }
+
+    #[test]
+    fn cursor_world_pos_is_none_outside_view_area() {
+        let mut app = test_app();
+        let pan_cam = PanCam {
+            render_rect: Some(Rect::new(0., 0., 50., 100.)),
+            ..default()
+        };
+        let cam = spawn_cam(&mut app, pan_cam);
+        set_cursor(&mut app, vec2(75., 25.));
+        update(&mut app);
+        assert_eq!(cursor_world_pos(&app, cam), None);
+    }
+
+    /// Scrolls one line down with `modifier` held, returning the camera's
+    /// movement and its scale afterwards
+    fn scroll_with(modifier: Option<KeyCode>) -> (Vec2, f32) {
+        let mut app = test_app();
+        let pan_cam = PanCam {
+            scroll_mapping: ScrollMapping::document(),
+            ..default()
+        };
+        let cam = spawn_cam(&mut app, pan_cam);
+        set_cursor(&mut app, vec2(75., 25.));
+        if let Some(modifier) = modifier {
+            keys(&mut app).press(modifier);
+        }
+        scroll(&mut app, -1.);
+        update(&mut app);
+        (translation(&app, cam), scale(&app, cam))
+    }
+
+    #[test]
+    fn plain_scroll_pans_vertically_with_document_mapping() {
+        assert_eq!(scroll_with(None), (vec2(0., -100.), 1.));
+    }
+
+    #[test]
+    fn shift_scroll_pans_horizontally_with_document_mapping() {
+        assert_eq!(scroll_with(Some(KeyCode::ShiftLeft)), (vec2(100., 0.), 1.));
+    }
+
+    #[test]
+    fn horizontal_scroll_pans_horizontally_while_vertical_scroll_zooms() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        let window = app
+            .world_mut()
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(app.world());
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+
+        // tilting the wheel right
+        app.world_mut().send_event(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: -1.,
+            y: 0.,
+            window,
+        });
+        update(&mut app);
+        assert_eq!(
+            (translation(&app, cam), scale(&app, cam)),
+            (vec2(100., 0.), 1.)
+        );
+
+        // swiping diagonally on a trackpad
+        app.world_mut().send_event(MouseWheel {
+            unit: MouseScrollUnit::Pixel,
+            x: 10.,
+            y: 10.,
+            window,
+        });
+        update(&mut app);
+        assert!((scale(&app, cam) - 0.99).abs() < 1e-5);
+        let x = translation(&app, cam).x;
+        assert!((89.9..=90.1).contains(&x));
+    }
+
+    #[test]
+    fn trackpad_scroll_behavior_pans_with_swipes_and_zooms_with_wheel() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                scroll_behavior: ScrollBehavior::Trackpad,
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+
+        scroll_in(&mut app, MouseScrollUnit::Pixel, 10.);
+        update(&mut app);
+        assert_eq!(
+            (translation(&app, cam), scale(&app, cam)),
+            (vec2(0., 10.), 1.)
+        );
+
+        // pinching, as reported by browsers
+        keys(&mut app).press(KeyCode::ControlLeft);
+        scroll_in(&mut app, MouseScrollUnit::Pixel, 10.);
+        update(&mut app);
+        assert!((scale(&app, cam) - 0.99).abs() < 1e-5);
+        keys(&mut app).release(KeyCode::ControlLeft);
+
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!(scale(&app, cam) < 0.99);
+        assert_eq!(translation(&app, cam), vec2(0., 10.));
+    }
+
+    #[test]
+    fn zoom_modifier_has_to_be_held_to_zoom() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_modifier: Some(KeyCode::AltLeft),
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.);
+
+        keys(&mut app).press(KeyCode::AltLeft);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!((scale(&app, cam) - 0.9).abs() < 1e-5);
+    }
+
+    #[test]
+    fn ctrl_scroll_zooms_with_document_mapping() {
+        let (translation, scale) = scroll_with(Some(KeyCode::ControlRight));
+        assert!((scale - 1.1).abs() < 1e-5);
+        // zoomed out around the cursor in the top right quadrant
+        assert!(translation.x < 0. && translation.y < 0.);
+    }
+
+    #[test]
+    fn scroll_only_zooms_cameras_in_scrolled_window() {
+        let mut app = test_app();
+        let primary_cam = spawn_cam(&mut app, PanCam::default());
+        let second_window = app.world_mut().spawn(Window::default()).id();
+        let second_cam = app
+            .world_mut()
+            .spawn((
+                Camera {
+                    target: RenderTarget::Window(WindowRef::Entity(second_window)),
+                    ..default()
+                },
+                mock_proj(vec2(100., 100.)),
+                Transform::default(),
+                PanCam::default(),
+            ))
+            .id();
+
+        app.world_mut().send_event(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.,
+            y: 1.,
+            window: second_window,
+        });
+        update(&mut app);
+        assert_eq!(scale(&app, primary_cam), 1.);
+        assert!(scale(&app, second_cam) < 1.);
+
+        let second_scale = scale(&app, second_cam);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!(scale(&app, primary_cam) < 1.);
+        assert_eq!(scale(&app, second_cam), second_scale);
+    }
+
+    #[test]
+    fn drag_only_pans_cameras_in_window_under_cursor() {
+        let mut app = test_app();
+        let primary_cam = spawn_cam(&mut app, PanCam::default());
+        let second_window = app
+            .world_mut()
+            .spawn(Window {
+                resolution: bevy::window::WindowResolution::new(200., 100.),
+                ..default()
+            })
+            .id();
+        let second_cam = app
+            .world_mut()
+            .spawn((
+                Camera {
+                    target: RenderTarget::Window(WindowRef::Entity(second_window)),
+                    ..default()
+                },
+                mock_proj(vec2(200., 100.)),
+                Transform::default(),
+                PanCam::default(),
+            ))
+            .id();
+        let set_second_cursor = |app: &mut App, pos: Vec2| {
+            app.world_mut()
+                .get_mut::<Window>(second_window)
+                .unwrap()
+                .set_cursor_position(Some(pos));
+        };
+
+        set_second_cursor(&mut app, vec2(150., 25.));
+        update(&mut app);
+        assert_eq!(cursor_world_pos(&app, primary_cam), None);
+        assert_eq!(cursor_world_pos(&app, second_cam), Some(vec2(50., 25.)));
+
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        set_second_cursor(&mut app, vec2(160., 25.));
+        update(&mut app);
+        assert_eq!(translation(&app, primary_cam), Vec2::ZERO);
+        assert_eq!(translation(&app, second_cam), vec2(-10., 0.));
+    }
+
+    #[test]
+    fn keys_only_pan_cameras_in_focused_window() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let primary_cam = spawn_cam(&mut app, PanCam::default());
+        let unfocused_window = app
+            .world_mut()
+            .spawn(Window {
+                focused: false,
+                ..default()
+            })
+            .id();
+        let unfocused_cam = app
+            .world_mut()
+            .spawn((
+                Camera {
+                    target: RenderTarget::Window(WindowRef::Entity(unfocused_window)),
+                    ..default()
+                },
+                mock_proj(vec2(100., 100.)),
+                Transform::default(),
+                PanCam::default(),
+            ))
+            .id();
+        set_cursor(&mut app, vec2(50., 50.));
+        app.world_mut()
+            .get_mut::<Window>(unfocused_window)
+            .unwrap()
+            .set_cursor_position(Some(vec2(50., 50.)));
+        update(&mut app);
+
+        keys(&mut app).press(KeyCode::ArrowRight);
+        update(&mut app);
+        update(&mut app);
+        assert!(translation(&app, primary_cam).x > 0.);
+        assert_eq!(translation(&app, unfocused_cam), Vec2::ZERO);
+    }
+
+    #[test]
+    fn side_by_side_viewports_only_react_to_cursor_inside() {
+        let mut app = test_app();
+        let spawn_half = |app: &mut App, x: u32| {
+            app.world_mut()
+                .spawn((
+                    Camera {
+                        viewport: Some(bevy::render::camera::Viewport {
+                            physical_position: UVec2::new(x, 0),
+                            physical_size: UVec2::new(50, 100),
+                            ..default()
+                        }),
+                        ..default()
+                    },
+                    mock_proj(vec2(50., 100.)),
+                    Transform::default(),
+                    PanCam::default(),
+                ))
+                .id()
+        };
+        let left = spawn_half(&mut app, 0);
+        let right = spawn_half(&mut app, 50);
+
+        set_cursor(&mut app, vec2(25., 50.));
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        // dragging into the other viewport keeps panning only the grabbed one
+        set_cursor(&mut app, vec2(75., 50.));
+        update(&mut app);
+        assert_eq!(translation(&app, left), vec2(-50., 0.));
+        assert_eq!(translation(&app, right), Vec2::ZERO);
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert_eq!(scale(&app, left), 1.);
+        assert!(scale(&app, right) < 1.);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn pan_cam_and_saved_views_round_trip_through_serde() {
+        let pan_cam = PanCam {
+            move_keys: DirectionKeys::wasd(),
+            edge_scroll: Some(EdgeScroll::default()),
+            max_x: 100.,
+            ..default()
+        };
+        let serialized = ron::to_string(&pan_cam).unwrap();
+        let deserialized: PanCam = ron::from_str(&serialized).unwrap();
+        assert_eq!(ron::to_string(&deserialized).unwrap(), serialized);
+        assert_eq!(deserialized.move_keys, DirectionKeys::wasd());
+
+        // missing fields use their defaults
+        let partial: PanCam = ron::from_str("(speed: 50.)").unwrap();
+        assert_eq!(partial.speed, 50.);
+        assert_eq!(partial.max_scale, f32::INFINITY);
+
+        let snapshot = PanCamSnapshot {
+            translation: Vec3::new(1., 2., 3.),
+            scale: 4.,
+        };
+        let serialized = ron::to_string(&snapshot).unwrap();
+        assert_eq!(
+            ron::from_str::<PanCamSnapshot>(&serialized).unwrap(),
+            snapshot
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn unbounded_limits_round_trip_through_json() {
+        let pan_cam = PanCam {
+            max_x: 100.,
+            ..default()
+        };
+        let serialized = serde_json::to_string(&pan_cam).unwrap();
+        let deserialized: PanCam = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), serialized);
+        assert_eq!(deserialized.min_x, f32::NEG_INFINITY);
+        assert_eq!(deserialized.max_x, 100.);
+        assert_eq!(deserialized.min_y, f32::NEG_INFINITY);
+        assert_eq!(deserialized.max_y, f32::INFINITY);
+        assert_eq!(deserialized.min_scale, pan_cam.min_scale);
+        assert_eq!(deserialized.max_scale, f32::INFINITY);
+    }
+
+    #[test]
+    fn pan_cam_inserted_through_reflection_gets_required_state() {
+        use std::any::TypeId;
+
+        let mut app = test_app();
+        let registry = app.world().resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let registration = registry.get(TypeId::of::<PanCam>()).unwrap();
+        assert!(registration.data::<ReflectDefault>().is_some());
+        // nested settings are registered too, so scenes can contain them
+        assert!(registry.get(TypeId::of::<Option<EdgeScroll>>()).is_some());
+
+        // like spawning the camera from a scene
+        let pan_cam = PanCam {
+            speed: 123.,
+            ..default()
+        };
+        let entity = app.world_mut().spawn_empty().id();
+        registration.data::<ReflectComponent>().unwrap().insert(
+            &mut app.world_mut().entity_mut(entity),
+            &*pan_cam.clone_value(),
+            &registry,
+        );
+        let entity = app.world().entity(entity);
+        assert_eq!(entity.get::<PanCam>().unwrap().speed, 123.);
+        assert!(entity.contains::<GrabState>());
+    }
+
+    #[test]
+    fn keyboard_pan_uses_configured_keys_and_scales_with_zoom() {
+        let pan_right_for_a_frame = |scale: f32, key: KeyCode| {
+            let mut app = test_app();
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+            let cam = spawn_cam(
+                &mut app,
+                PanCam {
+                    move_keys: DirectionKeys {
+                        right: vec![KeyCode::KeyL],
+                        ..DirectionKeys::NONE
+                    },
+                    speed: 100.,
+                    ..default()
+                },
+            );
+            set_view(&mut app, cam, Vec2::ZERO, scale);
+            set_cursor(&mut app, vec2(50., 50.));
+            update(&mut app);
+            keys(&mut app).press(key);
+            update(&mut app);
+            let before = translation(&app, cam);
+            update(&mut app);
+            translation(&app, cam) - before
+        };
+
+        assert_eq!(pan_right_for_a_frame(1., KeyCode::ArrowRight), Vec2::ZERO);
+        let delta = pan_right_for_a_frame(1., KeyCode::KeyL);
+        assert!((delta - vec2(10., 0.)).length() < 1e-4);
+        let zoomed_out_delta = pan_right_for_a_frame(2., KeyCode::KeyL);
+        assert!((zoomed_out_delta - 2. * delta).length() < 1e-4);
+    }
+
+    #[test]
+    fn camera_pressed_against_boundary_stays_still() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            16,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                max_x: 100.,
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(50., 50.));
+        keys(&mut app).press(KeyCode::ArrowRight);
+        for _ in 0..20 {
+            update(&mut app);
+        }
+        let resting = translation(&app, cam);
+        assert!((resting.x - 50.).abs() < 1e-3);
+
+        for i in 0..50 {
+            // simulate the projection area being recomputed with rounding errors
+            let wobble = if i % 2 == 0 { 1e-5 } else { -1e-5 };
+            let mut proj = app
+                .world_mut()
+                .get_mut::<OrthographicProjection>(cam)
+                .unwrap();
+            proj.area.max.x = 50. + wobble;
+            update(&mut app);
+            assert_eq!(translation(&app, cam), resting);
+        }
+    }
+
+    #[test]
+    fn soft_edges_decelerate_while_hard_edges_stop() {
+        let mut app = test_app();
+        let soft_left = PanCam {
+            min_x: -200.,
+            edge_softness: EdgeSoftness {
+                left: 100.,
+                ..default()
+            },
+            ..default()
+        };
+        let cam = start_grab(&mut app, soft_left, MouseButton::Left);
+        // 50 away from the limit of -150, halfway through the soft band
+        set_view(&mut app, cam, vec2(-100., 0.), 1.);
+        assert!((drag_right(&mut app, cam).x + 5.).abs() < 1e-4);
+
+        let mut app = test_app();
+        let soft_right = PanCam {
+            min_x: -200.,
+            edge_softness: EdgeSoftness {
+                right: 100.,
+                ..default()
+            },
+            ..default()
+        };
+        let cam = start_grab(&mut app, soft_right, MouseButton::Left);
+        set_view(&mut app, cam, vec2(-136., 0.), 1.);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+        assert_eq!(drag_right(&mut app, cam), vec2(-4., 0.));
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+    }
+
+    fn zoom_params() -> ZoomParams {
+        ZoomParams {
+            scale: 1.,
+            zoom_factor: 0.5,
+            min_scale: 0.1,
+            max_scale: 10.,
+            anchor: None,
+            area: Rect::new(-50., -50., 50., 50.),
+            translation: vec2(10., 20.),
+        }
+    }
+
+    #[test]
+    fn zoom_modes_scale_scrolling_differently() {
+        let zoom = |mode: ZoomMode, scale: f32, scroll: f32| {
+            scale * mode.zoom_factor(scale, scroll, 0.001)
+        };
+
+        // a notch in and a notch out only returns to the same scale when
+        // logarithmic
+        let round_trip = |mode| zoom(mode, zoom(mode, 2., 100.), -100.);
+        assert!((round_trip(ZoomMode::Multiplicative) - 2.).abs() > 1e-3);
+        assert!((round_trip(ZoomMode::Logarithmic) - 2.).abs() < 1e-5);
+        assert!((round_trip(ZoomMode::Linear) - 2.).abs() < 1e-5);
+
+        // linear steps are the same size at any scale
+        assert!((zoom(ZoomMode::Linear, 2., 100.) - 1.9).abs() < 1e-5);
+        assert!((zoom(ZoomMode::Linear, 0.5, 100.) - 0.4).abs() < 1e-5);
+        assert!((zoom(ZoomMode::Logarithmic, 2., 100.) - 2. * (-0.1f32).exp()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn linear_zoom_keeps_small_scales_positive() {
+        // a notch is larger than the scale itself
+        let factor = ZoomMode::Linear.zoom_factor(0.05, 100., 0.001);
+        assert_eq!(factor, MIN_LINEAR_ZOOM_FACTOR);
+
+        let mut scale = 0.05;
+        for _ in 0..10 {
+            scale *= ZoomMode::Linear.zoom_factor(scale, 100., 0.001);
+        }
+        assert!(scale > 0.);
+    }
+
+    #[test]
+    fn compute_zoom_clamps_scale() {
+        let params = ZoomParams {
+            zoom_factor: 0.01,
+            ..zoom_params()
+        };
+        assert_eq!(compute_zoom(params).0, 0.1);
+        let params = ZoomParams {
+            zoom_factor: 100.,
+            ..zoom_params()
+        };
+        assert_eq!(compute_zoom(params).0, 10.);
+    }
+
+    #[test]
+    fn compute_zoom_keeps_anchor_in_place() {
+        let params = ZoomParams {
+            anchor: Some(vec2(0.5, -1.)),
+            ..zoom_params()
+        };
+        let (scale, translation) = compute_zoom(params);
+        assert_eq!(scale, 0.5);
+        // the anchor was at (35, -30) and stays there
+        assert_eq!(translation, vec2(22.5, -5.));
+    }
+
+    #[test]
+    fn compute_zoom_at_limit_is_noop() {
+        let params = ZoomParams {
+            scale: 0.1,
+            anchor: Some(vec2(0.5, 0.5)),
+            ..zoom_params()
+        };
+        assert_eq!(compute_zoom(params), (0.1, vec2(10., 20.)));
+    }
+
+    /// Checks that dragging and zooming to the cursor are correct for a
+    /// projection showing `world_per_pixel` world units per logical pixel of
+    /// the 100x100 window
+    fn assert_pan_and_zoom_match_projection(proj: OrthographicProjection, world_per_pixel: f32) {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        *app.world_mut()
+            .get_mut::<OrthographicProjection>(cam)
+            .unwrap() = proj;
+        let scale_before = scale(&app, cam);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10. * world_per_pixel, 0.));
+
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+        // the cursor is now halfway between the center and the right edge
+        set_cursor(&mut app, vec2(75., 50.));
+        let half_width = 50. * world_per_pixel / scale_before;
+        let world_under_cursor = translation(&app, cam).x + 0.5 * half_width * scale_before;
+        scroll(&mut app, 1.);
+        update(&mut app);
+
+        let new_world_under_cursor = translation(&app, cam).x + 0.5 * half_width * scale(&app, cam);
+        assert!(scale(&app, cam) < scale_before);
+        assert!((new_world_under_cursor - world_under_cursor).abs() < 1e-4);
+    }
+
+    #[test]
+    fn pan_and_zoom_follow_fixed_vertical_projection() {
+        let mut proj = OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical {
+                viewport_height: 200.,
+            },
+            ..OrthographicProjection::default_2d()
+        };
+        proj.update(100., 100.);
+        assert_pan_and_zoom_match_projection(proj, 2.);
+    }
+
+    #[test]
+    fn pan_and_zoom_follow_scaled_window_size_projection() {
+        let mut proj = OrthographicProjection {
+            scaling_mode: ScalingMode::WindowSize,
+            scale: 2.,
+            ..OrthographicProjection::default_2d()
+        };
+        proj.update(100., 100.);
+        assert_pan_and_zoom_match_projection(proj, 2.);
+    }
+
+    #[test]
+    fn integer_zoom_magnet_captures_slow_zoom_only() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_focus: ZoomFocus::ScreenCenter,
+                integer_zoom_magnet: Some(IntegerZoomMagnet::default()),
+                ..default()
+            },
+        );
+
+        set_view(&mut app, cam, Vec2::ZERO, 1. / 1.96);
+        scroll_in(&mut app, MouseScrollUnit::Pixel, 1.);
+        update(&mut app);
+        assert!((scale(&app, cam) - 0.5).abs() < 1e-6);
+
+        // continued slow zooming leaves the integer ratio again
+        let mut ratio = 2.;
+        for _ in 0..5 {
+            scroll_in(&mut app, MouseScrollUnit::Pixel, 1.);
+            update(&mut app);
+            assert!(1. / scale(&app, cam) > ratio);
+            ratio = 1. / scale(&app, cam);
+        }
+
+        set_view(&mut app, cam, Vec2::ZERO, 1. / 1.96);
+        scroll(&mut app, 3.);
+        update(&mut app);
+        assert!(1. / scale(&app, cam) > 2.5);
+    }
+
+    #[test]
+    fn drag_and_keyboard_pan_add_up() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        keys(&mut app).press(KeyCode::ArrowUp);
+        // dragging moves 10 left, while the keyboard moves 200 * 0.1 up
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 20.));
+    }
+
+    #[test]
+    fn locked_zoom_starts_at_and_keeps_scale() {
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam::default().lock_zoom_at(Some(2.)),
+            MouseButton::Left,
+        );
+        assert_eq!(scale(&app, cam), 2.);
+
+        scroll(&mut app, 3.);
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 2.);
+        assert_ne!(drag_right(&mut app, cam), Vec2::ZERO);
+    }
+
+    #[test]
+    fn quick_drag_before_hold_delay_doesnt_pan() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                pan_hold_delay: Duration::from_millis(200),
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+
+        mouse(&mut app).press(MouseButton::Left);
+        for _ in 0..5 {
+            update(&mut app);
+        }
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+    }
+
+    #[test]
+    fn idle_frames_and_blocked_drags_dont_change_transform() {
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                min_x: -50.,
+                max_x: 50.,
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        #[derive(Resource, Default)]
+        struct TransformChanged(bool);
+        app.init_resource::<TransformChanged>().add_systems(
+            Last,
+            |query: Query<Ref<Transform>, With<PanCam>>, mut changed: ResMut<TransformChanged>| {
+                changed.0 = query.iter().any(|transform| transform.is_changed());
+            },
+        );
+        let transform_changed = |app: &App| app.world().resource::<TransformChanged>().0;
+        // on its first run, the system sees everything as changed
+        update(&mut app);
+
+        // dragging against the bounds
+        drag_right(&mut app, cam);
+        assert!(!transform_changed(&app));
+
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+        drag_right(&mut app, cam);
+        assert!(!transform_changed(&app));
+    }
+
+    #[test]
+    fn cursor_wraps_at_window_edge_while_dragging() {
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                wrap_cursor: true,
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        set_cursor(&mut app, vec2(99.5, 50.));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(-49.5, 0.));
+
+        // the warp to the left edge doesn't pan back
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+        assert_eq!(translation(&app, cam), vec2(-59.5, 0.));
+    }
+
+    #[test]
+    fn locked_axis_neither_pans_nor_zooms_to_cursor() {
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                pan_axes: BVec2::new(true, false),
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        set_cursor(&mut app, vec2(60., 60.));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(-10., 0.));
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+
+        set_cursor(&mut app, vec2(90., 10.));
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!(scale(&app, cam) < 1.);
+        let pos = translation(&app, cam);
+        assert!(pos.x > -10.);
+        assert_eq!(pos.y, 0.);
+    }
+
+    #[test]
+    fn horizontal_only_zoom_keeps_vertical_extent() {
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                zoom_axes: BVec2::new(true, false),
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        scroll(&mut app, 1.);
+        update(&mut app);
+
+        let stretch = app.world().get::<Transform>(cam).unwrap().scale;
+        assert_eq!(scale(&app, cam), 1.);
+        assert!(stretch.x < 1.);
+        assert_eq!(stretch.y, 1.);
+        // dragging still follows the cursor across the stretched view
+        assert!((drag_right(&mut app, cam) - vec2(-10. * stretch.x, 0.)).length() < 1e-4);
+    }
+
+    #[test]
+    fn world_deadzone_covers_same_world_distance_at_any_scale() {
+        // returns how many 10 pixel drags it takes to start panning
+        let drags_until_pan = |scale: f32| {
+            let mut app = test_app();
+            let cam = start_grab(
+                &mut app,
+                PanCam {
+                    pan_deadzone_world: 25.,
+                    ..default()
+                },
+                MouseButton::Left,
+            );
+            let mut proj = mock_proj(vec2(100., 100.));
+            proj.scale = scale;
+            proj.update(100., 100.);
+            *app.world_mut()
+                .get_mut::<OrthographicProjection>(cam)
+                .unwrap() = proj;
+            (1..10)
+                .find(|_| drag_right(&mut app, cam) != Vec2::ZERO)
+                .unwrap()
+        };
+        // 10 world units per drag need 3 drags to exceed 25, 20 need 2
+        assert_eq!(drags_until_pan(1.), 3);
+        assert_eq!(drags_until_pan(2.), 2);
+    }
+
+    #[test]
+    fn content_rect_stays_partly_visible_and_anchors_zoom() {
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                content_rect: Some(Rect::new(0., 0., 100., 100.)),
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        set_view(&mut app, cam, vec2(0., 50.), 1.);
+        for _ in 0..4 {
+            drag_right(&mut app, cam);
+        }
+        // the right quarter of the view still shows the content
+        assert_eq!(translation(&app, cam), vec2(-25., 50.));
+
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+        // the cursor is over empty space left of the content
+        set_cursor(&mut app, vec2(0., 50.));
+        scroll(&mut app, 1.);
+        update(&mut app);
+        // so the zoom is anchored at the content's left edge instead
+        let left_edge_x = translation(&app, cam).x + 0.5 * 50. * scale(&app, cam);
+        assert!(left_edge_x.abs() < 1e-4);
+    }
+
+    #[test]
+    fn zoom_sensitivity_and_pixels_per_line_are_configurable() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_sensitivity: Some(0.002),
+                pixels_per_line: Some(20.),
+                ..default()
+            },
+        );
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!((scale(&app, cam) - 0.96).abs() < 1e-6);
+
+        scroll_in(&mut app, MouseScrollUnit::Pixel, 20.);
+        update(&mut app);
+        assert!((scale(&app, cam) - 0.96 * 0.96).abs() < 1e-6);
+    }
+
+    #[test]
+    fn inverted_zoom_zooms_out_when_scrolling_up() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                invert_zoom: true,
+                ..default()
+            },
+        );
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!((scale(&app, cam) - 1.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn inverted_drag_moves_camera_with_cursor() {
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                invert_drag: true,
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        assert_eq!(drag_right(&mut app, cam), vec2(10., 0.));
+    }
+
+    #[test]
+    fn mouse_motion_input_pans_while_cursor_is_locked() {
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                pan_input: PanInputSource::MouseMotion,
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        // cursor positions are ignored
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+
+        app.world_mut()
+            .query_filtered::<&mut Window, With<PrimaryWindow>>()
+            .single_mut(app.world_mut())
+            .set_cursor_position(None);
+        app.world_mut().send_event(MouseMotion {
+            delta: vec2(10., 5.),
+        });
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(-10., 5.));
+    }
+
+    #[test]
+    fn lock_zoom_at_none_keeps_spawn_scale() {
+        let mut app = test_app();
+        let mut proj = mock_proj(vec2(100., 100.));
+        proj.scale = 1.5;
+        let cam = app
+            .world_mut()
+            .spawn((
+                Camera::default(),
+                proj,
+                Transform::default(),
+                PanCam::default().lock_zoom_at(None),
+            ))
+            .id();
+        let pan_cam = app.world().get::<PanCam>(cam).unwrap();
+        assert_eq!((pan_cam.min_scale, pan_cam.max_scale), (1.5, 1.5));
+
+        scroll(&mut app, 3.);
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.5);
+    }
+
+    #[test]
+    fn pan_only_ignores_scroll() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::pan_only(), MouseButton::Left);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+    }
+
+    #[test]
+    fn zoom_only_ignores_drag() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::zoom_only(), MouseButton::Left);
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!(scale(&app, cam) < 1.);
+    }
+
+    fn visibility_window() -> Window {
+        Window {
+            resolution: bevy::window::WindowResolution::new(100., 50.),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn visible_world_rect_follows_camera() {
+        let transform = GlobalTransform::from_xyz(10., 20., 0.);
+        let rect = PanCam::default().visible_world_rect(
+            &Camera::default(),
+            &mock_proj(vec2(100., 50.)),
+            &transform,
+            &visibility_window(),
+        );
+        assert_eq!(rect, Rect::new(-40., -5., 60., 45.));
+    }
+
+    #[test]
+    fn world_points_on_view_edge_are_visible() {
+        let pan_cam = PanCam::default();
+        let transform = GlobalTransform::from_xyz(10., 0., 0.);
+        let visible = |point| {
+            pan_cam.is_world_point_visible(
+                point,
+                &Camera::default(),
+                &mock_proj(vec2(100., 50.)),
+                &transform,
+                &visibility_window(),
+            )
+        };
+        assert!(visible(vec2(0., 0.)));
+        assert!(visible(vec2(60., 25.)));
+        assert!(visible(vec2(-40., -25.)));
+        assert!(!visible(vec2(-41., 0.)));
+        assert!(!visible(vec2(0., 25.1)));
+    }
+
+    #[test]
+    fn rotated_and_scaled_camera_shows_turned_view() {
+        let pan_cam = PanCam::default();
+        let transform = GlobalTransform::from(
+            Transform::from_xyz(10., 0., 0.)
+                .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2))
+                .with_scale(Vec3::splat(2.)),
+        );
+        let proj = mock_proj(vec2(100., 50.));
+        let window = visibility_window();
+        let rect = pan_cam.visible_world_rect(&Camera::default(), &proj, &transform, &window);
+        assert!(rect.min.abs_diff_eq(vec2(-40., -100.), 1e-4));
+        assert!(rect.max.abs_diff_eq(vec2(60., 100.), 1e-4));
+
+        let visible = |point| {
+            pan_cam.is_world_point_visible(point, &Camera::default(), &proj, &transform, &window)
+        };
+        // the view's long side is vertical once turned
+        assert!(visible(vec2(10., 95.)));
+        assert!(!visible(vec2(65., 0.)));
+    }
+
+    #[test]
+    fn max_empty_fraction_limits_zoom_out() {
+        let window_size = vec2(100., 100.);
+        let mut proj = mock_proj(window_size);
+        proj.scale = 10.;
+        constrain_proj_scale(
+            &mut proj,
+            vec2(100., 200.),
+            &(0.1..=f32::INFINITY),
+            window_size,
+            0.2,
+        );
+        let visible_width = 100. * proj.scale;
+        let empty_fraction = 1. - 100. / visible_width;
+        assert!((empty_fraction - 0.2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn bounds_margin_lets_camera_pan_past_bounds() {
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                min_x: -100.,
+                max_x: 100.,
+                bounds_margin: Some(BoundsMargin::World(10.)),
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        set_view(&mut app, cam, vec2(-50., 0.), 1.);
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+        assert_eq!(translation(&app, cam), vec2(-60., 0.));
+
+        // 20% of the view's width
+        app.world_mut()
+            .get_mut::<PanCam>(cam)
+            .unwrap()
+            .bounds_margin = Some(BoundsMargin::View(0.2));
+        set_view(&mut app, cam, vec2(-100., 0.), 1.);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(-70., 0.));
+    }
+
+    #[test]
+    fn view_larger_than_bounds_keeps_bounds_in_view() {
+        let aabb = Aabb2d {
+            min: vec2(0., 0.),
+            max: vec2(100., 100.),
+        };
+        let view_size = vec2(125., 50.);
+        assert_eq!(
+            clamp_to_safe_zone(vec2(500., 500.), aabb, view_size),
+            vec2(62.5, 75.)
+        );
+        assert_eq!(
+            clamp_to_safe_zone(vec2(-500., -500.), aabb, view_size),
+            vec2(37.5, 25.)
+        );
+    }
+
+    #[test]
+    fn panning_and_zooming_out_never_show_outside_bounds() {
+        let mut app = test_app();
+        let bounds = Rect::new(-150., -100., 150., 100.);
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                min_x: bounds.min.x,
+                min_y: bounds.min.y,
+                max_x: bounds.max.x,
+                max_y: bounds.max.y,
+                ..default()
+            },
+        );
+        // updates the projection's area like the camera system would
+        let update_and_refresh_area = |app: &mut App| {
+            update(app);
+            let mut proj = app
+                .world_mut()
+                .get_mut::<OrthographicProjection>(cam)
+                .unwrap();
+            proj.update(100., 100.);
+        };
+        let visible_rect = |app: &App| {
+            let proj = app.world().get::<OrthographicProjection>(cam).unwrap();
+            Rect::from_center_size(translation(app, cam), proj.area.size())
+        };
+
+        set_cursor(&mut app, vec2(10., 50.));
+        for _ in 0..3 {
+            scroll(&mut app, -5.);
+            update_and_refresh_area(&mut app);
+            assert_eq!(visible_rect(&app).union(bounds), bounds);
+        }
+        assert_eq!(scale(&app, cam), 2.);
+
+        mouse(&mut app).press(MouseButton::Left);
+        update_and_refresh_area(&mut app);
+        for x in [20., 30., 40., 50., 60.] {
+            set_cursor(&mut app, vec2(x, 50.));
+            update_and_refresh_area(&mut app);
+            assert_eq!(visible_rect(&app).union(bounds), bounds);
+        }
+        assert_eq!(translation(&app, cam), vec2(-50., 0.));
+    }
+
+    #[test]
+    fn bounds_change_updates_enforced_max_scale() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                max_empty_fraction: 0.5,
+                ..default()
+            },
+        );
+        app.world_mut()
+            .get_mut::<OrthographicProjection>(cam)
+            .unwrap()
+            .scale = 10.;
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 10.);
+
+        let mut pan_cam = app.world_mut().get_mut::<PanCam>(cam).unwrap();
+        pan_cam.min_x = -100.;
+        pan_cam.max_x = 100.;
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 4.);
+    }
+
+    #[test]
+    fn bounds_changed_late_in_frame_apply_same_frame() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        set_view(&mut app, cam, Vec2::ZERO, 10.);
+        update(&mut app);
+
+        app.add_systems(
+            Update,
+            (move |mut query: Query<&mut PanCam>| {
+                let mut pan_cam = query.get_mut(cam).unwrap();
+                pan_cam.min_x = -50.;
+                pan_cam.max_x = 50.;
+            })
+            .after(enforce_camera_limits),
+        );
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.);
+    }
+
+    #[test]
+    fn bounds_from_aabbs_unions_rects() {
+        assert_eq!(PanCam::bounds_from_aabbs(std::iter::empty()), None);
+
+        let rects = [
+            Rect::new(0., 0., 10., 10.),
+            Rect::new(-5., 20., 3., 30.),
+            Rect::new(40., -8., 41., -7.),
+        ];
+        let bounds = PanCam::bounds_from_aabbs(rects.into_iter()).unwrap();
+        assert_eq!(bounds, Rect::new(-5., -8., 41., 30.));
+
+        let mut pan_cam = PanCam::default();
+        pan_cam.set_bounds(bounds);
+        assert_eq!(pan_cam.rect(), bounds);
+    }
+
+    #[test]
+    fn min_content_fraction_caps_zoom_out() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                min_x: -50.,
+                max_x: 50.,
+                max_empty_fraction: 0.99,
+                min_content_fraction: 0.2,
+                ..default()
+            },
+        );
+        update(&mut app);
+        scroll(&mut app, -100.);
+        update(&mut app);
+
+        let visible_width = 100. * scale(&app, cam);
+        assert!((100. / visible_width - 0.2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn zoom_out_to_fit_bounds_stops_when_whole_bounds_are_visible() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                min_x: -100.,
+                max_x: 100.,
+                min_y: -50.,
+                max_y: 50.,
+                zoom_out_to_fit_bounds: true,
+                ..default()
+            },
+        );
+        update(&mut app);
+        scroll(&mut app, -100.);
+        update(&mut app);
+        // The bounds' width fills the window, with room to spare vertically
+        assert!((scale(&app, cam) - 2.).abs() < 1e-5);
 
-    /// Simple mock function to construct a square projection from a window size
-    fn mock_proj(window_size: Vec2) -> OrthographicProjection {
-        let mut proj = OrthographicProjection::default_2d();
-        proj.update(window_size.x, window_size.y);
-        proj
+        // Widening the window makes the height the limit instead
+        resize_window(&mut app, vec2(400., 100.));
+        update(&mut app);
+        scroll(&mut app, -100.);
+        update(&mut app);
+        assert!((scale(&app, cam) - 1.).abs() < 1e-5);
     }
 
     #[test]
@@ -447,7 +5016,7 @@ mod tests {
         let window_size = vec2(100., 100.);
         let proj = mock_proj(window_size);
         assert_eq!(
-            max_scale_within_bounds(vec2(100., INFINITY), &proj, window_size).x,
+            max_scale_within_bounds(vec2(100., f32::INFINITY), &proj, window_size).x,
             1.
         );
     }
@@ -458,7 +5027,7 @@ mod tests {
         let window_size = vec2(100., 100.);
         let proj = mock_proj(window_size);
         assert_eq!(
-            max_scale_within_bounds(vec2(50., INFINITY), &proj, window_size).x,
+            max_scale_within_bounds(vec2(50., f32::INFINITY), &proj, window_size).x,
             0.5
         );
     }
@@ -469,7 +5038,7 @@ mod tests {
         let window_size = vec2(100., 100.);
         let proj = mock_proj(window_size);
         assert_eq!(
-            max_scale_within_bounds(vec2(200., INFINITY), &proj, window_size).x,
+            max_scale_within_bounds(vec2(200., f32::INFINITY), &proj, window_size).x,
             2.
         );
     }
@@ -479,7 +5048,7 @@ mod tests {
         let window_size = vec2(100., 100.);
         let proj = mock_proj(window_size);
         assert_eq!(
-            max_scale_within_bounds(vec2(INFINITY, 100.), &proj, window_size).y,
+            max_scale_within_bounds(vec2(f32::INFINITY, 100.), &proj, window_size).y,
             1.
         );
     }
@@ -490,7 +5059,7 @@ mod tests {
         let window_size = vec2(100., 100.);
         let proj = mock_proj(window_size);
         assert_eq!(
-            max_scale_within_bounds(vec2(INFINITY, 50.), &proj, window_size).y,
+            max_scale_within_bounds(vec2(f32::INFINITY, 50.), &proj, window_size).y,
             0.5
         );
     }
@@ -501,7 +5070,7 @@ mod tests {
         let window_size = vec2(100., 100.);
         let proj = mock_proj(window_size);
         assert_eq!(
-            max_scale_within_bounds(vec2(INFINITY, 200.), &proj, window_size).y,
+            max_scale_within_bounds(vec2(f32::INFINITY, 200.), &proj, window_size).y,
             2.
         );
     }
@@ -0,0 +1,142 @@
+use bevy::{prelude::*, utils::HashMap};
+
+/// Links cameras so panning or zooming one pans and zooms all the others in
+/// the same group, e.g. for before and after comparisons or several windows
+/// showing the same document
+///
+/// Each camera shows the group's view moved by its `offset` and with its
+/// projection scale multiplied by its `scale_ratio`. When several cameras in a
+/// group are moved in the same frame, the one with the lowest `Entity` wins.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct PanCamLink {
+    /// The group of cameras to move together
+    pub group: u32,
+    /// World units this camera is moved from the group's position
+    pub offset: Vec2,
+    /// How much further this camera is zoomed out than the group
+    ///
+    /// Ratios that aren't positive are treated as `1.`.
+    pub scale_ratio: f32,
+    /// The view this camera was last given
+    #[reflect(ignore)]
+    synced: Option<(Vec2, f32)>,
+}
+
+impl PanCamLink {
+    /// Links a camera to `group`, without offset
+    pub fn new(group: u32) -> Self {
+        Self {
+            group,
+            offset: Vec2::ZERO,
+            scale_ratio: 1.,
+            synced: None,
+        }
+    }
+
+    /// `scale_ratio`, or `1.` if it isn't positive, so scales stay positive
+    fn valid_scale_ratio(&self) -> f32 {
+        if self.scale_ratio > 0. && self.scale_ratio.is_finite() {
+            self.scale_ratio
+        } else {
+            1.
+        }
+    }
+}
+
+/// Moves linked cameras along with the camera of their group that moved
+pub fn sync_linked_cameras(
+    mut query: Query<(
+        Entity,
+        &mut PanCamLink,
+        &mut Transform,
+        &mut OrthographicProjection,
+    )>,
+) {
+    // The camera that moved in each group, and the group's new view
+    let mut leaders: HashMap<u32, (Entity, Vec2, f32)> = HashMap::new();
+    for (entity, link, transform, proj) in &query {
+        let view = (transform.translation.truncate(), proj.scale);
+        if link.synced == Some(view) {
+            continue;
+        }
+        let group_view = (view.0 - link.offset, view.1 / link.valid_scale_ratio());
+        leaders
+            .entry(link.group)
+            .and_modify(|leader| {
+                if entity < leader.0 {
+                    *leader = (entity, group_view.0, group_view.1);
+                }
+            })
+            .or_insert((entity, group_view.0, group_view.1));
+    }
+    if leaders.is_empty() {
+        return;
+    }
+
+    for (entity, mut link, mut transform, mut proj) in &mut query {
+        let Some(&(leader, group_pos, group_scale)) = leaders.get(&link.group) else {
+            continue;
+        };
+        if entity != leader {
+            let translation = group_pos + link.offset;
+            if transform.translation.truncate() != translation {
+                transform.translation = translation.extend(transform.translation.z);
+            }
+            let scale = group_scale * link.valid_scale_ratio();
+            if proj.scale != scale {
+                proj.scale = scale;
+            }
+        }
+        link.synced = Some((transform.translation.truncate(), proj.scale));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+
+    use super::*;
+    use crate::{test_utils::*, PanCam};
+
+    #[test]
+    fn linked_cameras_pan_and_zoom_together() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        app.world_mut().entity_mut(cam).insert(PanCamLink::new(0));
+        let linked = spawn_cam(&mut app, PanCam::default());
+        app.world_mut().entity_mut(linked).insert(PanCamLink {
+            offset: vec2(1000., 0.),
+            scale_ratio: 2.,
+            ..PanCamLink::new(0)
+        });
+        update(&mut app);
+        assert_eq!(translation(&app, linked), vec2(1000., 0.));
+        assert_eq!(scale(&app, linked), 2.);
+
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+        assert_eq!(translation(&app, linked), vec2(990., 0.));
+        set_view(&mut app, linked, vec2(1000., 0.), 4.);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+        assert_eq!(scale(&app, cam), 2.);
+    }
+
+    #[test]
+    fn non_positive_scale_ratios_link_scales_one_to_one() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        app.world_mut().entity_mut(cam).insert(PanCamLink::new(0));
+        let linked = spawn_cam(&mut app, PanCam::default());
+        app.world_mut().entity_mut(linked).insert(PanCamLink {
+            scale_ratio: 0.,
+            ..PanCamLink::new(0)
+        });
+        update(&mut app);
+
+        set_view(&mut app, linked, Vec2::ZERO, 2.);
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 2.);
+        assert_eq!(scale(&app, linked), 2.);
+    }
+}
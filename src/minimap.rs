@@ -0,0 +1,375 @@
+use bevy::{
+    math::{vec2, Isometry2d},
+    prelude::*,
+    render::{camera::Viewport, view::RenderLayers},
+    utils::HashSet,
+    window::PrimaryWindow,
+};
+
+use crate::{camera_window, PanCam, PanCamAction, PanCamCommand, PanCamInputBlockers};
+
+/// The corner of the window a [`PanCamMinimap`] is shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum MinimapCorner {
+    /// The top left corner
+    TopLeft,
+    /// The top right corner
+    TopRight,
+    /// The bottom left corner
+    BottomLeft,
+    /// The bottom right corner
+    #[default]
+    BottomRight,
+}
+
+/// Turns a camera into an overview of a `PanCam` camera's world, shown in a
+/// corner of the window
+///
+/// The minimap shows the `target`'s whole bounded area, with a rectangle
+/// around the part the target currently shows. Clicking or dragging in the
+/// minimap moves the target there. Input over the minimap is kept from the
+/// target through its `"minimap"` source of [`PanCamInputBlockers`], so other
+/// cameras, e.g. in split screen, keep responding.
+///
+/// ```rust ignore
+/// let main = commands.spawn((Camera2d, PanCam::default())).id();
+/// commands.spawn(PanCamMinimap::new(main));
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+#[require(Camera2d, Camera(minimap_camera), RenderLayers(minimap_render_layers))]
+pub struct PanCamMinimap {
+    /// The `PanCam` camera shown and moved by the minimap
+    pub target: Entity,
+    /// The world area shown
+    ///
+    /// When `None`, the target's `content_rect` is shown, or else its bounds.
+    /// The minimap isn't updated while neither is set.
+    pub area: Option<Rect>,
+    /// The corner of the window the minimap is shown in
+    pub corner: MinimapCorner,
+    /// The size of the minimap, in logical pixels
+    pub size: Vec2,
+    /// The distance between the minimap and the window's edges, in logical
+    /// pixels
+    pub margin: f32,
+    /// The color of the rectangle around the target's view
+    pub view_rect_color: Color,
+    #[reflect(ignore)]
+    view: Option<MinimapView>,
+    #[reflect(ignore)]
+    dragging: bool,
+}
+
+impl PanCamMinimap {
+    /// The render layer the rectangle around the target's view is drawn on,
+    /// which only minimap cameras see
+    pub const VIEW_RECT_LAYER: usize = 31;
+
+    /// A minimap of `target` in the bottom right corner of the window
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            area: None,
+            corner: MinimapCorner::BottomRight,
+            size: Vec2::splat(200.),
+            margin: 10.,
+            view_rect_color: Color::WHITE,
+            view: None,
+            dragging: false,
+        }
+    }
+
+    /// The area of a window of `window_size` the minimap is shown in, in
+    /// logical pixels
+    fn viewport_rect(&self, window_size: Vec2) -> Rect {
+        let size = self.size.min(window_size - 2. * self.margin).max(Vec2::ONE);
+        let far = window_size - self.margin - size;
+        let min = match self.corner {
+            MinimapCorner::TopLeft => Vec2::splat(self.margin),
+            MinimapCorner::TopRight => vec2(far.x, self.margin),
+            MinimapCorner::BottomLeft => vec2(self.margin, far.y),
+            MinimapCorner::BottomRight => far,
+        };
+        Rect::from_corners(min, min + size)
+    }
+}
+
+fn minimap_camera() -> Camera {
+    Camera {
+        order: 1,
+        // The main camera already cleared the window
+        clear_color: ClearColorConfig::None,
+        ..default()
+    }
+}
+
+fn minimap_render_layers() -> RenderLayers {
+    RenderLayers::from_layers(&[0, PanCamMinimap::VIEW_RECT_LAYER])
+}
+
+/// Where a minimap was last shown
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MinimapView {
+    /// The minimap's area of the window, in logical pixels
+    viewport: Rect,
+    /// The world position at the center of the minimap
+    center: Vec2,
+    /// World units per logical pixel
+    scale: f32,
+}
+
+impl MinimapView {
+    /// The world position under a window position
+    fn to_world(self, window_pos: Vec2) -> Vec2 {
+        let offset = (window_pos - self.viewport.center()) * vec2(1., -1.);
+        self.center + offset * self.scale
+    }
+}
+
+/// The gizmos drawn on minimaps
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct PanCamMinimapGizmos;
+
+/// Places minimap cameras in their corner, fits them to the area they show and
+/// draws their target's view
+#[allow(clippy::type_complexity)]
+pub fn sync_minimaps(
+    mut minimaps: Query<
+        (
+            &mut PanCamMinimap,
+            &mut Camera,
+            &mut OrthographicProjection,
+            &mut Transform,
+        ),
+        Without<PanCam>,
+    >,
+    targets: Query<
+        (&PanCam, &Camera, &OrthographicProjection, &GlobalTransform),
+        Without<PanCamMinimap>,
+    >,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut gizmos: Gizmos<PanCamMinimapGizmos>,
+) {
+    let primary_window = primary_window.get_single().ok();
+
+    for (mut minimap, mut camera, mut proj, mut transform) in &mut minimaps {
+        let target = targets.get(minimap.target).ok();
+        let area = target.and_then(|(pan_cam, ..)| {
+            minimap
+                .area
+                .or(pan_cam.content_rect)
+                .or_else(|| Some(pan_cam.rect()).filter(|rect| rect.size().is_finite()))
+        });
+        let window = camera_window(&camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok());
+        let (
+            Some((target_pan_cam, target_camera, target_proj, target_transform)),
+            Some(area),
+            Some(window),
+        ) = (target, area, window)
+        else {
+            minimap.view = None;
+            continue;
+        };
+
+        let viewport = minimap.viewport_rect(window.size());
+        let physical_position = (viewport.min * window.scale_factor()).as_uvec2();
+        let physical_size = (viewport.size() * window.scale_factor())
+            .as_uvec2()
+            .max(UVec2::ONE);
+        if camera
+            .viewport
+            .as_ref()
+            .map(|viewport| (viewport.physical_position, viewport.physical_size))
+            != Some((physical_position, physical_size))
+        {
+            camera.viewport = Some(Viewport {
+                physical_position,
+                physical_size,
+                ..default()
+            });
+        }
+
+        let scale = (area.size() / viewport.size()).max_element();
+        if proj.scale != scale {
+            proj.scale = scale;
+        }
+        let center = area.center();
+        if transform.translation.truncate() != center {
+            transform.translation = center.extend(transform.translation.z);
+        }
+        minimap.view = Some(MinimapView {
+            viewport,
+            center,
+            scale,
+        });
+
+        let Some(target_window) = camera_window(target_camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+        let view_rect = target_pan_cam.visible_world_rect(
+            target_camera,
+            target_proj,
+            target_transform,
+            target_window,
+        );
+        gizmos.rect_2d(
+            Isometry2d::from_translation(view_rect.center()),
+            view_rect.size(),
+            minimap.view_rect_color,
+        );
+    }
+}
+
+/// Moves the targets of minimaps that are clicked or dragged in
+pub fn navigate_minimaps(
+    mut minimaps: Query<(&mut PanCamMinimap, &Camera)>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut blockers: ResMut<PanCamInputBlockers>,
+    mut pan_cam_commands: EventWriter<PanCamCommand>,
+) {
+    let primary_window = primary_window.get_single().ok();
+    let mut hovered_targets = HashSet::new();
+
+    for (mut minimap, camera) in &mut minimaps {
+        if !mouse_buttons.pressed(MouseButton::Left) {
+            minimap.dragging = false;
+        }
+        let Some(view) = minimap.view else {
+            minimap.dragging = false;
+            continue;
+        };
+        let Some(cursor) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+            .and_then(Window::cursor_position)
+        else {
+            continue;
+        };
+
+        let over = view.viewport.contains(cursor);
+        if over && mouse_buttons.just_pressed(MouseButton::Left) {
+            minimap.dragging = true;
+        }
+        if over || minimap.dragging {
+            hovered_targets.insert(minimap.target);
+        }
+        if minimap.dragging {
+            pan_cam_commands.send(PanCamCommand::new(
+                minimap.target,
+                PanCamAction::MoveTo(view.to_world(cursor)),
+                0.,
+            ));
+        }
+    }
+
+    let unhovered: Vec<_> = blockers
+        .cameras_blocked_by("minimap")
+        .filter(|target| !hovered_targets.contains(target))
+        .collect();
+    for target in unhovered {
+        blockers.set_for_camera(target, "minimap", false);
+    }
+    for target in hovered_targets {
+        if !blockers.is_camera_blocked_by(target, "minimap") {
+            blockers.set_for_camera(target, "minimap", true);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn viewport_is_placed_in_its_corner() {
+        let mut minimap = PanCamMinimap::new(Entity::PLACEHOLDER);
+        minimap.size = Vec2::splat(40.);
+        let window_size = vec2(200., 100.);
+        assert_eq!(
+            minimap.viewport_rect(window_size),
+            Rect::new(150., 50., 190., 90.)
+        );
+        minimap.corner = MinimapCorner::TopLeft;
+        assert_eq!(
+            minimap.viewport_rect(window_size),
+            Rect::new(10., 10., 50., 50.)
+        );
+    }
+
+    #[test]
+    fn minimap_shows_bounds_and_moves_target_when_clicked() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                min_x: -500.,
+                max_x: 500.,
+                min_y: -500.,
+                max_y: 500.,
+                ..default()
+            },
+        );
+        let minimap = app
+            .world_mut()
+            .spawn(PanCamMinimap {
+                size: Vec2::splat(40.),
+                ..PanCamMinimap::new(cam)
+            })
+            .id();
+        update(&mut app);
+        assert_eq!(scale(&app, minimap), 25.);
+        let viewport = app.world().get::<Camera>(minimap).unwrap().viewport.clone();
+        assert_eq!(viewport.unwrap().physical_position, UVec2::splat(50));
+
+        // 10 pixels right of and above the minimap's center
+        set_cursor(&mut app, vec2(80., 60.));
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(250., 250.));
+        assert!(app
+            .world()
+            .resource::<PanCamInputBlockers>()
+            .is_camera_blocked_by(cam, "minimap"));
+    }
+
+    #[test]
+    fn hovering_minimap_only_blocks_its_target() {
+        let mut app = test_app();
+        let bounds = PanCam {
+            min_x: -500.,
+            max_x: 500.,
+            min_y: -500.,
+            max_y: 500.,
+            ..default()
+        };
+        let cam = spawn_cam(&mut app, bounds.clone());
+        let other = spawn_cam(&mut app, bounds);
+        app.world_mut().spawn(PanCamMinimap {
+            size: Vec2::splat(40.),
+            ..PanCamMinimap::new(cam)
+        });
+        update(&mut app);
+
+        // Over the minimap in the bottom right corner
+        set_cursor(&mut app, vec2(80., 60.));
+        update(&mut app);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.);
+        assert!(scale(&app, other) < 1.);
+
+        set_cursor(&mut app, vec2(20., 20.));
+        update(&mut app);
+        assert!(!app
+            .world()
+            .resource::<PanCamInputBlockers>()
+            .is_camera_blocked_by(cam, "minimap"));
+    }
+}
@@ -0,0 +1,225 @@
+use bevy::{math::vec2, prelude::*};
+
+use crate::{
+    inertia::InertiaState, panned_position, view_to_world_offset, visible_area_size, GrabState,
+    PanCam, PanCamSettings,
+};
+
+/// Lets dragging pull the camera past its bounds, resisting more the further
+/// it goes, and springs it back once the drag is released
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Overscroll {
+    /// The furthest the camera can be pulled past a bound, as a fraction of
+    /// the view's size along that axis
+    pub max_fraction: f32,
+    /// About how many seconds the camera takes to spring back into the bounds
+    ///
+    /// After this time, 99% of the overshoot has been undone.
+    pub spring_back: f32,
+}
+
+impl Default for Overscroll {
+    fn default() -> Self {
+        Self {
+            max_fraction: 0.2,
+            spring_back: 0.3,
+        }
+    }
+}
+
+impl Overscroll {
+    /// How far the camera is shown past its bounds after being dragged
+    /// `excess` past them, approaching `max` on each axis
+    fn rubber_band(excess: Vec2, max: Vec2) -> Vec2 {
+        let band = |excess: f32, max: f32| {
+            if max > 0. {
+                excess * max / (excess.abs() + max)
+            } else {
+                0.
+            }
+        };
+        vec2(band(excess.x, max.x), band(excess.y, max.y))
+    }
+
+    /// The inverse of `rubber_band`
+    fn excess(offset: Vec2, max: Vec2) -> Vec2 {
+        let unband = |offset: f32, max: f32| {
+            if max > 0. {
+                let offset = offset.clamp(-0.999 * max, 0.999 * max);
+                offset * max / (max - offset.abs())
+            } else {
+                0.
+            }
+        };
+        vec2(unband(offset.x, max.x), unband(offset.y, max.y))
+    }
+}
+
+/// How far a camera is currently shown past its bounds
+#[derive(Component, Default)]
+pub struct OverscrollState {
+    /// The offset from the closest position within the bounds, in world units
+    offset: Vec2,
+}
+
+impl OverscrollState {
+    /// Whether the camera is past its bounds, springing back unless dragged
+    pub(crate) fn is_springing_back(&self) -> bool {
+        self.offset != Vec2::ZERO
+    }
+}
+
+/// Drags the camera by `movement` in world units along the axes of its view,
+/// like [`pan_camera`](crate::pan_camera), but lets it overshoot the bounds. Returns the distance
+/// actually moved.
+pub(crate) fn pan_camera_elastic(
+    pan_cam: &PanCam,
+    overscroll: &Overscroll,
+    state: &mut OverscrollState,
+    transform: &mut Mut<Transform>,
+    movement: Vec2,
+    proj_area_size: Vec2,
+) -> f32 {
+    let max = overscroll.max_fraction * proj_area_size;
+    let mut excess = Overscroll::excess(state.offset, max);
+    let world_movement = Vec2::select(
+        pan_cam.pan_axes,
+        view_to_world_offset(transform, movement),
+        Vec2::ZERO,
+    );
+
+    // Dragging back towards the bounds first takes up the overshoot
+    let mut remaining = world_movement;
+    for axis in 0..2 {
+        if excess[axis] == 0. {
+            continue;
+        }
+        let total = excess[axis] + remaining[axis];
+        if total * excess[axis] > 0. {
+            excess[axis] = total;
+            remaining[axis] = 0.;
+        } else {
+            excess[axis] = 0.;
+            remaining[axis] = total;
+        }
+    }
+
+    // The camera is panned from its position without overshoot, and only
+    // written to once the overshoot is added back
+    let old_pos = transform.translation.truncate();
+    let base = old_pos - state.offset;
+    let base_transform = Transform {
+        translation: base.extend(transform.translation.z),
+        ..**transform
+    };
+    let view_movement = (transform.rotation.inverse() * remaining.extend(0.)).truncate();
+    let new_base = panned_position(pan_cam, &base_transform, view_movement, proj_area_size);
+
+    // Whatever the bounds held back pulls the camera past them
+    excess += remaining - (new_base - base);
+    state.offset = Overscroll::rubber_band(excess, max);
+
+    let new_pos = new_base + state.offset;
+    if new_pos != old_pos {
+        transform.translation = new_pos.extend(transform.translation.z);
+    }
+    new_pos.distance(old_pos)
+}
+
+/// Springs cameras that were dragged past their bounds back once released
+pub fn spring_back_overscroll(
+    mut query: Query<(
+        &PanCam,
+        &GrabState,
+        &mut OverscrollState,
+        &mut InertiaState,
+        &mut Transform,
+        &OrthographicProjection,
+    )>,
+    time: Res<Time>,
+    settings: Res<PanCamSettings>,
+) {
+    for (pan_cam, grab_state, mut state, mut inertia_state, mut transform, proj) in &mut query {
+        if state.offset == Vec2::ZERO || grab_state.grabbing {
+            continue;
+        }
+        // Springing back replaces gliding on
+        inertia_state.stop();
+
+        let Some(overscroll) = pan_cam.overscroll else {
+            state.offset = Vec2::ZERO;
+            continue;
+        };
+
+        // The camera was moved back within the bounds by something else
+        let proj_area_size = visible_area_size(proj, &transform);
+        let base = transform.translation.truncate() - state.offset;
+        if pan_cam.clamp_position(base, base, proj_area_size) != base {
+            state.offset = Vec2::ZERO;
+            continue;
+        }
+
+        let t = if overscroll.spring_back > 0. && !settings.reduced_motion {
+            0.01f32.powf(time.delta_secs() / overscroll.spring_back)
+        } else {
+            0.
+        };
+        let mut offset = state.offset * t;
+        if offset.length() < 0.01 * proj.scale {
+            offset = Vec2::ZERO;
+        }
+        state.offset = offset;
+        transform.translation = (base + offset).extend(transform.translation.z);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::{test_utils::*, PanInertia};
+
+    #[test]
+    fn overscroll_stretches_past_bounds_and_springs_back() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                min_x: -100.,
+                max_x: 100.,
+                overscroll: Some(Overscroll::default()),
+                inertia: Some(PanInertia::default()),
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        set_view(&mut app, cam, vec2(-50., 0.), 1.);
+
+        // 20% of the view's width at most, resisting more the further it goes
+        drag_right(&mut app, cam);
+        assert!((translation(&app, cam).x - (-50. - 20. / 3.)).abs() < 1e-4);
+        drag_right(&mut app, cam);
+        assert!((translation(&app, cam).x + 60.).abs() < 1e-4);
+
+        // dragging back first takes up the overshoot
+        set_cursor(&mut app, vec2(60., 50.));
+        update(&mut app);
+        assert!((translation(&app, cam).x - (-50. - 20. / 3.)).abs() < 1e-4);
+
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+        let springing = translation(&app, cam).x;
+        assert!(springing > -50. - 20. / 3. && springing < -50.);
+        for _ in 0..10 {
+            update(&mut app);
+        }
+        assert!((translation(&app, cam).x + 50.).abs() < 1e-4);
+    }
+}
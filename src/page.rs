@@ -0,0 +1,200 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    camera_window, pan_camera, IntendedPan, PanCam, PanCamInputBlockers, PanCamSettings,
+    PanCamStats, RawInputFilter,
+};
+
+/// Paging movement of a camera that hasn't been applied yet
+#[derive(Component, Default)]
+pub struct PageState {
+    /// The total movement of the current animation
+    offset: Vec2,
+    /// Seconds since the current animation started
+    elapsed: f32,
+}
+
+impl PageState {
+    /// The part of the current animation still to be applied
+    fn remaining(&self, duration: f32) -> Vec2 {
+        self.offset * (1. - ease(self.progress(duration)))
+    }
+
+    fn progress(&self, duration: f32) -> f32 {
+        if duration > 0. {
+            (self.elapsed / duration).min(1.)
+        } else {
+            1.
+        }
+    }
+}
+
+/// Eases out, so paging responds immediately and settles smoothly
+fn ease(t: f32) -> f32 {
+    1. - (1. - t).powi(3)
+}
+
+/// Pans cameras by a page when their `page_keys` are pressed
+///
+/// Pressing again while a page is still animating continues from where that
+/// page would have ended, so repeated presses move by whole pages.
+#[allow(clippy::too_many_arguments)]
+pub fn do_camera_page_pan(
+    keyboard_buttons: Res<ButtonInput<KeyCode>>,
+    mut query: Query<
+        (
+            Entity,
+            &PanCam,
+            &Camera,
+            &mut PageState,
+            &mut Transform,
+            &OrthographicProjection,
+        ),
+        RawInputFilter,
+    >,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    blockers: Res<PanCamInputBlockers>,
+    time: Res<Time>,
+    mut stats: ResMut<PanCamStats>,
+    mut intended_pans: EventWriter<IntendedPan>,
+    settings: Res<PanCamSettings>,
+) {
+    let primary_window = primary_window.get_single().ok();
+
+    for (entity, pan_cam, camera, mut state, mut transform, projection) in &mut query {
+        if !pan_cam.enabled || !camera.is_active || !pan_cam.pan_enabled {
+            *state = PageState::default();
+            continue;
+        }
+
+        let focused = !blockers.camera_blocked_input(entity).keys
+            && camera_window(camera, primary_window)
+                .and_then(|window_entity| windows.get(window_entity).ok())
+                .is_none_or(|window| pan_cam.key_focus.allows(camera, window));
+        let direction = if focused {
+            pan_cam.page_keys.just_pressed_direction(&keyboard_buttons)
+        } else {
+            Vec2::ZERO
+        };
+        let duration = if settings.reduced_motion {
+            0.
+        } else {
+            pan_cam.page_duration
+        };
+        if direction != Vec2::ZERO {
+            let page = direction * (1. - pan_cam.page_overlap) * projection.area.size();
+            *state = PageState {
+                offset: state.remaining(duration) + page,
+                elapsed: 0.,
+            };
+        } else if state.offset == Vec2::ZERO {
+            continue;
+        }
+
+        let movement = if duration > 0. {
+            let remaining_before = state.remaining(duration);
+            state.elapsed += time.delta_secs();
+            remaining_before - state.remaining(duration)
+        } else {
+            // Nothing to animate, so the whole page is moved at once
+            state.offset
+        };
+        if state.progress(duration) >= 1. {
+            *state = PageState::default();
+        }
+
+        if pan_cam.passive {
+            intended_pans.send(IntendedPan {
+                entity,
+                delta: movement,
+            });
+            continue;
+        }
+
+        let distance = pan_camera(pan_cam, &mut transform, movement, projection.area.size());
+        if stats.enabled {
+            stats.total_pan_distance += distance;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, DirectionKeys, PanCamSettings};
+
+    #[test]
+    fn pressing_again_mid_page_keeps_remaining_movement() {
+        let state = PageState {
+            offset: Vec2::new(100., 0.),
+            elapsed: 0.1,
+        };
+        let remaining = state.remaining(0.2);
+        assert!(remaining.x > 0. && remaining.x < 50.);
+        assert_eq!(state.remaining(0.), Vec2::ZERO);
+    }
+
+    #[test]
+    fn paging_moves_by_view_size_minus_overlap() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                page_keys: DirectionKeys {
+                    right: vec![KeyCode::PageDown],
+                    ..DirectionKeys::NONE
+                },
+                page_overlap: 0.25,
+                ..default()
+            },
+        );
+
+        for _ in 0..2 {
+            keys(&mut app).press(KeyCode::PageDown);
+            update(&mut app);
+            keys(&mut app).release(KeyCode::PageDown);
+            for _ in 0..10 {
+                update(&mut app);
+            }
+        }
+
+        let moved = translation(&app, cam);
+        assert!((moved - vec2(150., 0.)).length() < 1e-3);
+    }
+
+    #[test]
+    fn reduced_motion_moves_a_whole_page_at_once() {
+        let mut app = test_app();
+        app.insert_resource(PanCamSettings {
+            reduced_motion: true,
+            ..default()
+        });
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                page_keys: DirectionKeys {
+                    right: vec![KeyCode::PageDown],
+                    ..DirectionKeys::NONE
+                },
+                page_overlap: 0.25,
+                ..default()
+            },
+        );
+
+        keys(&mut app).press(KeyCode::PageDown);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(75., 0.));
+
+        keys(&mut app).release(KeyCode::PageDown);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(75., 0.));
+    }
+}
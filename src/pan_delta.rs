@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+
+/// Sent when a `PanCam` camera moved this frame, with the net movement from
+/// all sources combined
+///
+/// Useful e.g. for moving parallax background layers by a fraction of the
+/// camera's movement.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct CameraPanDelta {
+    /// The camera entity
+    pub entity: Entity,
+    /// How far the camera moved this frame, in world units
+    pub delta: Vec2,
+}
+
+/// The camera position as of the last frame
+#[derive(Component, Default)]
+pub struct PanDeltaState {
+    last_pos: Option<Vec2>,
+}
+
+/// Sends `CameraPanDelta` for cameras that moved since the last frame
+pub fn send_pan_deltas(
+    mut query: Query<(Entity, &Transform, &mut PanDeltaState)>,
+    mut deltas: EventWriter<CameraPanDelta>,
+) {
+    for (entity, transform, mut state) in &mut query {
+        let pos = transform.translation.truncate();
+        let Some(last_pos) = state.last_pos.replace(pos) else {
+            continue;
+        };
+        if pos != last_pos {
+            deltas.send(CameraPanDelta {
+                entity,
+                delta: pos - last_pos,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{ecs::event::EventCursor, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, PanCam};
+
+    #[test]
+    fn pan_delta_matches_transform_change() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        let mut cursor = EventCursor::<CameraPanDelta>::default();
+        cursor.clear(app.world().resource::<Events<CameraPanDelta>>());
+
+        keys(&mut app).press(KeyCode::ArrowUp);
+        let moved = drag_right(&mut app, cam);
+        let sent: Vec<_> = cursor
+            .read(app.world().resource::<Events<CameraPanDelta>>())
+            .copied()
+            .collect();
+        assert_eq!(
+            sent,
+            [CameraPanDelta {
+                entity: cam,
+                delta: moved,
+            }]
+        );
+    }
+}
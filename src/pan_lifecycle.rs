@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+/// Sent when dragging a camera with its grab buttons moves past the
+/// `pan_deadzone` and starts panning it
+///
+/// Together with [`PanEnded`], this lets the same button be used to click on
+/// things and to drag the camera: presses that end without a `PanStarted`
+/// were clicks.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PanStarted {
+    /// The camera entity
+    pub entity: Entity,
+}
+
+/// Sent when a drag that sent [`PanStarted`] ends
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PanEnded {
+    /// The camera entity
+    pub entity: Entity,
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+
+    use super::*;
+    use crate::{test_utils::*, PanCam};
+
+    #[test]
+    fn pan_lifecycle_events_only_for_drags_past_deadzone() {
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                pan_deadzone: 5.,
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        let lifecycle = |app: &mut App| {
+            let started = app
+                .world_mut()
+                .resource_mut::<Events<PanStarted>>()
+                .drain()
+                .count();
+            let ended = app
+                .world_mut()
+                .resource_mut::<Events<PanEnded>>()
+                .drain()
+                .count();
+            (started, ended)
+        };
+
+        set_cursor(&mut app, vec2(53., 50.));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+        assert_eq!(lifecycle(&mut app), (0, 0));
+
+        set_cursor(&mut app, vec2(56., 50.));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(-3., 0.));
+        assert_eq!(lifecycle(&mut app), (1, 0));
+
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+        assert_eq!(lifecycle(&mut app), (0, 1));
+
+        // a click that doesn't move is not a pan
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+        assert_eq!(lifecycle(&mut app), (0, 0));
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+use crate::PanCam;
+
+/// Scrolls an entity at a different speed than the world as a `PanCam` camera
+/// pans, e.g. for background layers
+///
+/// The entity is moved by the camera's movement times `1 - factor` in the same
+/// frame, so a `factor` of `1.` scrolls like the foreground, `0.5` at half its
+/// speed and `0.` keeps the entity fixed on screen. Only panning is followed,
+/// not zooming or rotation.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ParallaxLayer {
+    /// How fast the layer scrolls relative to the foreground, per axis
+    pub factor: Vec2,
+    /// The camera to follow
+    ///
+    /// When `None`, the layer follows the only `PanCam` camera and stays put
+    /// if there are several.
+    pub camera: Option<Entity>,
+    /// The camera's position when the layer was last moved
+    #[reflect(ignore)]
+    last_camera_pos: Option<Vec2>,
+}
+
+impl ParallaxLayer {
+    /// A layer scrolling at `factor` times the speed of the foreground
+    pub fn new(factor: Vec2) -> Self {
+        Self {
+            factor,
+            camera: None,
+            last_camera_pos: None,
+        }
+    }
+
+    /// A layer following `camera`
+    pub fn with_camera(self, camera: Entity) -> Self {
+        Self {
+            camera: Some(camera),
+            ..self
+        }
+    }
+}
+
+/// Moves `ParallaxLayer`s along with the movement of their camera
+///
+/// Layers are only written to when their camera moved, so they aren't marked
+/// as changed every frame.
+pub fn sync_parallax_layers(
+    mut layers: Query<(&mut ParallaxLayer, &mut Transform), Without<PanCam>>,
+    cameras: Query<&Transform, With<PanCam>>,
+) {
+    for (mut layer, mut transform) in &mut layers {
+        let camera = match layer.camera {
+            Some(entity) => cameras.get(entity).ok(),
+            None => cameras.get_single().ok(),
+        };
+        let Some(camera) = camera else {
+            continue;
+        };
+        let camera_pos = camera.translation.truncate();
+        let last_camera_pos = layer.last_camera_pos;
+        layer.set_if_neq(ParallaxLayer {
+            last_camera_pos: Some(camera_pos),
+            ..layer.clone()
+        });
+        let Some(last_camera_pos) = last_camera_pos else {
+            continue;
+        };
+
+        let offset = (camera_pos - last_camera_pos) * (1. - layer.factor);
+        transform.set_if_neq(Transform {
+            translation: transform.translation + offset.extend(0.),
+            ..*transform
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{math::vec2, math::vec3};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn parallax_layers_follow_camera_in_the_same_frame() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        let layer = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0., 0., -1.),
+                ParallaxLayer::new(vec2(0.5, 1.)),
+            ))
+            .id();
+        update(&mut app);
+
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+        let layer_pos = app.world().get::<Transform>(layer).unwrap().translation;
+        assert_eq!(layer_pos, vec3(-5., 0., -1.));
+    }
+
+    #[test]
+    fn parallax_layers_are_left_unchanged_while_the_camera_is_still() {
+        let mut app = test_app();
+        spawn_cam(&mut app, PanCam::default());
+        let layer = app
+            .world_mut()
+            .spawn((Transform::default(), ParallaxLayer::new(vec2(0.5, 1.))))
+            .id();
+        update(&mut app);
+        update(&mut app);
+        let last_changed = |app: &App| {
+            let entity = app.world().entity(layer);
+            (
+                entity.get_ref::<Transform>().unwrap().last_changed(),
+                entity.get_ref::<ParallaxLayer>().unwrap().last_changed(),
+            )
+        };
+        let before = last_changed(&app);
+
+        update(&mut app);
+        assert_eq!(last_changed(&app), before);
+    }
+}
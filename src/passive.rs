@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+use crate::{normalized_view_pos_to_world, PanCam, ZoomFocusState};
+
+/// Sent instead of panning a camera in [`PanCam::passive`] mode
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct IntendedPan {
+    /// The camera entity
+    pub entity: Entity,
+    /// How far the camera would have moved, in world units, before bounds
+    pub delta: Vec2,
+}
+
+/// Sent instead of zooming a camera in [`PanCam::passive`] mode
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct IntendedZoom {
+    /// The camera entity
+    pub entity: Entity,
+    /// The projection scale the camera would have zoomed to, before scale
+    /// limits
+    pub scale: f32,
+    /// The world position that would have been kept in place, or `None` when
+    /// zooming towards the middle of the view
+    pub anchor: Option<Vec2>,
+}
+
+impl IntendedZoom {
+    /// The zoom for multiplying a camera's scale by `zoom_factor`, anchored at
+    /// the normalized view position `anchor` if zooming to the cursor
+    pub(crate) fn new(
+        entity: Entity,
+        pan_cam: &PanCam,
+        proj: &OrthographicProjection,
+        transform: &Transform,
+        zoom_factor: f32,
+        anchor: Option<Vec2>,
+        focus: &ZoomFocusState,
+    ) -> Self {
+        let anchor = pan_cam.zoom_anchor(anchor, focus).map(|anchor| {
+            normalized_view_pos_to_world(anchor, transform.translation.truncate(), proj.area)
+        });
+        Self {
+            entity,
+            scale: proj.scale * zoom_factor,
+            anchor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{ecs::event::EventCursor, math::vec2};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn passive_camera_sends_intended_changes_instead() {
+        let mut app = test_app();
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                passive: true,
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        let mut pans = EventCursor::<IntendedPan>::default();
+        let mut zooms = EventCursor::<IntendedZoom>::default();
+
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+        let sent: Vec<_> = pans
+            .read(app.world().resource::<Events<IntendedPan>>())
+            .copied()
+            .collect();
+        assert_eq!(
+            sent,
+            [IntendedPan {
+                entity: cam,
+                delta: vec2(-10., 0.),
+            }]
+        );
+
+        mouse(&mut app).release(MouseButton::Left);
+        set_cursor(&mut app, vec2(75., 50.));
+        update(&mut app);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 1.);
+        let sent: Vec<_> = zooms
+            .read(app.world().resource::<Events<IntendedZoom>>())
+            .copied()
+            .collect();
+        assert_eq!(sent.len(), 1);
+        assert!((sent[0].scale - 0.9).abs() < 1e-6);
+        assert_eq!(sent[0].anchor, Some(vec2(25., 0.)));
+    }
+}
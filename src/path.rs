@@ -0,0 +1,228 @@
+use bevy::prelude::*;
+
+use crate::{Easing, PanCam, PanCamSettings, PanCamSnapshot};
+
+/// A view a [`PanCamPath`] moves the camera to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathWaypoint {
+    /// The world position to center on
+    pub translation: Vec2,
+    /// The projection scale
+    pub scale: f32,
+    /// How long getting here from the previous view takes, in seconds
+    pub duration: f32,
+    /// How the camera eases into this view
+    pub easing: Easing,
+}
+
+impl PathWaypoint {
+    /// A waypoint reached with `Easing::SmoothStep`
+    pub fn new(translation: Vec2, scale: f32, duration: f32) -> Self {
+        Self {
+            translation,
+            scale,
+            duration,
+            easing: Easing::SmoothStep,
+        }
+    }
+}
+
+/// Plays a camera through a sequence of views, e.g. for intros and tutorial
+/// fly-overs
+///
+/// Starts from the camera's current view. User input is suspended while the
+/// path plays, by unsetting the camera's `enabled` flag. The component is
+/// removed once the last waypoint is reached, sending [`PanCamPathDone`], and
+/// input resumes. Removing it early stops the path where it is.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct PanCamPath {
+    /// The views to move through, in order
+    pub waypoints: Vec<PathWaypoint>,
+    /// The waypoint being moved to
+    current: usize,
+    /// Seconds since leaving the previous waypoint
+    elapsed: f32,
+    /// The view the current waypoint is moved to from
+    from: Option<PanCamSnapshot>,
+    /// Whether playing the path unset the camera's `enabled` flag
+    suspended_input: bool,
+}
+
+impl PanCamPath {
+    /// Creates a path through `waypoints`
+    pub fn new(waypoints: impl IntoIterator<Item = PathWaypoint>) -> Self {
+        Self {
+            waypoints: waypoints.into_iter().collect(),
+            current: 0,
+            elapsed: 0.,
+            from: None,
+            suspended_input: false,
+        }
+    }
+}
+
+/// Sent when a camera reaches the last waypoint of its [`PanCamPath`]
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PanCamPathDone {
+    /// The camera entity
+    pub entity: Entity,
+}
+
+/// Plays `PanCamPath`s
+pub fn play_paths(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut PanCam,
+        &mut PanCamPath,
+        &mut OrthographicProjection,
+        &mut Transform,
+    )>,
+    mut done: EventWriter<PanCamPathDone>,
+    time: Res<Time>,
+    settings: Res<PanCamSettings>,
+) {
+    for (entity, mut pan_cam, mut path, mut proj, mut transform) in &mut query {
+        let path = &mut *path;
+        let mut from = match path.from {
+            Some(from) => from,
+            None => {
+                if pan_cam.enabled {
+                    pan_cam.enabled = false;
+                    path.suspended_input = true;
+                }
+                PanCamSnapshot::capture(&transform, &proj)
+            }
+        };
+        path.elapsed += time.delta_secs();
+
+        // Skip past the waypoints reached this frame
+        while let Some(waypoint) = path.waypoints.get(path.current) {
+            if path.elapsed < waypoint.duration {
+                break;
+            }
+            path.elapsed -= waypoint.duration;
+            from = PanCamSnapshot {
+                translation: waypoint.translation.extend(from.translation.z),
+                scale: waypoint.scale,
+            };
+            path.current += 1;
+        }
+        path.from = Some(from);
+
+        let Some(waypoint) = path.waypoints.get(path.current) else {
+            proj.scale = from.scale;
+            transform.translation = from.translation;
+            commands.entity(entity).remove::<PanCamPath>();
+            done.send(PanCamPathDone { entity });
+            continue;
+        };
+
+        // With reduced motion, jump to each waypoint and wait there instead
+        let t = if settings.reduced_motion {
+            1.
+        } else {
+            waypoint.easing.ease(path.elapsed / waypoint.duration)
+        };
+        proj.scale = from.scale * (waypoint.scale / from.scale).powf(t);
+        transform.translation = from
+            .translation
+            .truncate()
+            .lerp(waypoint.translation, t)
+            .extend(from.translation.z);
+    }
+}
+
+/// Resumes input on cameras whose path suspended it, once the path is removed
+/// or replaced by another one
+///
+/// A replacing path suspends input again when it starts playing.
+pub fn resume_input_after_path(
+    trigger: Trigger<OnReplace, PanCamPath>,
+    paths: Query<&PanCamPath>,
+    mut pan_cams: Query<&mut PanCam>,
+) {
+    let Ok(path) = paths.get(trigger.entity()) else {
+        return;
+    };
+    if !path.suspended_input {
+        return;
+    }
+    if let Ok(mut pan_cam) = pan_cams.get_mut(trigger.entity()) {
+        pan_cam.enabled = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn path_plays_waypoints_with_input_suspended() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let cam = spawn_cam(&mut app, PanCam::default());
+        update(&mut app);
+
+        let waypoint = |translation, scale| PathWaypoint {
+            easing: Easing::Linear,
+            ..PathWaypoint::new(translation, scale, 0.5)
+        };
+        app.world_mut().entity_mut(cam).insert(PanCamPath::new([
+            waypoint(vec2(100., 0.), 4.),
+            waypoint(vec2(100., 100.), 1.),
+        ]));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(50., 0.));
+        assert_eq!(scale(&app, cam), 2.);
+        assert!(!app.world().get::<PanCam>(cam).unwrap().enabled);
+
+        for _ in 0..3 {
+            update(&mut app);
+        }
+        assert_eq!(translation(&app, cam), vec2(100., 100.));
+        assert_eq!(scale(&app, cam), 1.);
+        assert!(app.world().get::<PanCamPath>(cam).is_none());
+        assert!(app.world().get::<PanCam>(cam).unwrap().enabled);
+        assert_eq!(app.world().resource::<Events<PanCamPathDone>>().len(), 1);
+    }
+
+    #[test]
+    fn replacing_a_playing_path_resumes_input_once_it_ends() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let cam = spawn_cam(&mut app, PanCam::default());
+        update(&mut app);
+
+        app.world_mut()
+            .entity_mut(cam)
+            .insert(PanCamPath::new([PathWaypoint::new(vec2(100., 0.), 1., 1.)]));
+        update(&mut app);
+        assert!(!app.world().get::<PanCam>(cam).unwrap().enabled);
+
+        app.world_mut()
+            .entity_mut(cam)
+            .insert(PanCamPath::new([PathWaypoint::new(
+                vec2(0., 100.),
+                1.,
+                0.5,
+            )]));
+        update(&mut app);
+        assert!(!app.world().get::<PanCam>(cam).unwrap().enabled);
+
+        for _ in 0..2 {
+            update(&mut app);
+        }
+        assert!(app.world().get::<PanCamPath>(cam).is_none());
+        assert!(app.world().get::<PanCam>(cam).unwrap().enabled);
+    }
+}
@@ -0,0 +1,267 @@
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    math::{vec2, vec3},
+    prelude::*,
+    window::PrimaryWindow,
+};
+
+use crate::{
+    camera_window, cursor_in_viewport, scroll_zoom_factor, PanCam, PanCamInputBlockers,
+    PanCamSettings, RawInputFilter, ZoomFocus,
+};
+
+/// Lets a `PanCam` control a camera with a `PerspectiveProjection`, for "2.5D"
+/// scenes viewed in perspective
+///
+/// Dragging moves the camera in its XY plane, keeping the grabbed point of the
+/// content plane under the cursor, and scrolling dollies the camera towards or
+/// away from the content plane instead of changing a projection scale. With
+/// `ZoomFocus::Cursor`, dollying keeps the point under the cursor in place.
+///
+/// The bounds limit the point of the content plane in the middle of the view.
+/// Other orthographic features, like key panning, inertia and commands, don't
+/// apply to perspective cameras.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct PanCamPerspective {
+    /// The z position of the plane the content lies in
+    pub plane_z: f32,
+    /// The closest the camera can get to the content plane, along its view
+    /// axis
+    pub min_distance: f32,
+    /// The furthest the camera can get from the content plane, along its view
+    /// axis
+    pub max_distance: f32,
+    /// The point of the content plane grabbed by the cursor
+    #[reflect(ignore)]
+    grabbed: Option<Vec3>,
+}
+
+impl PanCamPerspective {
+    /// Dollies between `min_distance` and `max_distance` from the content plane
+    /// at z = 0
+    pub fn new(min_distance: f32, max_distance: f32) -> Self {
+        Self {
+            plane_z: 0.,
+            min_distance,
+            max_distance,
+            grabbed: None,
+        }
+    }
+}
+
+impl Default for PanCamPerspective {
+    fn default() -> Self {
+        Self::new(1., 1000.)
+    }
+}
+
+/// Pans and dollies perspective cameras with drag and scroll input
+#[allow(clippy::too_many_arguments)]
+pub fn do_perspective_camera(
+    mut query: Query<
+        (
+            Entity,
+            &PanCam,
+            &mut PanCamPerspective,
+            &Camera,
+            &Projection,
+            &mut Transform,
+        ),
+        RawInputFilter,
+    >,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    keyboard_buttons: Res<ButtonInput<KeyCode>>,
+    mut scroll_events: EventReader<MouseWheel>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    blockers: Res<PanCamInputBlockers>,
+    settings: Res<PanCamSettings>,
+) {
+    let scroll_events: Vec<_> = scroll_events.read().collect();
+    let primary_window = primary_window.get_single().ok();
+
+    for (entity, pan_cam, mut perspective, camera, projection, mut transform) in &mut query {
+        let Projection::Perspective(projection) = projection else {
+            continue;
+        };
+        if !pan_cam.enabled || !camera.is_active {
+            perspective.grabbed = None;
+            continue;
+        }
+        let Some(window_entity) = camera_window(camera, primary_window) else {
+            continue;
+        };
+        let Ok(window) = windows.get(window_entity) else {
+            continue;
+        };
+        let view_rect = pan_cam.view_rect(camera, window);
+        let cursor_pos = window.cursor_position();
+        let blocked = blockers.camera_blocked_input(entity);
+
+        let grab_buttons = &pan_cam.grab_buttons;
+        if !pan_cam.pan_enabled || !grab_buttons.any_pressed(&mouse_buttons, &keyboard_buttons) {
+            perspective.grabbed = None;
+        } else if let Some(cursor_pos) = cursor_pos {
+            let point = plane_point(
+                &transform,
+                projection.fov,
+                view_rect,
+                perspective.plane_z,
+                cursor_pos,
+            );
+            if grab_buttons.any_just_pressed(&mouse_buttons, &keyboard_buttons) {
+                perspective.grabbed = point
+                    .filter(|_| cursor_in_viewport(camera, window, cursor_pos) && !blocked.pan);
+            } else if let (Some(grabbed), Some(point)) = (perspective.grabbed, point) {
+                // Moving the camera parallel to the content plane moves every
+                // point of it under the cursor by the same amount
+                let offset = (grabbed - point).with_z(0.);
+                if offset != Vec3::ZERO {
+                    transform.translation += offset;
+                }
+            }
+        }
+
+        let scroll: f32 = scroll_events
+            .iter()
+            .filter(|ev| ev.window == window_entity)
+            .map(|ev| match ev.unit {
+                MouseScrollUnit::Line => ev.y * pan_cam.pixels_per_line_or(&settings),
+                MouseScrollUnit::Pixel => ev.y * pan_cam.pixel_scroll_multiplier,
+            })
+            .sum();
+        let scroll = if pan_cam.invert_zoom { -scroll } else { scroll };
+        let cursor_in_view = cursor_pos.filter(|pos| cursor_in_viewport(camera, window, *pos));
+        if pan_cam.zoom_enabled
+            && !blocked.zoom
+            && scroll != 0.
+            && (cursor_pos.is_none() || cursor_in_view.is_some())
+        {
+            let focus_pos = match (&pan_cam.zoom_focus, cursor_in_view) {
+                (ZoomFocus::Cursor, Some(cursor_pos)) => cursor_pos,
+                _ => view_rect.center(),
+            };
+            dolly(
+                &perspective,
+                &mut transform,
+                projection.fov,
+                view_rect,
+                focus_pos,
+                scroll_zoom_factor(scroll, pan_cam.zoom_sensitivity_or(&settings)),
+            );
+        }
+
+        // Keep the middle of the view within the bounds
+        if let Some(center) = plane_point(
+            &transform,
+            projection.fov,
+            view_rect,
+            perspective.plane_z,
+            view_rect.center(),
+        ) {
+            let bounds = pan_cam.rect();
+            let clamped = center.truncate().clamp(bounds.min, bounds.max);
+            if clamped != center.truncate() {
+                transform.translation += (clamped - center.truncate()).extend(0.);
+            }
+        }
+    }
+}
+
+/// Moves the camera along the ray through `focus_pos`, changing its distance
+/// to the content plane by `factor` within the allowed range
+///
+/// Moving along the ray keeps the point of the content plane under
+/// `focus_pos` in place.
+fn dolly(
+    perspective: &PanCamPerspective,
+    transform: &mut Mut<Transform>,
+    fov: f32,
+    view_rect: Rect,
+    focus_pos: Vec2,
+    factor: f32,
+) {
+    let forward = transform.forward();
+    if forward.z >= 0. {
+        return;
+    }
+    let distance = (perspective.plane_z - transform.translation.z) / forward.z;
+    let Some(focus) = plane_point(transform, fov, view_rect, perspective.plane_z, focus_pos) else {
+        return;
+    };
+    let new_distance =
+        (distance * factor).clamp(perspective.min_distance, perspective.max_distance);
+    if new_distance == distance {
+        return;
+    }
+    transform.translation = focus + (transform.translation - focus) * (new_distance / distance);
+}
+
+/// The point of the content plane at `plane_z` seen at `screen_pos`, in logical
+/// window pixels, or `None` if the camera doesn't look towards it there
+fn plane_point(
+    transform: &Transform,
+    fov: f32,
+    view_rect: Rect,
+    plane_z: f32,
+    screen_pos: Vec2,
+) -> Option<Vec3> {
+    let half_size = view_rect.half_size();
+    let ndc = (screen_pos - view_rect.center()) / half_size * vec2(1., -1.);
+    let tan_half_fov = (fov / 2.).tan();
+    let direction = transform.rotation
+        * vec3(
+            ndc.x * tan_half_fov * half_size.x / half_size.y,
+            ndc.y * tan_half_fov,
+            -1.,
+        );
+    let t = (plane_z - transform.translation.z) / direction.z;
+    (t.is_finite() && t > 0.).then(|| transform.translation + direction * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn perspective_camera_pans_and_dollies_towards_cursor() {
+        let mut app = test_app();
+        // With a 90° field of view 50 units away, a pixel covers a world unit
+        let cam = app
+            .world_mut()
+            .spawn((
+                Camera::default(),
+                Projection::Perspective(PerspectiveProjection {
+                    fov: std::f32::consts::FRAC_PI_2,
+                    ..default()
+                }),
+                Transform::from_xyz(0., 0., 50.),
+                PanCam::default(),
+                PanCamPerspective::new(10., 100.),
+            ))
+            .id();
+        let position = |app: &App| app.world().get::<Transform>(cam).unwrap().translation;
+        set_cursor(&mut app, vec2(75., 75.));
+        update(&mut app);
+
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        set_cursor(&mut app, vec2(85., 75.));
+        update(&mut app);
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+        assert!((position(&app) - vec3(-10., 0., 50.)).length() < 1e-4);
+
+        // The point under the cursor stays in place
+        let focus = vec3(25., -25., 0.);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!((position(&app) - (focus + vec3(-35., 25., 50.) * 0.9)).length() < 1e-4);
+
+        scroll(&mut app, 9.);
+        update(&mut app);
+        assert!((position(&app) - (focus + vec3(-35., 25., 50.) * 0.2)).length() < 1e-4);
+    }
+}
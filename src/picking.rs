@@ -0,0 +1,106 @@
+use bevy::{
+    picking::{
+        events::{DragEnd, DragStart, Pointer},
+        pointer::{PointerButton, PointerId},
+    },
+    prelude::*,
+    utils::HashSet,
+};
+
+use crate::{PanCamInputBlockers, PanCamSettings};
+
+/// Blocks camera input through the `"picking"` source of
+/// [`PanCamInputBlockers`] while an entity is dragged with `bevy_picking`, so
+/// dragging it doesn't also drag the camera
+///
+/// Drags that start on a window rather than an entity are left to the camera.
+pub fn block_while_picking_drags(
+    mut drag_starts: EventReader<Pointer<DragStart>>,
+    mut drag_ends: EventReader<Pointer<DragEnd>>,
+    windows: Query<(), With<Window>>,
+    settings: Res<PanCamSettings>,
+    mut drags: Local<HashSet<(PointerId, PointerButton)>>,
+    mut blockers: ResMut<PanCamInputBlockers>,
+) {
+    for drag in drag_starts.read() {
+        if !windows.contains(drag.target) {
+            drags.insert((drag.pointer_id, drag.button));
+        }
+    }
+    for drag in drag_ends.read() {
+        drags.remove(&(drag.pointer_id, drag.button));
+    }
+
+    let dragging = settings.block_on_picking_drags && !drags.is_empty();
+    if blockers.is_blocked_by("picking") != dragging {
+        blockers.set("picking", dragging);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{math::vec2, window::PrimaryWindow};
+
+    use super::*;
+    use crate::{test_utils::*, PanCam};
+
+    #[test]
+    fn picking_drags_on_entities_block_panning() {
+        use bevy::{
+            picking::{
+                backend::HitData,
+                events::{DragEnd, DragStart, Pointer},
+                pointer::{Location, PointerButton, PointerId},
+            },
+            render::camera::NormalizedRenderTarget,
+            window::WindowRef,
+        };
+
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        let window = app
+            .world_mut()
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(app.world());
+        let location = Location {
+            target: NormalizedRenderTarget::Window(
+                WindowRef::Primary.normalize(Some(window)).unwrap(),
+            ),
+            position: vec2(50., 50.),
+        };
+        let drag_start = |target| {
+            Pointer::new(
+                target,
+                PointerId::Mouse,
+                location.clone(),
+                DragStart {
+                    button: PointerButton::Primary,
+                    hit: HitData::new(cam, 0., None, None),
+                },
+            )
+        };
+
+        // dragging empty space pans
+        app.world_mut().send_event(drag_start(window));
+        assert_eq!(drag_right(&mut app, cam), vec2(-10., 0.));
+
+        let sprite = app.world_mut().spawn_empty().id();
+        app.world_mut().send_event(drag_start(sprite));
+        assert_eq!(drag_right(&mut app, cam), Vec2::ZERO);
+
+        app.world_mut().send_event(Pointer::new(
+            sprite,
+            PointerId::Mouse,
+            location.clone(),
+            DragEnd {
+                button: PointerButton::Primary,
+                distance: vec2(10., 0.),
+            },
+        ));
+        update(&mut app);
+        assert!(!app
+            .world()
+            .resource::<PanCamInputBlockers>()
+            .is_blocked_by("picking"));
+    }
+}
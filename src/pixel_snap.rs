@@ -0,0 +1,128 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{camera_window, visible_area_size, GridSnapMode, PanCam};
+
+/// The translation of a camera snapped by `snap_to_pixels` or a continuous
+/// `grid_snap`, and the translation it was snapped from
+#[derive(Component, Default)]
+pub struct PixelSnapState {
+    snapped: Option<(Vec3, Vec3)>,
+}
+
+/// Restores the unsnapped translation of cameras snapped last frame, so
+/// movement keeps accumulating sub-pixel amounts
+///
+/// Cameras moved by something else since they were snapped keep their new
+/// translation.
+pub fn unsnap_translation(mut query: Query<(&mut Transform, &mut PixelSnapState)>) {
+    for (mut transform, mut state) in &mut query {
+        let Some((snapped, unsnapped)) = state.snapped.take() else {
+            continue;
+        };
+        if transform.translation == snapped && snapped != unsnapped {
+            transform.translation = unsnapped;
+        }
+    }
+}
+
+/// Rounds the translation of cameras with `snap_to_pixels` to whole physical
+/// pixels, so pixel art doesn't shimmer as the camera moves, and of cameras
+/// with a continuous `grid_snap` to their grid
+pub fn snap_translation(
+    mut query: Query<(
+        &PanCam,
+        &Camera,
+        &OrthographicProjection,
+        &mut Transform,
+        &mut PixelSnapState,
+    )>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    let primary_window = primary_window.get_single().ok();
+
+    for (pan_cam, camera, proj, mut transform, mut state) in &mut query {
+        let grid_snap = pan_cam
+            .grid_snap
+            .filter(|grid_snap| grid_snap.mode == GridSnapMode::Continuous);
+        if !pan_cam.snap_to_pixels && grid_snap.is_none() {
+            continue;
+        }
+        // Already snapped, when the camera systems didn't run since
+        if state
+            .snapped
+            .is_some_and(|(snapped, _)| snapped == transform.translation)
+        {
+            continue;
+        }
+
+        let unsnapped = transform.translation;
+        let mut snapped = unsnapped.truncate();
+        if let Some(grid_snap) = grid_snap {
+            snapped = grid_snap.snap(snapped);
+        }
+        if pan_cam.snap_to_pixels {
+            let Some(window) = camera_window(camera, primary_window)
+                .and_then(|window_entity| windows.get(window_entity).ok())
+            else {
+                continue;
+            };
+            let physical_size = pan_cam.view_rect(camera, window).size() * window.scale_factor();
+            let pixel_size = visible_area_size(proj, &transform) / physical_size;
+            if !pixel_size.is_finite() || pixel_size.cmple(Vec2::ZERO).any() {
+                continue;
+            }
+            snapped = (snapped / pixel_size).round() * pixel_size;
+        }
+
+        let snapped = snapped.extend(unsnapped.z);
+        state.snapped = Some((snapped, unsnapped));
+        if snapped != unsnapped {
+            transform.translation = snapped;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn snap_to_pixels_rounds_translation_but_keeps_sub_pixel_movement() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        // one world unit per pixel, panning a tenth of a pixel per frame
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                speed: 1.,
+                snap_to_pixels: true,
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(50., 50.));
+        set_view(&mut app, cam, vec2(0.25, -3.7), 1.);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(0., -4.));
+
+        keys(&mut app).press(KeyCode::ArrowRight);
+        update(&mut app);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(0., -4.));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(1., -4.));
+
+        // moving the camera elsewhere replaces the unsnapped translation
+        set_view(&mut app, cam, vec2(10.2, 0.), 1.);
+        keys(&mut app).release(KeyCode::ArrowRight);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(10., 0.));
+    }
+}
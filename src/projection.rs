@@ -0,0 +1,123 @@
+use bevy::{prelude::*, reflect::PartialReflect};
+
+use crate::PanCam;
+
+/// Marks cameras whose `OrthographicProjection` mirrors their orthographic
+/// `Projection`
+///
+/// The other systems only control `OrthographicProjection`s, so cameras using
+/// the `Projection` enum, e.g. `Camera3d`s, are given one that is copied from
+/// the enum before they are controlled and back to it afterwards.
+#[derive(Component)]
+pub struct MirroredProjection;
+
+/// Copies the orthographic `Projection` of `PanCam` cameras to the
+/// `OrthographicProjection` the other systems control, adding it if needed
+///
+/// Cameras switched to another projection stop being mirrored.
+#[allow(clippy::type_complexity)]
+pub fn copy_projection_from_enum(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            Ref<Projection>,
+            Option<&mut OrthographicProjection>,
+            Has<MirroredProjection>,
+        ),
+        With<PanCam>,
+    >,
+) {
+    for (entity, projection, proj, mirrored) in &mut query {
+        match (&*projection, proj) {
+            (Projection::Orthographic(enum_proj), None) => {
+                commands
+                    .entity(entity)
+                    .insert((enum_proj.clone(), MirroredProjection));
+            }
+            (Projection::Orthographic(enum_proj), Some(mut proj))
+                if mirrored && projection.is_changed() && !same_projection(&proj, enum_proj) =>
+            {
+                *proj = enum_proj.clone();
+            }
+            (Projection::Orthographic(_), Some(_)) => {}
+            (_, Some(_)) if mirrored => {
+                commands
+                    .entity(entity)
+                    .remove::<(OrthographicProjection, MirroredProjection)>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Copies the `OrthographicProjection` of mirrored cameras back to their
+/// `Projection` once it was controlled
+#[allow(clippy::type_complexity)]
+pub fn copy_projection_to_enum(
+    mut query: Query<
+        (&OrthographicProjection, &mut Projection),
+        (With<MirroredProjection>, Changed<OrthographicProjection>),
+    >,
+) {
+    for (proj, mut projection) in &mut query {
+        // Cameras switched to another projection are left as they are
+        let up_to_date = match &*projection {
+            Projection::Orthographic(enum_proj) => same_projection(proj, enum_proj),
+            _ => true,
+        };
+        if !up_to_date {
+            *projection = Projection::Orthographic(proj.clone());
+        }
+    }
+}
+
+fn same_projection(a: &OrthographicProjection, b: &OrthographicProjection) -> bool {
+    a.reflect_partial_eq(b).unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn cameras_with_projection_enum_are_controlled() {
+        let mut app = test_app();
+        let cam = app
+            .world_mut()
+            .spawn((
+                Camera::default(),
+                Projection::Orthographic(mock_proj(vec2(100., 100.))),
+                Transform::default(),
+                PanCam::default(),
+            ))
+            .id();
+        let enum_scale = |app: &App| match app.world().get::<Projection>(cam).unwrap() {
+            Projection::Orthographic(proj) => proj.scale,
+            _ => panic!("expected an orthographic projection"),
+        };
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!((enum_scale(&app) - 0.9).abs() < 1e-6);
+        assert_eq!(enum_scale(&app), scale(&app, cam));
+
+        // Changes made to the enum are picked up
+        if let Projection::Orthographic(proj) =
+            &mut *app.world_mut().get_mut::<Projection>(cam).unwrap()
+        {
+            proj.scale = 2.;
+        }
+        update(&mut app);
+        assert_eq!(scale(&app, cam), 2.);
+
+        *app.world_mut().get_mut::<Projection>(cam).unwrap() = Projection::Perspective(default());
+        update(&mut app);
+        assert!(app.world().get::<OrthographicProjection>(cam).is_none());
+    }
+}
@@ -0,0 +1,143 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::{PanCamSettings, PanCamSnapshot};
+
+/// Briefly zooms a camera in and back out, e.g. to emphasize a hit
+///
+/// Insert it on a `PanCam` camera to start the effect. The camera zooms in by
+/// `amount` around `anchor`, then eases back out over the rest of `duration`.
+/// When done, the camera is restored to exactly the view it had when the effect
+/// started, and the component is removed. While the effect runs it drives the
+/// camera's view, overriding other changes.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct PunchZoom {
+    /// The world position to zoom towards
+    pub anchor: Vec2,
+    /// How far to zoom in at the peak, as a fraction of the current scale
+    ///
+    /// `0.2` zooms in until the scale is 80% of what it was.
+    pub amount: f32,
+    /// Total length of the effect in seconds
+    pub duration: f32,
+    elapsed: f32,
+    start: Option<PanCamSnapshot>,
+}
+
+impl PunchZoom {
+    /// Creates a punch zoom effect
+    pub fn new(anchor: Vec2, amount: f32, duration: f32) -> Self {
+        Self {
+            anchor,
+            amount,
+            duration,
+            elapsed: 0.,
+            start: None,
+        }
+    }
+
+    /// How far zoomed in the effect is, from 0 to 1, at `t` from 0 to 1
+    ///
+    /// Peaks quickly, a quarter of the way in, then eases back out.
+    fn profile(t: f32) -> f32 {
+        (PI * t.sqrt()).sin()
+    }
+}
+
+/// Plays `PunchZoom` effects
+pub fn punch_zoom(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut PunchZoom,
+        &mut OrthographicProjection,
+        &mut Transform,
+    )>,
+    time: Res<Time>,
+    settings: Res<PanCamSettings>,
+) {
+    for (entity, mut punch, mut proj, mut transform) in &mut query {
+        let start = *punch
+            .start
+            .get_or_insert_with(|| PanCamSnapshot::capture(&transform, &proj));
+        punch.elapsed += time.delta_secs();
+
+        let t = if punch.duration > 0. && !settings.reduced_motion {
+            punch.elapsed / punch.duration
+        } else {
+            1.
+        };
+
+        if t >= 1. {
+            start.apply(&mut transform, &mut proj);
+            commands.entity(entity).remove::<PunchZoom>();
+            continue;
+        }
+
+        let factor = 1. - punch.amount * PunchZoom::profile(t);
+        proj.scale = start.scale * factor;
+        let anchor = punch.anchor;
+        transform.translation =
+            (anchor + (start.translation.truncate() - anchor) * factor).extend(start.translation.z);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, PanCam};
+
+    #[test]
+    fn profile_starts_and_ends_at_rest() {
+        assert_eq!(PunchZoom::profile(0.), 0.);
+        assert!((PunchZoom::profile(0.25) - 1.).abs() < 1e-6);
+        assert!(PunchZoom::profile(1.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn punch_zoom_restores_starting_view() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+        let cam = spawn_cam(&mut app, PanCam::default());
+        set_view(&mut app, cam, vec2(10., 20.), 1.7);
+        app.world_mut()
+            .entity_mut(cam)
+            .insert(PunchZoom::new(vec2(50., 0.), 0.3, 0.5));
+
+        let mut min_scale = f32::INFINITY;
+        for _ in 0..20 {
+            update(&mut app);
+            min_scale = min_scale.min(scale(&app, cam));
+        }
+
+        assert!(app.world().get::<PunchZoom>(cam).is_none());
+        assert!(min_scale < 1.7 * 0.8);
+        assert_eq!(scale(&app, cam), 1.7);
+        assert_eq!(translation(&app, cam), vec2(10., 20.));
+    }
+
+    #[test]
+    fn reduced_motion_skips_punch_zoom() {
+        let mut app = test_app();
+        app.insert_resource(PanCamSettings {
+            reduced_motion: true,
+            ..default()
+        });
+        let cam = spawn_cam(&mut app, PanCam::default());
+        app.world_mut()
+            .entity_mut(cam)
+            .insert(PunchZoom::new(vec2(20., 0.), 0.5, 1.));
+
+        update(&mut app);
+        assert!(app.world().get::<PunchZoom>(cam).is_none());
+        assert_eq!(scale(&app, cam), 1.);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+    }
+}
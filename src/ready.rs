@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+
+use crate::{
+    animation::AnimationState, fit::FitOnStartupState, PanCam, PanCamPath, PunchZoom, Travel,
+};
+
+/// Sent once per camera, the first frame it is at rest after being spawned
+///
+/// A camera is at rest when its `fit_on_startup` is done, if it has one, and
+/// neither its transform nor its projection changed this frame. Useful e.g. to
+/// hide a loading overlay once the camera shows the scene.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PanCamReady {
+    /// The camera entity
+    pub entity: Entity,
+}
+
+/// Whether `PanCamReady` has been sent for a camera
+#[derive(Component, Default)]
+pub struct ReadyState {
+    sent: bool,
+}
+
+/// Sends `PanCamReady` for cameras that are at rest for the first time
+#[allow(clippy::type_complexity)]
+pub fn send_ready_events(
+    mut query: Query<(
+        Entity,
+        &PanCam,
+        &mut ReadyState,
+        &FitOnStartupState,
+        Ref<Transform>,
+        Ref<OrthographicProjection>,
+        Has<PunchZoom>,
+        Has<Travel>,
+        Has<PanCamPath>,
+        &AnimationState,
+    )>,
+    mut ready_events: EventWriter<PanCamReady>,
+) {
+    for (
+        entity,
+        pan_cam,
+        mut state,
+        fit_state,
+        transform,
+        proj,
+        punch_zoom,
+        traveling,
+        playing_path,
+        animation,
+    ) in &mut query
+    {
+        if state.sent {
+            continue;
+        }
+
+        let fitted = pan_cam.fit_on_startup.is_none() || fit_state.is_done();
+        let at_rest = !transform.is_changed()
+            && !proj.is_changed()
+            && !punch_zoom
+            && !traveling
+            && !playing_path
+            && !animation.is_animating();
+        if fitted && at_rest {
+            state.sent = true;
+            ready_events.send(PanCamReady { entity });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{ecs::event::EventCursor, math::vec2};
+
+    use super::*;
+    use crate::{test_utils::*, FitConfig, PanCamContent};
+
+    #[test]
+    fn ready_is_sent_once_after_fit_on_startup() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                fit_on_startup: Some(FitConfig::default()),
+                ..default()
+            },
+        );
+        let mut cursor = EventCursor::<PanCamReady>::default();
+        let mut ready_events = |app: &mut App| {
+            update(app);
+            cursor
+                .read(app.world().resource::<Events<PanCamReady>>())
+                .copied()
+                .collect::<Vec<_>>()
+        };
+
+        for _ in 0..3 {
+            assert!(ready_events(&mut app).is_empty());
+        }
+
+        app.world_mut().spawn((
+            GlobalTransform::from_translation(Vec3::new(100., 0., 0.)),
+            PanCamContent {
+                size: vec2(10., 10.),
+            },
+        ));
+        // content is positioned, then fitted to
+        assert!(ready_events(&mut app).is_empty());
+        assert!(ready_events(&mut app).is_empty());
+        assert_eq!(ready_events(&mut app), [PanCamReady { entity: cam }]);
+
+        for _ in 0..3 {
+            assert!(ready_events(&mut app).is_empty());
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use bevy::prelude::*;
+
+use crate::{PanCam, PanCamSnapshot};
+
+/// How a camera's view changed during one frame of a [`PanCamRecording`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedFrame {
+    /// How far the camera moved, in world units
+    pub pan: Vec2,
+    /// How much the projection scale was multiplied by
+    pub zoom: f32,
+}
+
+/// A camera's movement over a number of frames, e.g. to save as a bug
+/// reproduction or play back in a demo or test
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PanCamRecording {
+    /// The view the recording started from
+    pub start: Option<PanCamSnapshot>,
+    /// The camera's movement in each recorded frame, oldest first
+    pub frames: Vec<RecordedFrame>,
+}
+
+/// Records the camera's movement each frame, whatever moved it, until removed
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct PanCamRecorder {
+    /// The frames recorded so far
+    pub recording: PanCamRecording,
+    /// The view at the end of the last recorded frame
+    last: Option<PanCamSnapshot>,
+}
+
+/// Plays a [`PanCamRecording`] back, one recorded frame per frame, so the
+/// camera moves exactly as it did when recorded
+///
+/// The camera jumps to the recording's start view first, if it has one. Like
+/// [`PanCamPath`](crate::PanCamPath), user input is suspended by unsetting the
+/// camera's `enabled` flag, and the component is removed once the last frame
+/// is played, sending [`PanCamReplayDone`].
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct PanCamReplay {
+    /// The recording being played
+    pub recording: PanCamRecording,
+    /// The next frame to play
+    frame: usize,
+    /// Whether replaying unset the camera's `enabled` flag
+    suspended_input: bool,
+}
+
+impl PanCamReplay {
+    /// Plays `recording` from its first frame
+    pub fn new(recording: PanCamRecording) -> Self {
+        Self {
+            recording,
+            frame: 0,
+            suspended_input: false,
+        }
+    }
+}
+
+/// Sent when a camera has played the last frame of its [`PanCamReplay`]
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PanCamReplayDone {
+    /// The camera entity
+    pub entity: Entity,
+}
+
+/// Plays `PanCamReplay`s
+pub fn play_replays(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut PanCam,
+        &mut PanCamReplay,
+        &mut OrthographicProjection,
+        &mut Transform,
+    )>,
+    mut done: EventWriter<PanCamReplayDone>,
+) {
+    for (entity, mut pan_cam, mut replay, mut proj, mut transform) in &mut query {
+        if replay.frame == 0 {
+            if pan_cam.enabled {
+                pan_cam.enabled = false;
+                replay.suspended_input = true;
+            }
+            if let Some(start) = replay.recording.start {
+                start.apply(&mut transform, &mut proj);
+            }
+        }
+
+        let Some(&frame) = replay.recording.frames.get(replay.frame) else {
+            commands.entity(entity).remove::<PanCamReplay>();
+            done.send(PanCamReplayDone { entity });
+            continue;
+        };
+        replay.frame += 1;
+        if frame.pan != Vec2::ZERO {
+            transform.translation += frame.pan.extend(0.);
+        }
+        if frame.zoom != 1. {
+            proj.scale *= frame.zoom;
+        }
+    }
+}
+
+/// Resumes input on cameras whose replay suspended it, once the replay is
+/// removed or replaced by another one
+///
+/// A replacing replay suspends input again when it starts playing.
+pub fn resume_input_after_replay(
+    trigger: Trigger<OnReplace, PanCamReplay>,
+    replays: Query<&PanCamReplay>,
+    mut pan_cams: Query<&mut PanCam>,
+) {
+    let Ok(replay) = replays.get(trigger.entity()) else {
+        return;
+    };
+    if !replay.suspended_input {
+        return;
+    }
+    if let Ok(mut pan_cam) = pan_cams.get_mut(trigger.entity()) {
+        pan_cam.enabled = true;
+    }
+}
+
+/// Adds this frame's movement to each `PanCamRecorder`
+pub fn record_cameras(
+    mut query: Query<(&Transform, &OrthographicProjection, &mut PanCamRecorder)>,
+) {
+    for (transform, proj, mut recorder) in &mut query {
+        let view = PanCamSnapshot::capture(transform, proj);
+        let Some(last) = recorder.last.replace(view) else {
+            recorder.recording.start = Some(view);
+            continue;
+        };
+        recorder.recording.frames.push(RecordedFrame {
+            pan: (view.translation - last.translation).truncate(),
+            zoom: view.scale / last.scale,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn replaying_recording_reproduces_camera_movement() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        app.world_mut()
+            .entity_mut(cam)
+            .insert(PanCamRecorder::default());
+        update(&mut app);
+        drag_right(&mut app, cam);
+        drag_right(&mut app, cam);
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        let recorded = (translation(&app, cam), scale(&app, cam));
+        let recording = app
+            .world_mut()
+            .entity_mut(cam)
+            .take::<PanCamRecorder>()
+            .unwrap()
+            .recording;
+        assert_eq!(recording.start.unwrap().translation, Vec3::ZERO);
+
+        set_view(&mut app, cam, vec2(300., 300.), 5.);
+        let frames = recording.frames.len();
+        app.world_mut()
+            .entity_mut(cam)
+            .insert(PanCamReplay::new(recording));
+        for _ in 0..frames {
+            update(&mut app);
+        }
+        assert!((translation(&app, cam) - recorded.0).length() < 1e-3);
+        assert!((scale(&app, cam) - recorded.1).abs() < 1e-5);
+        assert!(!app.world().get::<PanCam>(cam).unwrap().enabled);
+
+        update(&mut app);
+        assert!(app.world().get::<PanCamReplay>(cam).is_none());
+        assert!(app.world().get::<PanCam>(cam).unwrap().enabled);
+        assert_eq!(app.world().resource::<Events<PanCamReplayDone>>().len(), 1);
+    }
+
+    #[test]
+    fn replacing_a_playing_replay_resumes_input_once_it_ends() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        update(&mut app);
+        let recording = PanCamRecording {
+            start: None,
+            frames: vec![
+                RecordedFrame {
+                    pan: Vec2::ZERO,
+                    zoom: 1.
+                };
+                3
+            ],
+        };
+
+        app.world_mut()
+            .entity_mut(cam)
+            .insert(PanCamReplay::new(recording.clone()));
+        update(&mut app);
+        assert!(!app.world().get::<PanCam>(cam).unwrap().enabled);
+
+        app.world_mut()
+            .entity_mut(cam)
+            .insert(PanCamReplay::new(recording));
+        update(&mut app);
+        assert!(!app.world().get::<PanCam>(cam).unwrap().enabled);
+
+        for _ in 0..3 {
+            update(&mut app);
+        }
+        assert!(app.world().get::<PanCamReplay>(cam).is_none());
+        assert!(app.world().get::<PanCam>(cam).unwrap().enabled);
+    }
+}
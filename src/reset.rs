@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+
+use crate::{PanCam, PanCamAction, PanCamCommand, PanCamInputBlockers, RawInputFilter};
+
+/// Keys that animate the camera back to its `home` view
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResetView {
+    /// The keys that reset the view
+    pub keys: Vec<KeyCode>,
+    /// How long the camera takes to return, in seconds
+    pub duration: f32,
+}
+
+impl Default for ResetView {
+    /// Home resets the view
+    fn default() -> Self {
+        Self {
+            keys: vec![KeyCode::Home],
+            duration: 0.3,
+        }
+    }
+}
+
+/// Animates cameras back to their `home` view when one of their `reset_view`
+/// keys is pressed
+pub fn do_camera_reset(
+    keyboard_buttons: Res<ButtonInput<KeyCode>>,
+    query: Query<(Entity, &PanCam, &Camera), RawInputFilter>,
+    blockers: Res<PanCamInputBlockers>,
+    mut pan_cam_commands: EventWriter<PanCamCommand>,
+) {
+    if keyboard_buttons.get_just_pressed().next().is_none() {
+        return;
+    }
+
+    for (entity, pan_cam, camera) in &query {
+        let Some(reset_view) = &pan_cam.reset_view else {
+            continue;
+        };
+        if !pan_cam.enabled
+            || !camera.is_active
+            || blockers.camera_blocked_input(entity).keys
+            || !keyboard_buttons.any_just_pressed(reset_view.keys.iter().copied())
+        {
+            continue;
+        }
+        pan_cam_commands.send(PanCamCommand::new(
+            entity,
+            PanCamAction::Reset,
+            reset_view.duration,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, PanCamCommandsExt};
+
+    #[test]
+    fn reset_key_animates_back_to_saved_home() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                reset_view: Some(ResetView::default()),
+                ..default()
+            },
+        );
+        set_view(&mut app, cam, vec2(30., -20.), 2.);
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).save_home();
+        });
+        set_view(&mut app, cam, vec2(-50., 10.), 0.5);
+        update(&mut app);
+
+        keys(&mut app).press(KeyCode::Home);
+        update(&mut app);
+        assert_ne!(translation(&app, cam), vec2(30., -20.));
+        for _ in 0..5 {
+            update(&mut app);
+        }
+        assert_eq!(translation(&app, cam), vec2(30., -20.));
+        assert_eq!(scale(&app, cam), 2.);
+
+        set_view(&mut app, cam, Vec2::ZERO, 1.);
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).reset_view(0.);
+        });
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(30., -20.));
+    }
+}
@@ -0,0 +1,332 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{base_world_size, camera_window, PanCam};
+
+/// Which world position stays in place when a camera's view is resized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResizeAnchor {
+    /// Keep the world position at the center of the view in place
+    Center,
+    /// Keep the world position under the cursor in place, or the one at the
+    /// center of the view if the cursor is outside the window
+    Cursor,
+}
+
+/// How a camera's scale changes when its view is resized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResizePolicy {
+    /// Keep the scale, so a larger view shows more of the world
+    #[default]
+    KeepScale,
+    /// Keep showing the same height of the world
+    KeepWorldHeight,
+    /// Keep showing the same width of the world
+    KeepWorldWidth,
+    /// Keep all of the previously visible area visible, showing more of the
+    /// world along one axis when the aspect ratio changes
+    KeepVisibleRect,
+}
+
+impl ResizePolicy {
+    /// What to multiply the scale by when the world size shown at scale 1
+    /// goes from `old` to `new`
+    fn scale_factor(self, old: Vec2, new: Vec2) -> f32 {
+        let ratio = old / new;
+        match self {
+            ResizePolicy::KeepScale => 1.,
+            ResizePolicy::KeepWorldHeight => ratio.y,
+            ResizePolicy::KeepWorldWidth => ratio.x,
+            ResizePolicy::KeepVisibleRect => ratio.max_element(),
+        }
+    }
+}
+
+/// The view of a camera as of the last frame, to detect resizes
+#[derive(Component, Default)]
+pub struct ResizeState {
+    last_view: Option<(Rect, Rect, f32)>,
+}
+
+/// Converts a position in logical window pixels to world space, for a view
+/// occupying `view_rect` of the window and showing `area` around `cam_pos`
+fn view_pos_to_world(pos: Vec2, view_rect: Rect, area: Rect, cam_pos: Vec2) -> Vec2 {
+    let mut fraction = (pos - view_rect.min) / view_rect.size();
+    // window coordinates have y pointing down
+    fraction.y = 1. - fraction.y;
+    cam_pos + area.min + fraction * area.size()
+}
+
+/// Rescales cameras with a `resize_policy` when their view is resized
+///
+/// Runs before the camera limits are enforced, so the new scale is kept within
+/// them.
+pub fn apply_resize_policy(
+    mut query: Query<(&PanCam, &Camera, &mut OrthographicProjection, &ResizeState)>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    let primary_window = primary_window.get_single().ok();
+
+    for (pan_cam, camera, mut proj, state) in &mut query {
+        if pan_cam.resize_policy == ResizePolicy::KeepScale {
+            continue;
+        }
+        let Some((last_view_rect, ..)) = state.last_view else {
+            continue;
+        };
+        let Some(window) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+        let view_size = pan_cam.view_rect(camera, window).size();
+        if view_size == last_view_rect.size() {
+            continue;
+        }
+
+        let factor = pan_cam.resize_policy.scale_factor(
+            base_world_size(&proj, last_view_rect.size()),
+            base_world_size(&proj, view_size),
+        );
+        if !factor.is_finite() || factor <= 0. {
+            continue;
+        }
+        let mut scaled = proj.clone();
+        scaled.scale *= factor;
+        pan_cam.constrain_scale(&mut scaled, view_size);
+        if scaled.scale != proj.scale {
+            proj.scale = scaled.scale;
+        }
+    }
+}
+
+/// Moves cameras with a `resize_anchor` after their view is resized, so the
+/// anchored world position stays in place
+///
+/// Changes of the window's scale factor resize the view in logical pixels, and
+/// use the `scale_factor_anchor` if there is one.
+///
+/// Runs after the projection has been updated for the new view size, but before
+/// transforms are propagated, so the adjusted view is rendered the same frame.
+pub fn keep_anchor_on_resize(
+    mut query: Query<(
+        &PanCam,
+        &Camera,
+        &OrthographicProjection,
+        &mut Transform,
+        &mut ResizeState,
+    )>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    let primary_window = primary_window.get_single().ok();
+
+    for (pan_cam, camera, proj, mut transform, mut state) in &mut query {
+        let Some(window) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+
+        let view_rect = pan_cam.view_rect(camera, window);
+        let scale_factor = window.scale_factor();
+        let last_view = state
+            .last_view
+            .replace((view_rect, proj.area, scale_factor));
+
+        let Some((last_view_rect, last_area, last_scale_factor)) = last_view else {
+            continue;
+        };
+        let anchor = if last_scale_factor != scale_factor {
+            pan_cam.scale_factor_anchor.or(pan_cam.resize_anchor)
+        } else {
+            pan_cam.resize_anchor
+        };
+        let Some(anchor) = anchor else {
+            continue;
+        };
+        if last_view_rect.size() == view_rect.size() {
+            continue;
+        }
+
+        let cam_pos = transform.translation.truncate();
+        let anchor_pos = match anchor {
+            ResizeAnchor::Cursor => window.cursor_position(),
+            ResizeAnchor::Center => None,
+        };
+        let anchor_world_pos_before = match anchor_pos {
+            Some(pos) => view_pos_to_world(pos, last_view_rect, last_area, cam_pos),
+            None => cam_pos + last_area.center(),
+        };
+        let anchor_world_pos_after = match anchor_pos {
+            Some(pos) => view_pos_to_world(pos, view_rect, proj.area, cam_pos),
+            None => cam_pos + proj.area.center(),
+        };
+
+        let new_cam_pos = pan_cam.clamp_position(
+            cam_pos,
+            cam_pos + anchor_world_pos_before - anchor_world_pos_after,
+            proj.area.size(),
+        );
+        if new_cam_pos != cam_pos {
+            transform.translation = new_cam_pos.extend(transform.translation.z);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{math::vec2, render::camera::CameraProjection};
+
+    use super::*;
+    use crate::{test_utils::*, DragPixelSpace};
+
+    /// Resizes the window and the camera's projection like the camera system
+    /// would, returning the world positions under the cursor at `cursor_pos`
+    /// before the resize and at `cursor_pos_after` after it
+    fn resize_with_anchor(
+        anchor: ResizeAnchor,
+        cursor_pos: Vec2,
+        cursor_pos_after: Vec2,
+    ) -> (Vec2, Vec2) {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                resize_anchor: Some(anchor),
+                ..default()
+            },
+        );
+        set_view(&mut app, cam, vec2(30., -10.), 2.);
+        let mut proj = mock_proj(vec2(100., 100.));
+        proj.scale = 2.;
+        proj.update(100., 100.);
+        *app.world_mut()
+            .get_mut::<OrthographicProjection>(cam)
+            .unwrap() = proj.clone();
+        set_cursor(&mut app, cursor_pos);
+        update(&mut app);
+        let world_pos_before = cursor_world_pos(&app, cam).unwrap();
+
+        resize_window(&mut app, vec2(300., 200.));
+        set_cursor(&mut app, cursor_pos_after);
+        proj.update(300., 200.);
+        *app.world_mut()
+            .get_mut::<OrthographicProjection>(cam)
+            .unwrap() = proj;
+        update(&mut app);
+        // cursor world position is updated before the resize is handled
+        update(&mut app);
+        (world_pos_before, cursor_world_pos(&app, cam).unwrap())
+    }
+
+    #[test]
+    fn resize_keeps_world_position_under_cursor() {
+        let (before, after) =
+            resize_with_anchor(ResizeAnchor::Cursor, vec2(80., 30.), vec2(80., 30.));
+        assert!((before - after).length() < 1e-4);
+    }
+
+    #[test]
+    fn resize_keeps_world_position_at_center() {
+        let (before, after) =
+            resize_with_anchor(ResizeAnchor::Center, vec2(50., 50.), vec2(150., 100.));
+        assert!((before - after).length() < 1e-4);
+    }
+
+    #[test]
+    fn resize_policy_rescales_to_keep_world_size() {
+        let resized_scale = |resize_policy: ResizePolicy| {
+            let mut app = test_app();
+            let cam = spawn_cam(
+                &mut app,
+                PanCam {
+                    resize_policy,
+                    ..default()
+                },
+            );
+            update(&mut app);
+            resize_window(&mut app, vec2(200., 50.));
+            update(&mut app);
+            scale(&app, cam)
+        };
+        assert_eq!(resized_scale(ResizePolicy::KeepScale), 1.);
+        assert_eq!(resized_scale(ResizePolicy::KeepWorldHeight), 2.);
+        assert_eq!(resized_scale(ResizePolicy::KeepWorldWidth), 0.5);
+        assert_eq!(resized_scale(ResizePolicy::KeepVisibleRect), 2.);
+    }
+
+    #[test]
+    fn scale_factor_change_keeps_center_and_zoom_anchor() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                resize_anchor: Some(ResizeAnchor::Cursor),
+                scale_factor_anchor: Some(ResizeAnchor::Center),
+                ..default()
+            },
+        );
+        set_view(&mut app, cam, vec2(30., -10.), 1.);
+        set_cursor(&mut app, vec2(80., 30.));
+        update(&mut app);
+
+        // same physical size and cursor position, but half the logical pixels
+        let mut windows = app
+            .world_mut()
+            .query_filtered::<&mut Window, With<PrimaryWindow>>();
+        windows
+            .single_mut(app.world_mut())
+            .resolution
+            .set_scale_factor(2.);
+        *app.world_mut()
+            .get_mut::<OrthographicProjection>(cam)
+            .unwrap() = mock_proj(vec2(50., 50.));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(30., -10.));
+        assert!(cursor_world_pos(&app, cam)
+            .unwrap()
+            .abs_diff_eq(vec2(45., 0.), 1e-4));
+
+        scroll(&mut app, 1.);
+        update(&mut app);
+        let world_under_cursor = translation(&app, cam) + vec2(15., 10.) * scale(&app, cam);
+        assert!(scale(&app, cam) < 1.);
+        assert!((world_under_cursor - vec2(45., 0.)).length() < 1e-4);
+    }
+
+    #[test]
+    fn scale_factor_change_mid_drag_does_not_jump() {
+        let drag_on_high_dpi = |drag_pixel_space: DragPixelSpace| {
+            let mut app = test_app();
+            let cam = start_grab(
+                &mut app,
+                PanCam {
+                    drag_pixel_space,
+                    ..default()
+                },
+                MouseButton::Left,
+            );
+
+            // the cursor's logical position halves without it moving
+            let mut windows = app
+                .world_mut()
+                .query_filtered::<&mut Window, With<PrimaryWindow>>();
+            windows
+                .single_mut(app.world_mut())
+                .resolution
+                .set_scale_factor(2.);
+            *app.world_mut()
+                .get_mut::<OrthographicProjection>(cam)
+                .unwrap() = mock_proj(vec2(50., 50.));
+            update(&mut app);
+            assert_eq!(translation(&app, cam), Vec2::ZERO);
+
+            drag_right(&mut app, cam)
+        };
+        assert_eq!(drag_on_high_dpi(DragPixelSpace::Logical), vec2(-10., 0.));
+        assert_eq!(drag_on_high_dpi(DragPixelSpace::Physical), vec2(-20., 0.));
+    }
+}
@@ -0,0 +1,166 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    camera_window, view_pos_to_world, visible_area_size, PanCam, PanCamInputBlockers,
+    RawInputFilter,
+};
+
+/// Which keys rotate the camera
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RotateKeys {
+    /// The keys that rotate the camera counterclockwise
+    pub counterclockwise: Vec<KeyCode>,
+    /// The keys that rotate the camera clockwise
+    pub clockwise: Vec<KeyCode>,
+}
+
+impl RotateKeys {
+    /// No keys rotate the camera
+    pub const NONE: Self = Self {
+        counterclockwise: vec![],
+        clockwise: vec![],
+    };
+
+    /// The camera is rotated counterclockwise by Q and clockwise by E
+    pub fn q_e() -> Self {
+        Self {
+            counterclockwise: vec![KeyCode::KeyQ],
+            clockwise: vec![KeyCode::KeyE],
+        }
+    }
+
+    /// 1 when rotating counterclockwise, -1 when rotating clockwise, 0 when
+    /// both or neither
+    fn direction(&self, keyboard_buttons: &ButtonInput<KeyCode>) -> f32 {
+        let mut direction = 0.;
+        if keyboard_buttons.any_pressed(self.counterclockwise.iter().copied()) {
+            direction += 1.;
+        }
+        if keyboard_buttons.any_pressed(self.clockwise.iter().copied()) {
+            direction -= 1.;
+        }
+        direction
+    }
+}
+
+/// Rotates cameras while their `rotate_keys` are held, around the middle of
+/// the view or the cursor
+pub fn do_camera_key_rotate(
+    keyboard_buttons: Res<ButtonInput<KeyCode>>,
+    mut query: Query<
+        (
+            Entity,
+            &PanCam,
+            &Camera,
+            &OrthographicProjection,
+            &mut Transform,
+        ),
+        RawInputFilter,
+    >,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    blockers: Res<PanCamInputBlockers>,
+    time: Res<Time>,
+) {
+    if keyboard_buttons.get_pressed().next().is_none() {
+        return;
+    }
+
+    let primary_window = primary_window.get_single().ok();
+
+    for (entity, pan_cam, camera, proj, mut transform) in &mut query {
+        if !pan_cam.enabled
+            || !camera.is_active
+            || pan_cam.passive
+            || blockers.camera_blocked_input(entity).keys
+        {
+            continue;
+        }
+
+        let direction = pan_cam.rotate_keys.direction(&keyboard_buttons);
+        if direction == 0. {
+            continue;
+        }
+
+        let window = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok());
+        if window.is_some_and(|window| !pan_cam.key_focus.allows(camera, window)) {
+            continue;
+        }
+        let angle = direction * pan_cam.rotate_speed * time.delta_secs();
+
+        let cam_pos = transform.translation.truncate();
+        let pivot = window
+            .filter(|_| pan_cam.rotate_to_cursor)
+            .and_then(|window| {
+                let view_rect = pan_cam.view_rect(camera, window);
+                let cursor_pos = window.cursor_position()?;
+                Some(view_pos_to_world(cursor_pos, view_rect, proj, &transform))
+            });
+
+        transform.rotate_z(angle);
+
+        // Rotating around the cursor also moves the camera around it, so the
+        // world position under the cursor stays in place
+        if let Some(pivot) = pivot {
+            let proposed_cam_pos = pivot + Vec2::from_angle(angle).rotate(cam_pos - pivot);
+            let new_cam_pos = pan_cam.clamp_position(
+                cam_pos,
+                proposed_cam_pos,
+                visible_area_size(proj, &transform),
+            );
+            transform.translation = new_cam_pos.extend(transform.translation.z);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn rotated_camera_pans_along_its_view_axes() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        app.world_mut().get_mut::<Transform>(cam).unwrap().rotation =
+            Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
+
+        let delta = drag_right(&mut app, cam);
+        assert!((delta - vec2(0., -10.)).length() < 1e-4);
+    }
+
+    #[test]
+    fn rotate_keys_rotate_around_cursor() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                rotate_keys: RotateKeys::q_e(),
+                rotate_speed: 10. * std::f32::consts::PI,
+                rotate_to_cursor: true,
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(90., 50.));
+        update(&mut app);
+        let cursor_before = cursor_world_pos(&app, cam).unwrap();
+
+        // a frame of holding Q rotates a half turn counterclockwise
+        keys(&mut app).press(KeyCode::KeyQ);
+        update(&mut app);
+        let transform = *app.world().get::<Transform>(cam).unwrap();
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(std::f32::consts::PI), 1e-4));
+        assert!((cursor_world_pos(&app, cam).unwrap() - cursor_before).length() < 1e-3);
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Serialize, Serializer};
+
+/// Writes a limit as an `Option`, with unbounded (infinite) limits as `None`,
+/// since formats like JSON have no way to represent infinity
+pub(crate) fn serialize<S: Serializer>(limit: &f32, serializer: S) -> Result<S::Ok, S::Error> {
+    limit.is_finite().then_some(*limit).serialize(serializer)
+}
+
+/// Lower limits, where `None` is `f32::NEG_INFINITY`
+pub(crate) mod lower {
+    use serde::{Deserialize, Deserializer};
+
+    pub(crate) use super::serialize;
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+        Ok(Option::<f32>::deserialize(deserializer)?.unwrap_or(f32::NEG_INFINITY))
+    }
+}
+
+/// Upper limits, where `None` is `f32::INFINITY`
+pub(crate) mod upper {
+    use serde::{Deserialize, Deserializer};
+
+    pub(crate) use super::serialize;
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+        Ok(Option::<f32>::deserialize(deserializer)?.unwrap_or(f32::INFINITY))
+    }
+}
@@ -0,0 +1,230 @@
+use bevy::prelude::*;
+
+use crate::PanCam;
+
+/// Plugin-wide defaults for `PanCam` settings, and settings of the plugin
+/// itself
+///
+/// Can be inserted or changed at any time, before or after adding
+/// `PanCamPlugin`. Cameras use the defaults for the `PanCam` fields they leave
+/// as `None`, and keep the values they set themselves.
+#[derive(Resource, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct PanCamSettings {
+    /// The [`PanCam::zoom_sensitivity`] of cameras that don't set their own
+    pub zoom_sensitivity: f32,
+    /// The [`PanCam::pixels_per_line`] of cameras that don't set their own
+    pub pixels_per_line: f32,
+    /// The [`PanCam::zoom_smoothing`] of cameras that don't set their own
+    pub zoom_smoothing: f32,
+    /// Which input cameras ignore while `bevy_egui` wants the mouse or
+    /// keyboard
+    ///
+    /// [`EguiBlockPolicy::NEVER`] turns blocking off. Only has an effect with
+    /// the `bevy_egui` feature.
+    pub egui_block_policy: EguiBlockPolicy,
+    /// Whether cameras ignore input while an entity is dragged with
+    /// `bevy_picking`
+    ///
+    /// Only has an effect with the `picking` feature.
+    pub block_on_picking_drags: bool,
+    /// Whether camera movement snaps to where it's going instead of easing
+    /// or coasting there, for users who prefer reduced motion
+    ///
+    /// Affects:
+    /// - drags and flicks, which stop when released instead of gliding with
+    ///   [`PanInertia`](crate::PanInertia)
+    /// - [`PanCam::zoom_smoothing`], which zooms at once
+    /// - overscroll, which springs back at once
+    /// - edge scrolling, which starts at full speed instead of ramping up
+    /// - paging with [`PanCam::page_keys`], which moves a whole page at once
+    /// - [`PanCamCommand`](crate::PanCamCommand) animations and
+    ///   [`Travel`](crate::Travel)s, which reach their end view on their first
+    ///   frame
+    /// - [`PunchZoom`](crate::PunchZoom)s, which leave the view as it is
+    /// - [`PanCamPath`](crate::PanCamPath)s, which jump to each waypoint and
+    ///   wait there for its duration
+    /// - [`PanCamFollow::smoothing`](crate::PanCamFollow::smoothing), which
+    ///   keeps the target in place at all times
+    /// - [`PanCamShake`](crate::PanCamShake), which doesn't shake the camera;
+    ///   its trauma still decays
+    ///
+    /// Panning and zooming with held keys or gamepad sticks move at their
+    /// usual speed.
+    pub reduced_motion: bool,
+}
+
+impl Default for PanCamSettings {
+    fn default() -> Self {
+        Self {
+            zoom_sensitivity: 0.001,
+            pixels_per_line: 100.,
+            zoom_smoothing: 0.,
+            egui_block_policy: EguiBlockPolicy::default(),
+            block_on_picking_drags: true,
+            reduced_motion: false,
+        }
+    }
+}
+
+/// Which input `bevy_egui` blocks, through the `"bevy_egui"` source of
+/// [`PanCamInputBlockers`](crate::PanCamInputBlockers)
+///
+/// Each kind of input is only blocked while egui needs it, so e.g. typing in a
+/// text field doesn't stop dragging the camera.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub struct EguiBlockPolicy {
+    /// Whether panning with the pointer is blocked while egui wants the
+    /// pointer, e.g. while it's over an egui window or dragging a slider
+    ///
+    /// Defaults to `true`.
+    pub pan: bool,
+    /// Whether scrolling and pinching are blocked while the pointer is over an
+    /// egui scroll area whose content doesn't fit, so egui scrolls it instead
+    ///
+    /// Panels and windows that don't scroll, and egui wanting keyboard input,
+    /// never block zooming. Defaults to `true`.
+    pub zoom: bool,
+    /// Whether keyboard controls are blocked while egui wants keyboard input,
+    /// e.g. while a text field is focused
+    ///
+    /// Defaults to `true`, so typing doesn't also move the camera.
+    pub keys: bool,
+}
+
+impl EguiBlockPolicy {
+    /// Never blocks input
+    pub const NEVER: Self = Self {
+        pan: false,
+        zoom: false,
+        keys: false,
+    };
+}
+
+impl Default for EguiBlockPolicy {
+    fn default() -> Self {
+        Self {
+            pan: true,
+            zoom: true,
+            keys: true,
+        }
+    }
+}
+
+impl PanCam {
+    /// The camera's [`zoom_sensitivity`](Self::zoom_sensitivity), or else the
+    /// plugin-wide one
+    pub fn zoom_sensitivity_or(&self, settings: &PanCamSettings) -> f32 {
+        self.zoom_sensitivity.unwrap_or(settings.zoom_sensitivity)
+    }
+
+    /// The camera's [`pixels_per_line`](Self::pixels_per_line), or else the
+    /// plugin-wide one
+    pub fn pixels_per_line_or(&self, settings: &PanCamSettings) -> f32 {
+        self.pixels_per_line.unwrap_or(settings.pixels_per_line)
+    }
+
+    /// The camera's [`zoom_smoothing`](Self::zoom_smoothing), or else the
+    /// plugin-wide one
+    pub fn zoom_smoothing_or(&self, settings: &PanCamSettings) -> f32 {
+        self.zoom_smoothing.unwrap_or(settings.zoom_smoothing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, PanInertia};
+
+    #[test]
+    fn settings_provide_defaults_cameras_can_override() {
+        let mut app = test_app();
+        app.insert_resource(PanCamSettings {
+            zoom_sensitivity: 0.002,
+            ..default()
+        });
+        let cam = spawn_cam(&mut app, PanCam::default());
+        let overriding_cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_sensitivity: Some(0.003),
+                ..default()
+            },
+        );
+        let settings = app.world().resource::<PanCamSettings>();
+        let pan_cam = |cam| app.world().get::<PanCam>(cam).unwrap();
+        assert_eq!(pan_cam(cam).zoom_sensitivity_or(settings), 0.002);
+        assert_eq!(pan_cam(overriding_cam).zoom_sensitivity_or(settings), 0.003);
+    }
+
+    #[test]
+    fn cameras_keep_values_they_set_even_when_the_settings_change() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_sensitivity: Some(0.001),
+                ..default()
+            },
+        );
+        let default_cam = spawn_cam(&mut app, PanCam::default());
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+        app.insert_resource(PanCamSettings {
+            zoom_sensitivity: 0.002,
+            ..default()
+        });
+
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert_eq!(
+            app.world().get::<PanCam>(cam).unwrap().zoom_sensitivity,
+            Some(0.001)
+        );
+        assert!(scale(&app, default_cam) < scale(&app, cam));
+        assert!(scale(&app, cam) < 1.);
+    }
+
+    #[test]
+    fn reduced_motion_stops_flicks_and_zooms_at_once() {
+        let mut app = test_app();
+        app.insert_resource(PanCamSettings {
+            reduced_motion: true,
+            ..default()
+        })
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                inertia: Some(PanInertia::default()),
+                zoom_smoothing: Some(0.2),
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        set_cursor(&mut app, vec2(60., 50.));
+        update(&mut app);
+        set_cursor(&mut app, vec2(70., 50.));
+        update(&mut app);
+        mouse(&mut app).release(MouseButton::Left);
+        update(&mut app);
+
+        let released_at = translation(&app, cam);
+        assert_eq!(released_at, vec2(-20., 0.));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), released_at);
+
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!((scale(&app, cam) - 0.9).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,187 @@
+use bevy::{math::vec2, prelude::*};
+
+use crate::PanCamSettings;
+
+/// Shakes a camera by an offset applied on top of its panned position, growing
+/// with `trauma`
+///
+/// The offset is removed again before the camera is panned, so it never ends
+/// up in the pan position or the bounds clamp. Add trauma for each hit or
+/// explosion with [`PanCamShake::add_trauma`]; it decays over time.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct PanCamShake {
+    /// How strongly the camera currently shakes, from `0.` to `1.`
+    ///
+    /// The offset grows with the square of the trauma, so small amounts barely
+    /// shake the camera.
+    pub trauma: f32,
+    /// How much trauma is lost per second
+    pub decay: f32,
+    /// How many times per second the shake changes direction, roughly
+    pub frequency: f32,
+    /// The largest offset on each axis, at full trauma
+    ///
+    /// In world units at a scale of `1.`, so the shake looks the same at any
+    /// zoom.
+    pub amplitude: Vec2,
+    #[reflect(ignore)]
+    time: f32,
+    /// The shaken translation, and the translation it was shaken from
+    #[reflect(ignore)]
+    applied: Option<(Vec3, Vec3)>,
+}
+
+impl Default for PanCamShake {
+    fn default() -> Self {
+        Self {
+            trauma: 0.,
+            decay: 1.,
+            frequency: 15.,
+            amplitude: Vec2::splat(10.),
+            time: 0.,
+            applied: None,
+        }
+    }
+}
+
+impl PanCamShake {
+    /// Adds to the trauma, up to `1.`
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0., 1.);
+    }
+
+    /// Removes the applied offset, unless the camera was moved by something
+    /// else since
+    fn remove_offset(&mut self, transform: &mut Mut<Transform>) {
+        let Some((shaken, unshaken)) = self.applied.take() else {
+            return;
+        };
+        if transform.translation == shaken {
+            transform.translation = unshaken;
+        }
+    }
+
+    fn offset(&self, scale: f32) -> Vec2 {
+        let t = self.time * self.frequency;
+        self.trauma.powi(2) * self.amplitude * scale * vec2(noise(0, t), noise(1, t))
+    }
+}
+
+/// Smooth noise between `-1.` and `1.`, interpolated between random values at
+/// whole `t`
+fn noise(seed: u32, t: f32) -> f32 {
+    let hash = |i: i32| {
+        let mut x = (i as u32).wrapping_mul(0x9e37_79b9) ^ seed.wrapping_mul(0x85eb_ca6b);
+        x ^= x >> 16;
+        x = x.wrapping_mul(0x7feb_352d);
+        x ^= x >> 15;
+        x = x.wrapping_mul(0x846c_a68b);
+        x ^= x >> 16;
+        x as f32 / u32::MAX as f32 * 2. - 1.
+    };
+    let i = t.floor();
+    let f = t - i;
+    let (a, b) = (hash(i as i32), hash(i as i32 + 1));
+    a + (b - a) * f * f * (3. - 2. * f)
+}
+
+/// Removes the shake offset of cameras before they are panned
+pub fn unshake_translation(mut query: Query<(&mut Transform, &mut PanCamShake)>) {
+    for (mut transform, mut shake) in &mut query {
+        shake.remove_offset(&mut transform);
+    }
+}
+
+/// Applies the shake offset of cameras on top of their panned position, and
+/// decays their trauma
+pub fn shake_translation(
+    mut query: Query<(&mut Transform, &mut PanCamShake, &OrthographicProjection)>,
+    time: Res<Time>,
+    settings: Res<PanCamSettings>,
+) {
+    for (mut transform, mut shake, proj) in &mut query {
+        // Still shaken, when the camera systems didn't run since
+        shake.remove_offset(&mut transform);
+        if shake.trauma <= 0. {
+            continue;
+        }
+
+        if !settings.reduced_motion {
+            shake.time += time.delta_secs();
+            let unshaken = transform.translation;
+            let shaken = unshaken + shake.offset(proj.scale).extend(0.);
+            shake.applied = Some((shaken, unshaken));
+            transform.translation = shaken;
+        }
+
+        let decay = shake.decay * time.delta_secs();
+        shake.trauma = (shake.trauma - decay).max(0.);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::{test_utils::*, PanCam};
+
+    #[test]
+    fn shake_is_not_absorbed_into_pan_position() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = start_grab(
+            &mut app,
+            PanCam {
+                min_x: -100.,
+                max_x: 100.,
+                ..default()
+            },
+            MouseButton::Left,
+        );
+        set_view(&mut app, cam, vec2(-45., 0.), 1.);
+        app.world_mut().entity_mut(cam).insert(PanCamShake {
+            trauma: 1.,
+            decay: 2.,
+            ..default()
+        });
+        update(&mut app);
+        assert_ne!(translation(&app, cam), vec2(-45., 0.));
+
+        // Panning and clamping only see the unshaken position
+        drag_right(&mut app, cam);
+        drag_right(&mut app, cam);
+        for _ in 0..5 {
+            update(&mut app);
+        }
+        assert_eq!(app.world().get::<PanCamShake>(cam).unwrap().trauma, 0.);
+        assert_eq!(translation(&app, cam), vec2(-50., 0.));
+    }
+
+    #[test]
+    fn reduced_motion_decays_trauma_without_shaking() {
+        let mut app = test_app();
+        app.insert_resource(PanCamSettings {
+            reduced_motion: true,
+            ..default()
+        })
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(&mut app, PanCam::default());
+        app.world_mut().entity_mut(cam).insert(PanCamShake {
+            trauma: 1.,
+            decay: 2.,
+            ..default()
+        });
+        update(&mut app);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+        assert!(app.world().get::<PanCamShake>(cam).unwrap().trauma < 1.);
+    }
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+/// Scrolls a `PanCam` camera as if the user scrolled the mouse wheel, e.g. for
+/// tutorials
+///
+/// The scroll goes through exactly the same logic as real scrolling, so it
+/// zooms or pans depending on the camera's settings and respects all of its
+/// limits. See also
+/// [`PanCamCommandsExt::simulate_zoom`](crate::PanCamCommandsExt::simulate_zoom).
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct SimulatedScroll {
+    /// The camera entity
+    pub entity: Entity,
+    /// How many lines to scroll, where positive values zoom in
+    pub lines: f32,
+    /// Where the cursor is, in logical pixels of the camera's window, or `None`
+    /// if it's outside the window
+    pub cursor_pos: Option<Vec2>,
+}
+
+impl SimulatedScroll {
+    /// The scroll offset in pixels
+    pub(crate) fn offset(&self, pixels_per_line: f32) -> f32 {
+        self.lines * pixels_per_line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+
+    use super::*;
+    use crate::{test_utils::*, PanCam, PanCamCommandsExt};
+
+    #[test]
+    fn simulated_zoom_matches_real_scroll() {
+        let view_after = |simulate: bool| {
+            let mut app = test_app();
+            let cam = spawn_cam(
+                &mut app,
+                PanCam {
+                    min_scale: 0.8,
+                    ..default()
+                },
+            );
+            set_view(&mut app, cam, vec2(10., -5.), 1.);
+            if simulate {
+                run_commands(&mut app, move |commands| {
+                    commands
+                        .entity(cam)
+                        .simulate_zoom(1., Some(vec2(70., 20.)))
+                        .simulate_zoom(2., Some(vec2(70., 20.)));
+                });
+            } else {
+                set_cursor(&mut app, vec2(70., 20.));
+                scroll(&mut app, 1.);
+                update(&mut app);
+                scroll(&mut app, 2.);
+            }
+            update(&mut app);
+            (translation(&app, cam), scale(&app, cam))
+        };
+        let real = view_after(false);
+        assert_eq!(real.1, 0.8);
+        assert_eq!(view_after(true), real);
+    }
+}
@@ -0,0 +1,207 @@
+use bevy::prelude::*;
+
+use crate::{zoom_camera, PanCam, PanCamEnabled, PanCamSettings, PanCamStats, ZoomFocusState};
+
+/// The scale a camera is smoothly zooming towards
+#[derive(Component, Default)]
+pub struct ZoomSmoothingState {
+    target_scale: Option<f32>,
+    /// Normalized view position to zoom towards, see `normalize_view_pos`
+    anchor: Option<Vec2>,
+    view_size: Vec2,
+    /// Scrolling towards the next zoom step, in lines
+    step_lines: f32,
+    /// Zoom scrolling still within the `scroll_deadzone`, in pixels
+    deadzone_pixels: f32,
+}
+
+impl ZoomSmoothingState {
+    /// Sets the scale to zoom towards, anchored at the latest cursor position
+    pub(crate) fn zoom_towards(
+        &mut self,
+        target_scale: f32,
+        anchor: Option<Vec2>,
+        view_size: Vec2,
+    ) {
+        self.target_scale = Some(target_scale);
+        self.anchor = anchor;
+        self.view_size = view_size;
+    }
+
+    /// Stops zooming
+    fn stop(&mut self) {
+        self.target_scale = None;
+        self.anchor = None;
+    }
+
+    /// The scale being zoomed towards, if still zooming
+    pub(crate) fn target_scale(&self) -> Option<f32> {
+        self.target_scale
+    }
+
+    /// Adds scrolling for `zoom_steps`, returning how many whole steps have
+    /// been scrolled, positive when zooming in
+    pub(crate) fn scroll_steps(&mut self, lines: f32) -> i32 {
+        self.step_lines += lines;
+        let steps = self.step_lines.trunc();
+        self.step_lines -= steps;
+        steps as i32
+    }
+
+    /// Adds `pixels` of zoom scrolling, returning how far to zoom: nothing
+    /// while the scrolling accumulated in one direction is within `deadzone`,
+    /// then all of it
+    pub(crate) fn scroll_past_deadzone(&mut self, pixels: f32, deadzone: f32) -> f32 {
+        // Jitter back and forth never adds up past the dead zone
+        if self.deadzone_pixels * pixels < 0. {
+            self.deadzone_pixels = 0.;
+        }
+        self.deadzone_pixels += pixels;
+        if self.deadzone_pixels.abs() <= deadzone {
+            return 0.;
+        }
+        std::mem::take(&mut self.deadzone_pixels)
+    }
+}
+
+/// Moves the scale of cameras with `zoom_smoothing` towards the scale they
+/// were zoomed to, keeping the world position under the cursor in place
+pub fn smooth_zoom(
+    mut query: Query<(
+        &PanCam,
+        &mut ZoomSmoothingState,
+        &mut OrthographicProjection,
+        &mut Transform,
+        &ZoomFocusState,
+    )>,
+    time: Res<Time>,
+    mut stats: ResMut<PanCamStats>,
+    controls_enabled: Res<PanCamEnabled>,
+    settings: Res<PanCamSettings>,
+) {
+    for (pan_cam, mut state, mut proj, mut transform, focus) in &mut query {
+        let Some(target_scale) = state.target_scale else {
+            continue;
+        };
+        if !**controls_enabled || !pan_cam.enabled || !pan_cam.zoom_enabled {
+            state.stop();
+            continue;
+        }
+
+        let smoothing = pan_cam.zoom_smoothing_or(&settings);
+        let t = if smoothing > 0. && !settings.reduced_motion {
+            1. - 0.01f32.powf(time.delta_secs() / smoothing)
+        } else {
+            1.
+        };
+        let remaining = target_scale / proj.scale;
+        let arrived = (remaining - 1.).abs() < 1e-3;
+        let zoom_factor = if arrived {
+            remaining
+        } else {
+            remaining.powf(t)
+        };
+
+        let zoom_change = zoom_camera(
+            pan_cam,
+            &mut proj,
+            &mut transform,
+            zoom_factor,
+            state.anchor,
+            focus,
+            state.view_size,
+        );
+        if stats.enabled {
+            stats.total_zoom_change += zoom_change;
+        }
+
+        // The scale limits may have changed since the target was set
+        if arrived || zoom_change == 0. {
+            state.stop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, render::camera::CameraProjection, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, PanCamTimestep, ZoomFocus};
+
+    #[test]
+    fn smoothing_is_frame_rate_independent_and_deterministic_on_fixed_timestep() {
+        let scale_after_smooth_zoom = |frame_ms: u64, timestep: PanCamTimestep| {
+            let mut app = test_app_with_timestep(timestep);
+            app.insert_resource(Time::<Fixed>::from_hz(100.))
+                .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                    frame_ms,
+                )));
+            let cam = spawn_cam(
+                &mut app,
+                PanCam {
+                    zoom_smoothing: Some(0.5),
+                    zoom_focus: ZoomFocus::ScreenCenter,
+                    ..default()
+                },
+            );
+            update(&mut app);
+            scroll(&mut app, 1.);
+            for _ in 0..400 / frame_ms {
+                update(&mut app);
+            }
+            scale(&app, cam)
+        };
+
+        let at_200_fps = scale_after_smooth_zoom(5, PanCamTimestep::Variable);
+        let at_25_fps = scale_after_smooth_zoom(40, PanCamTimestep::Variable);
+        assert!(at_200_fps < 1.);
+        assert!((at_200_fps - at_25_fps).abs() < 1e-4);
+
+        let fixed = scale_after_smooth_zoom(5, PanCamTimestep::Fixed);
+        assert!(fixed < 1.);
+        assert_eq!(fixed, scale_after_smooth_zoom(40, PanCamTimestep::Fixed));
+    }
+
+    #[test]
+    fn smoothed_zoom_eases_towards_scrolled_scale() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            10,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_smoothing: Some(0.2),
+                ..default()
+            },
+        );
+        // updates the projection's area like the camera system would
+        let update_and_refresh_area = |app: &mut App| {
+            update(app);
+            let mut proj = app
+                .world_mut()
+                .get_mut::<OrthographicProjection>(cam)
+                .unwrap();
+            proj.update(100., 100.);
+        };
+        let world_under_cursor = |app: &App| translation(app, cam).x + 25. * scale(app, cam);
+        // halfway between the center and the right edge
+        set_cursor(&mut app, vec2(75., 50.));
+        update_and_refresh_area(&mut app);
+
+        scroll(&mut app, 1.);
+        update_and_refresh_area(&mut app);
+        let target_scale = 0.9;
+        assert!(scale(&app, cam) < 1. && scale(&app, cam) > target_scale);
+        assert!((world_under_cursor(&app) - 25.).abs() < 1e-4);
+
+        for _ in 0..30 {
+            update_and_refresh_area(&mut app);
+        }
+        assert!((scale(&app, cam) - target_scale).abs() < 1e-6);
+        assert!((world_under_cursor(&app) - 25.).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,490 @@
+use bevy::{
+    ecs::{query::QueryFilter, system::EntityCommands},
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{
+    animation::AnimationState, clamp_to_safe_zone, edge_scroll::EdgeScrollState,
+    fit::fit_entities_action, inertia::InertiaState, overscroll::OverscrollState, page::PageState,
+    smooth_zoom::ZoomSmoothingState, Easing, PanCam, PanCamAction, PanCamCommand, PanCamPath,
+    PanCamReplay, PunchZoom, SimulatedScroll, Travel, TravelMode,
+};
+
+/// The view of a `PanCam` camera: where it is and how far it's zoomed
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PanCamSnapshot {
+    /// The camera's translation
+    pub translation: Vec3,
+    /// The camera's projection scale
+    pub scale: f32,
+}
+
+impl PanCamSnapshot {
+    /// Captures the current view of a camera
+    pub fn capture(transform: &Transform, projection: &OrthographicProjection) -> Self {
+        Self {
+            translation: transform.translation,
+            scale: projection.scale,
+        }
+    }
+
+    /// Restores a camera to this view
+    pub fn apply(&self, transform: &mut Transform, projection: &mut OrthographicProjection) {
+        transform.translation = self.translation;
+        projection.scale = self.scale;
+    }
+}
+
+/// Views saved with [`PanCamCommandsExt::push_pan_cam_state`], most recent last
+#[derive(Component, Debug, Clone, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct PanCamSnapshotStack(pub Vec<PanCamSnapshot>);
+
+/// Named views of a camera, like CAD viewports, saved with
+/// [`PanCamCommandsExt::save_bookmark`]
+#[derive(Component, Debug, Clone, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct PanCamBookmarks(pub HashMap<String, PanCamSnapshot>);
+
+/// Commands for changing a `PanCam` camera's view
+///
+/// Views can be saved and restored later, e.g. for a preview mode. Saved views
+/// form a stack, so temporary views can be nested.
+pub trait PanCamCommandsExt {
+    /// Saves the camera's current view
+    fn push_pan_cam_state(&mut self) -> &mut Self;
+
+    /// Restores the most recently saved view, if any, stopping any ongoing
+    /// camera motion so the restored view is exact
+    fn pop_pan_cam_state(&mut self) -> &mut Self;
+
+    /// Moves the camera so `point` is at the center of the view, within the
+    /// bounds
+    fn center_on(&mut self, point: Vec2, mode: TravelMode) -> &mut Self;
+
+    /// Scrolls the camera by `lines` as if the user scrolled with the cursor at
+    /// `cursor_pos`, see [`SimulatedScroll`]
+    fn simulate_zoom(&mut self, lines: f32, cursor_pos: Option<Vec2>) -> &mut Self;
+
+    /// Animates the camera over `duration` seconds to frame `entities`, with
+    /// `padding` world units around them, e.g. for "zoom to selection"
+    ///
+    /// Entities are as large as their [`PanCamContent`](crate::PanCamContent)
+    /// size, or else their render `Aabb`, which sprites and meshes have.
+    /// Entities with neither are framed as points.
+    fn fit_entities(
+        &mut self,
+        entities: impl IntoIterator<Item = Entity>,
+        padding: f32,
+        duration: f32,
+    ) -> &mut Self;
+
+    /// Like [`fit_entities`](Self::fit_entities), for all entities matching the
+    /// query filter `F`
+    fn fit_entities_matching<F: QueryFilter + 'static>(
+        &mut self,
+        padding: f32,
+        duration: f32,
+    ) -> &mut Self;
+
+    /// Saves the camera's current view as a bookmark called `name`, replacing
+    /// any bookmark with that name
+    fn save_bookmark(&mut self, name: impl Into<String>) -> &mut Self;
+
+    /// Animates the camera to the bookmark called `name` over `duration`
+    /// seconds, if there is one, see [`PanCamCommand`]
+    fn go_to_bookmark(&mut self, name: impl Into<String>, duration: f32) -> &mut Self;
+
+    /// Like [`go_to_bookmark`](Self::go_to_bookmark), animating with `easing`
+    fn go_to_bookmark_eased(
+        &mut self,
+        name: impl Into<String>,
+        duration: f32,
+        easing: Easing,
+    ) -> &mut Self;
+
+    /// Makes the camera's current view its `home` view
+    fn save_home(&mut self) -> &mut Self;
+
+    /// Animates the camera back to its `home` view over `duration` seconds,
+    /// see [`PanCamAction::Reset`]
+    fn reset_view(&mut self, duration: f32) -> &mut Self;
+}
+
+/// Stops everything that keeps moving a camera by itself: gliding, smooth
+/// zooming, springing back, edge scrolling, paging, animations and effects
+fn stop_motion(entity: &mut EntityWorldMut) {
+    fn reset<T: Component + Default>(entity: &mut EntityWorldMut) {
+        if let Some(mut state) = entity.get_mut::<T>() {
+            *state = T::default();
+        }
+    }
+    reset::<InertiaState>(entity);
+    reset::<ZoomSmoothingState>(entity);
+    reset::<OverscrollState>(entity);
+    reset::<EdgeScrollState>(entity);
+    reset::<AnimationState>(entity);
+    reset::<PageState>(entity);
+    entity.remove::<(Travel, PunchZoom, PanCamPath, PanCamReplay)>();
+}
+
+impl PanCamCommandsExt for EntityCommands<'_> {
+    fn push_pan_cam_state(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            let (Some(transform), Some(projection)) = (
+                entity.get::<Transform>(),
+                entity.get::<OrthographicProjection>(),
+            ) else {
+                return;
+            };
+            let snapshot = PanCamSnapshot::capture(transform, projection);
+            if let Some(mut stack) = entity.get_mut::<PanCamSnapshotStack>() {
+                stack.0.push(snapshot);
+            }
+        })
+    }
+
+    fn pop_pan_cam_state(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            let Some(snapshot) = entity
+                .get_mut::<PanCamSnapshotStack>()
+                .and_then(|mut stack| stack.0.pop())
+            else {
+                return;
+            };
+            if let Some(mut transform) = entity.get_mut::<Transform>() {
+                transform.translation = snapshot.translation;
+            }
+            if let Some(mut projection) = entity.get_mut::<OrthographicProjection>() {
+                projection.scale = snapshot.scale;
+            }
+            stop_motion(&mut entity);
+        })
+    }
+
+    fn center_on(&mut self, point: Vec2, mode: TravelMode) -> &mut Self {
+        match mode {
+            TravelMode::Instant => self.queue(move |mut entity: EntityWorldMut| {
+                let (Some(pan_cam), Some(projection)) = (
+                    entity.get::<PanCam>(),
+                    entity.get::<OrthographicProjection>(),
+                ) else {
+                    return;
+                };
+                let pos = clamp_to_safe_zone(
+                    point,
+                    pan_cam.aabb(projection.area.size()),
+                    projection.area.size(),
+                );
+                entity.remove::<Travel>();
+                if let Some(mut transform) = entity.get_mut::<Transform>() {
+                    transform.translation = pos.extend(transform.translation.z);
+                }
+            }),
+            TravelMode::FlyOut { margin, duration } => {
+                self.insert(Travel::new(point, margin, duration))
+            }
+        }
+    }
+
+    fn fit_entities(
+        &mut self,
+        entities: impl IntoIterator<Item = Entity>,
+        padding: f32,
+        duration: f32,
+    ) -> &mut Self {
+        let entities: Vec<Entity> = entities.into_iter().collect();
+        self.queue(move |mut entity: EntityWorldMut| {
+            let id = entity.id();
+            entity.world_scope(|world| {
+                if let Some(action) = fit_entities_action(world, entities, padding) {
+                    world.send_event(PanCamCommand::new(id, action, duration));
+                }
+            });
+        })
+    }
+
+    fn fit_entities_matching<F: QueryFilter + 'static>(
+        &mut self,
+        padding: f32,
+        duration: f32,
+    ) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            let id = entity.id();
+            entity.world_scope(|world| {
+                let entities: Vec<Entity> =
+                    world.query_filtered::<Entity, F>().iter(world).collect();
+                if let Some(action) = fit_entities_action(world, entities, padding) {
+                    world.send_event(PanCamCommand::new(id, action, duration));
+                }
+            });
+        })
+    }
+
+    fn simulate_zoom(&mut self, lines: f32, cursor_pos: Option<Vec2>) -> &mut Self {
+        let entity = self.id();
+        self.commands().send_event(SimulatedScroll {
+            entity,
+            lines,
+            cursor_pos,
+        });
+        self
+    }
+
+    fn save_bookmark(&mut self, name: impl Into<String>) -> &mut Self {
+        let name = name.into();
+        self.queue(move |mut entity: EntityWorldMut| {
+            let (Some(transform), Some(projection)) = (
+                entity.get::<Transform>(),
+                entity.get::<OrthographicProjection>(),
+            ) else {
+                return;
+            };
+            let snapshot = PanCamSnapshot::capture(transform, projection);
+            if let Some(mut bookmarks) = entity.get_mut::<PanCamBookmarks>() {
+                bookmarks.0.insert(name, snapshot);
+            }
+        })
+    }
+
+    fn go_to_bookmark(&mut self, name: impl Into<String>, duration: f32) -> &mut Self {
+        self.go_to_bookmark_eased(name, duration, Easing::default())
+    }
+
+    fn go_to_bookmark_eased(
+        &mut self,
+        name: impl Into<String>,
+        duration: f32,
+        easing: Easing,
+    ) -> &mut Self {
+        let name = name.into();
+        self.queue(move |mut entity: EntityWorldMut| {
+            let Some(snapshot) = entity
+                .get::<PanCamBookmarks>()
+                .and_then(|bookmarks| bookmarks.0.get(&name).copied())
+            else {
+                return;
+            };
+            let action = PanCamAction::SetView {
+                translation: snapshot.translation.truncate(),
+                scale: snapshot.scale,
+            };
+            let id = entity.id();
+            entity.world_scope(|world| {
+                world.send_event(PanCamCommand::new(id, action, duration).with_easing(easing));
+            });
+        })
+    }
+
+    fn save_home(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            let (Some(transform), Some(projection)) = (
+                entity.get::<Transform>(),
+                entity.get::<OrthographicProjection>(),
+            ) else {
+                return;
+            };
+            let snapshot = PanCamSnapshot::capture(transform, projection);
+            if let Some(mut pan_cam) = entity.get_mut::<PanCam>() {
+                pan_cam.home = Some(snapshot);
+            }
+        })
+    }
+
+    fn reset_view(&mut self, duration: f32) -> &mut Self {
+        let entity = self.id();
+        self.commands()
+            .send_event(PanCamCommand::new(entity, PanCamAction::Reset, duration));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, DirectionKeys, PanCam, PanCamRecording, PanInertia, RecordedFrame};
+
+    #[test]
+    fn nested_state_push_pop_restores_exactly() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        set_view(&mut app, cam, vec2(1.5, -2.25), 0.75);
+
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).push_pan_cam_state();
+        });
+        set_view(&mut app, cam, vec2(100., 100.), 3.);
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).push_pan_cam_state();
+        });
+        set_view(&mut app, cam, vec2(-7., 0.), 0.1);
+
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).pop_pan_cam_state();
+        });
+        assert_eq!(translation(&app, cam), vec2(100., 100.));
+        assert_eq!(scale(&app, cam), 3.);
+
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).pop_pan_cam_state();
+        });
+        assert_eq!(translation(&app, cam), vec2(1.5, -2.25));
+        assert_eq!(scale(&app, cam), 0.75);
+
+        // popping an empty stack leaves the view alone
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).pop_pan_cam_state();
+        });
+        assert_eq!(translation(&app, cam), vec2(1.5, -2.25));
+    }
+
+    #[test]
+    fn bookmarks_restore_saved_views() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(&mut app, PanCam::default());
+        update(&mut app);
+        set_view(&mut app, cam, vec2(30., -20.), 2.);
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).save_bookmark("overview");
+        });
+        set_view(&mut app, cam, vec2(-50., 10.), 0.5);
+
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).go_to_bookmark("overview", 0.5);
+        });
+        update(&mut app);
+        assert!(scale(&app, cam) > 0.5 && scale(&app, cam) < 2.);
+
+        for _ in 0..5 {
+            update(&mut app);
+        }
+        assert_eq!(translation(&app, cam), vec2(30., -20.));
+        assert_eq!(scale(&app, cam), 2.);
+        assert_eq!(command_done_events(&app).len(), 1);
+
+        // unknown bookmarks are ignored
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).go_to_bookmark("missing", 0.);
+        });
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(30., -20.));
+    }
+
+    #[test]
+    fn popping_state_stops_gliding_and_smooth_zooming() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                inertia: Some(PanInertia::default()),
+                zoom_smoothing: Some(0.5),
+                ..default()
+            },
+        );
+        set_view(&mut app, cam, vec2(1.5, -2.25), 0.75);
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).push_pan_cam_state();
+        });
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+
+        // fling the camera and start a smooth zoom
+        mouse(&mut app).press(MouseButton::Left);
+        update(&mut app);
+        for x in [60., 70.] {
+            set_cursor(&mut app, vec2(x, 50.));
+            update(&mut app);
+        }
+        mouse(&mut app).release(MouseButton::Left);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        let gliding_from = translation(&app, cam);
+        update(&mut app);
+        assert_ne!(translation(&app, cam), gliding_from);
+
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).pop_pan_cam_state();
+        });
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(1.5, -2.25));
+        assert_eq!(scale(&app, cam), 0.75);
+    }
+
+    #[test]
+    fn popping_state_stops_replays_and_resumes_input() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).push_pan_cam_state();
+        });
+        let frame = RecordedFrame {
+            pan: vec2(10., 0.),
+            zoom: 1.,
+        };
+        app.world_mut()
+            .entity_mut(cam)
+            .insert(PanCamReplay::new(PanCamRecording {
+                start: None,
+                frames: vec![frame; 10],
+            }));
+        update(&mut app);
+        assert!(!app.world().get::<PanCam>(cam).unwrap().enabled);
+
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).pop_pan_cam_state();
+        });
+        update(&mut app);
+        assert!(app.world().get::<PanCamReplay>(cam).is_none());
+        assert!(app.world().get::<PanCam>(cam).unwrap().enabled);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+    }
+
+    #[test]
+    fn popping_state_stops_paging() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                page_keys: DirectionKeys {
+                    right: vec![KeyCode::PageDown],
+                    ..DirectionKeys::NONE
+                },
+                ..default()
+            },
+        );
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).push_pan_cam_state();
+        });
+
+        keys(&mut app).press(KeyCode::PageDown);
+        update(&mut app);
+        keys(&mut app).release(KeyCode::PageDown);
+        update(&mut app);
+        assert_ne!(translation(&app, cam), Vec2::ZERO);
+
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).pop_pan_cam_state();
+        });
+        for _ in 0..10 {
+            update(&mut app);
+        }
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+    }
+}
@@ -0,0 +1,169 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    animation::AnimationState, camera_window, max_zoom_scale, smooth_zoom::ZoomSmoothingState,
+    world_rect, PanCam,
+};
+
+/// The live view of a `PanCam` camera, e.g. to show a zoom indicator or slider,
+/// or to debug camera behavior in an inspector
+///
+/// Updated every frame by the plugin.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct PanCamState {
+    /// The current projection scale
+    pub scale: f32,
+    /// The projection scale being zoomed or animated towards, if any
+    pub target_scale: Option<f32>,
+    /// How fast the camera is moving, in world units per second
+    pub velocity: Vec2,
+    /// The zoom level as a percentage of the camera's `zoom_percent_scale`,
+    /// e.g. `200.` when zoomed in twice as far
+    pub zoom_percent: f32,
+    /// The world area the view shows, the same as the camera's
+    /// [`VisibleWorldRect`](crate::VisibleWorldRect)
+    pub visible_area: Rect,
+    /// The area the view is kept within, with infinite sides where the camera
+    /// has no bounds
+    pub bounds: Rect,
+    /// The largest scale the camera can currently zoom out to, given its
+    /// bounds and the size of its view
+    pub max_scale: f32,
+    /// The translation at the previous update
+    #[reflect(ignore)]
+    last_translation: Option<Vec2>,
+}
+
+/// Updates `PanCamState` for all `PanCam` cameras
+pub fn update_pan_cam_state(
+    mut query: Query<(
+        &PanCam,
+        &Camera,
+        &OrthographicProjection,
+        &Transform,
+        &ZoomSmoothingState,
+        &AnimationState,
+        &mut PanCamState,
+    )>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    time: Res<Time>,
+) {
+    let primary_window = primary_window.get_single().ok();
+    let dt = time.delta_secs();
+    for (pan_cam, camera, proj, transform, zoom_smoothing, animation, mut state) in &mut query {
+        let translation = transform.translation.truncate();
+        let velocity = match state.last_translation {
+            Some(last) if dt > 0. => (translation - last) / dt,
+            _ => Vec2::ZERO,
+        };
+        let window = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok());
+        let max_scale = window.map_or(pan_cam.max_scale, |window| {
+            let view_size = pan_cam.view_rect(camera, window).size();
+            max_zoom_scale(pan_cam, proj, view_size).max(pan_cam.min_scale)
+        });
+        let area = window.map_or(proj.area, |window| pan_cam.view_area(camera, proj, window));
+        state.set_if_neq(PanCamState {
+            scale: proj.scale,
+            target_scale: animation
+                .target_scale()
+                .or_else(|| zoom_smoothing.target_scale()),
+            velocity,
+            zoom_percent: 100. * pan_cam.zoom_percent_scale / proj.scale,
+            visible_area: world_rect(area, &GlobalTransform::from(*transform)),
+            bounds: pan_cam.rect(),
+            max_scale,
+            last_translation: Some(translation),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, PanCamAction, PanCamCommand, VisibleWorldRect};
+
+    #[test]
+    fn pan_cam_state_reports_visible_area_and_bounds() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                min_x: -100.,
+                max_x: 100.,
+                ..default()
+            },
+        );
+        set_view(&mut app, cam, vec2(20., 10.), 1.);
+        update(&mut app);
+        let state = *app.world().get::<PanCamState>(cam).unwrap();
+        assert_eq!(state.visible_area, Rect::new(-30., -40., 70., 60.));
+        assert_eq!(state.bounds.min.x, -100.);
+        assert_eq!(state.bounds.max.y, f32::INFINITY);
+        // the bounds are twice as wide as the view
+        assert_eq!(state.max_scale, 2.);
+    }
+
+    #[test]
+    fn pan_cam_state_visible_area_matches_visible_world_rect() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+
+        // zooming is reflected the same frame, and rotated views are covered
+        // by their bounding box
+        app.world_mut().get_mut::<Transform>(cam).unwrap().rotation =
+            Quat::from_rotation_z(std::f32::consts::FRAC_PI_4);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        let state = *app.world().get::<PanCamState>(cam).unwrap();
+        let size = Vec2::splat(90. * std::f32::consts::SQRT_2);
+        assert!((state.visible_area.size() - size).length() < 1e-3);
+        assert_eq!(
+            state.visible_area,
+            **app.world().get::<VisibleWorldRect>(cam).unwrap()
+        );
+    }
+
+    #[test]
+    fn pan_cam_state_reports_zoom_target_and_velocity() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_percent_scale: 2.,
+                ..default()
+            },
+        );
+        let state = |app: &App| *app.world().get::<PanCamState>(cam).unwrap();
+        update(&mut app);
+        assert_eq!(state(&app).scale, 1.);
+        assert_eq!(state(&app).zoom_percent, 200.);
+        assert_eq!(state(&app).target_scale, None);
+
+        app.world_mut()
+            .send_event(PanCamCommand::new(cam, PanCamAction::ZoomTo(4.), 0.5));
+        update(&mut app);
+        assert_eq!(state(&app).target_scale, Some(4.));
+
+        update(&mut app);
+        assert_eq!(state(&app).target_scale, None);
+        assert_eq!(state(&app).zoom_percent, 50.);
+
+        set_cursor(&mut app, vec2(50., 50.));
+        keys(&mut app).press(KeyCode::ArrowRight);
+        update(&mut app);
+        assert!(state(&app).velocity.x > 0.);
+        assert_eq!(state(&app).velocity.y, 0.);
+    }
+}
@@ -0,0 +1,248 @@
+//! Helpers for testing camera behavior headlessly, e.g. in integration tests
+//! of apps using `PanCam`
+//!
+//! Build an app with [`test_app`], spawn cameras with [`spawn_cam`], then set
+//! up input with [`set_cursor`], [`mouse`], [`keys`] or [`scroll`] and run a
+//! frame with [`update`] before checking [`translation`] and [`scale`].
+//! With the `bevy_egui` feature, `spawn_egui_context` adds egui UI that blocks
+//! input like it would in an app.
+
+use bevy::{
+    ecs::system::RunSystemOnce,
+    input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+    math::vec2,
+    prelude::*,
+    render::camera::CameraProjection,
+    window::{PrimaryWindow, WindowResized, WindowResolution},
+};
+
+use crate::{CursorWorldPos, PanCam, PanCamCommandDone, PanCamPlugin, PanCamTimestep};
+
+/// A 2D projection showing `window_size` world units at scale 1
+pub fn mock_proj(window_size: Vec2) -> OrthographicProjection {
+    let mut proj = OrthographicProjection::default_2d();
+    proj.update(window_size.x, window_size.y);
+    proj
+}
+
+/// Builds a headless app with the plugin, input resources and a 100x100
+/// primary window
+pub fn test_app() -> App {
+    test_app_with_timestep(PanCamTimestep::default())
+}
+
+/// Like [`test_app`], with cameras moved on `timestep`
+pub fn test_app_with_timestep(timestep: PanCamTimestep) -> App {
+    let mut app = App::new();
+    app.insert_resource(timestep)
+        .add_plugins((MinimalPlugins, PanCamPlugin))
+        .init_resource::<ButtonInput<MouseButton>>()
+        .init_resource::<ButtonInput<KeyCode>>()
+        .add_event::<MouseWheel>()
+        .add_event::<MouseMotion>()
+        .add_event::<WindowResized>();
+    #[cfg(feature = "gestures")]
+    app.add_event::<bevy::input::gestures::PinchGesture>();
+    #[cfg(feature = "touch")]
+    app.init_resource::<bevy::input::touch::Touches>();
+    #[cfg(feature = "minimap")]
+    app.add_plugins(AssetPlugin::default())
+        .init_asset::<bevy::gizmos::LineGizmo>();
+    app.world_mut().spawn((
+        Window {
+            resolution: WindowResolution::new(100., 100.),
+            ..default()
+        },
+        PrimaryWindow,
+    ));
+    app
+}
+
+/// Spawns a camera rendering to the primary window, with a projection matching
+/// its default size
+pub fn spawn_cam(app: &mut App, pan_cam: PanCam) -> Entity {
+    app.world_mut()
+        .spawn((
+            Camera::default(),
+            mock_proj(vec2(100., 100.)),
+            Transform::default(),
+            pan_cam,
+        ))
+        .id()
+}
+
+/// Runs one frame, then clears the just pressed/released input state like
+/// the input plugin would
+pub fn update(app: &mut App) {
+    app.update();
+    app.world_mut()
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .clear();
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .clear();
+}
+
+/// Moves the cursor in the primary window, in logical pixels from its top left
+pub fn set_cursor(app: &mut App, pos: Vec2) {
+    let mut windows = app
+        .world_mut()
+        .query_filtered::<&mut Window, With<PrimaryWindow>>();
+    windows
+        .single_mut(app.world_mut())
+        .set_cursor_position(Some(pos));
+}
+
+/// Resizes the primary window, in logical pixels
+pub fn resize_window(app: &mut App, size: Vec2) {
+    let mut windows = app
+        .world_mut()
+        .query_filtered::<&mut Window, With<PrimaryWindow>>();
+    windows
+        .single_mut(app.world_mut())
+        .resolution
+        .set(size.x, size.y);
+}
+
+/// The mouse buttons, to press or release
+pub fn mouse(app: &mut App) -> Mut<'_, ButtonInput<MouseButton>> {
+    app.world_mut().resource_mut::<ButtonInput<MouseButton>>()
+}
+
+/// The keyboard keys, to press or release
+pub fn keys(app: &mut App) -> Mut<'_, ButtonInput<KeyCode>> {
+    app.world_mut().resource_mut::<ButtonInput<KeyCode>>()
+}
+
+/// Scrolls the mouse wheel over the primary window by `lines`, positive
+/// being up
+pub fn scroll(app: &mut App, lines: f32) {
+    scroll_in(app, MouseScrollUnit::Line, lines);
+}
+
+/// Scrolls over the primary window by `amount` in `unit`s
+pub fn scroll_in(app: &mut App, unit: MouseScrollUnit, amount: f32) {
+    let window = app
+        .world_mut()
+        .query_filtered::<Entity, With<PrimaryWindow>>()
+        .single(app.world());
+    app.world_mut().send_event(MouseWheel {
+        unit,
+        x: 0.,
+        y: amount,
+        window,
+    });
+}
+
+/// Moves a camera and sets its projection scale
+pub fn set_view(app: &mut App, entity: Entity, translation: Vec2, scale: f32) {
+    app.world_mut()
+        .get_mut::<Transform>(entity)
+        .unwrap()
+        .translation = translation.extend(0.);
+    app.world_mut()
+        .get_mut::<OrthographicProjection>(entity)
+        .unwrap()
+        .scale = scale;
+}
+
+/// A camera's projection scale
+pub fn scale(app: &App, entity: Entity) -> f32 {
+    app.world()
+        .get::<OrthographicProjection>(entity)
+        .unwrap()
+        .scale
+}
+
+/// A camera's position
+pub fn translation(app: &App, entity: Entity) -> Vec2 {
+    app.world()
+        .get::<Transform>(entity)
+        .unwrap()
+        .translation
+        .truncate()
+}
+
+/// Drags the cursor 10 pixels to the right, returning the camera movement
+pub fn drag_right(app: &mut App, cam: Entity) -> Vec2 {
+    let before = translation(app, cam);
+    let cursor = app
+        .world_mut()
+        .query_filtered::<&Window, With<PrimaryWindow>>()
+        .single(app.world())
+        .cursor_position()
+        .unwrap();
+    set_cursor(app, cursor + vec2(10., 0.));
+    update(app);
+    translation(app, cam) - before
+}
+
+/// Spawns a camera, then presses `button` with the cursor in the middle of the
+/// window
+pub fn start_grab(app: &mut App, pan_cam: PanCam, button: MouseButton) -> Entity {
+    let cam = spawn_cam(app, pan_cam);
+    set_cursor(app, vec2(50., 50.));
+    update(app);
+    mouse(app).press(button);
+    update(app);
+    cam
+}
+
+/// A camera's [`CursorWorldPos`]
+pub fn cursor_world_pos(app: &App, entity: Entity) -> Option<Vec2> {
+    app.world().get::<CursorWorldPos>(entity).unwrap().0
+}
+
+/// Runs `f` with `Commands` and applies them right away
+pub fn run_commands(app: &mut App, f: impl FnOnce(&mut Commands) + Send + Sync + 'static) {
+    let mut f = Some(f);
+    app.world_mut()
+        .run_system_once(move |mut commands: Commands| {
+            if let Some(f) = f.take() {
+                f(&mut commands);
+            }
+        })
+        .unwrap();
+}
+
+/// The [`PanCamCommandDone`] events sent in the last two frames
+pub fn command_done_events(app: &App) -> Vec<PanCamCommandDone> {
+    app.world()
+        .resource::<Events<PanCamCommandDone>>()
+        .get_cursor()
+        .read(app.world().resource::<Events<PanCamCommandDone>>())
+        .copied()
+        .collect()
+}
+
+/// Runs a frame of an egui context with the pointer at `cursor_pos`,
+/// showing `ui`, and spawns it like `bevy_egui` would
+#[cfg(feature = "bevy_egui")]
+pub fn spawn_egui_context(
+    app: &mut App,
+    cursor_pos: Vec2,
+    ui: impl Fn(&bevy_egui::egui::Context),
+) -> bevy_egui::egui::Context {
+    use bevy_egui::{egui, EguiContext};
+
+    let mut context = EguiContext::default();
+    let ctx = context.get_mut().clone();
+    let input = || egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            egui::Pos2::ZERO,
+            egui::vec2(100., 100.),
+        )),
+        events: vec![egui::Event::PointerMoved(egui::pos2(
+            cursor_pos.x,
+            cursor_pos.y,
+        ))],
+        ..default()
+    };
+    // Each frame sees the areas laid out in the one before, and scroll areas
+    // only know whether their content fits from their second frame on
+    for _ in 0..3 {
+        let _ = ctx.run(input(), &ui);
+    }
+    app.world_mut().spawn(context);
+    ctx
+}
@@ -0,0 +1,170 @@
+use std::hash::Hash;
+
+use bevy::{
+    ecs::schedule::{InternedScheduleLabel, ScheduleLabel},
+    prelude::*,
+};
+
+/// Which schedule `PanCamPlugin` moves cameras in
+///
+/// Insert it before adding `PanCamPlugin`, which reads it when it's built:
+///
+/// ```rust ignore
+/// app.insert_resource(PanCamTimestep::Fixed)
+///     .add_plugins(PanCamPlugin);
+/// ```
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Resource)]
+pub enum PanCamTimestep {
+    /// Cameras are moved once per frame, in `Update`
+    #[default]
+    Variable,
+    /// Cameras are moved in `FixedUpdate`
+    ///
+    /// Movement, smoothing and animations then advance by the fixed timestep,
+    /// so replaying the same input gives the same camera path regardless of
+    /// the frame rate.
+    ///
+    /// Mouse button and key presses and releases are buffered until the next
+    /// fixed step, so grabs, clicks and key presses made on frames without one
+    /// are seen late rather than lost. Systems in `FixedUpdate` see the
+    /// buffered presses too. Touches aren't buffered, so taps that start and
+    /// end between two fixed steps are missed.
+    Fixed,
+}
+
+impl PanCamTimestep {
+    /// The schedule cameras are moved in, and
+    /// [`PanCamSystemSet`](crate::PanCamSystemSet) runs in
+    pub(crate) fn schedule(self) -> InternedScheduleLabel {
+        match self {
+            Self::Variable => Update.intern(),
+            Self::Fixed => FixedUpdate.intern(),
+        }
+    }
+}
+
+/// Presses and releases of `T` since the last fixed step
+///
+/// Swapped with the `ButtonInput<T>` resource while the fixed step runs.
+#[derive(Resource, Deref, DerefMut)]
+pub(crate) struct BufferedInput<T: Copy + Eq + Hash + Send + Sync + 'static>(ButtonInput<T>);
+
+impl<T: Copy + Eq + Hash + Send + Sync + 'static> Default for BufferedInput<T> {
+    fn default() -> Self {
+        Self(ButtonInput::default())
+    }
+}
+
+/// Adds this frame's presses and releases to the [`BufferedInput`]
+pub(crate) fn buffer_input<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    input: Res<ButtonInput<T>>,
+    mut buffered: ResMut<BufferedInput<T>>,
+) {
+    for &button in input.get_just_pressed() {
+        buffered.press(button);
+    }
+    for &button in input.get_just_released() {
+        buffered.release(button);
+    }
+}
+
+/// Swaps the [`BufferedInput`] in for the fixed step
+pub(crate) fn use_buffered_input<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    mut input: ResMut<ButtonInput<T>>,
+    mut buffered: ResMut<BufferedInput<T>>,
+) {
+    std::mem::swap(&mut *input, &mut buffered.0);
+}
+
+/// Swaps the frame's input back in after the fixed step, clearing the presses
+/// and releases it has seen
+pub(crate) fn restore_input<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    mut input: ResMut<ButtonInput<T>>,
+    mut buffered: ResMut<BufferedInput<T>>,
+) {
+    std::mem::swap(&mut *input, &mut buffered.0);
+    buffered.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, PanCam, ResetView};
+
+    fn fixed_app() -> App {
+        let mut app = test_app_with_timestep(PanCamTimestep::Fixed);
+        app.insert_resource(Time::<Fixed>::from_hz(10.))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                40,
+            )));
+        app
+    }
+
+    #[test]
+    fn presses_between_fixed_steps_are_seen_on_the_next_one() {
+        // Fixed steps run every 2.5 frames, so some of the presses are made on
+        // frames without one
+        for frames_before in 0..3 {
+            let mut app = fixed_app();
+            let cam = spawn_cam(
+                &mut app,
+                PanCam {
+                    reset_view: Some(ResetView {
+                        duration: 0.,
+                        ..default()
+                    }),
+                    ..default()
+                },
+            );
+            app.world_mut()
+                .get_mut::<Transform>(cam)
+                .unwrap()
+                .translation = Vec3::new(30., 0., 0.);
+            for _ in 0..frames_before {
+                update(&mut app);
+            }
+
+            keys(&mut app).press(KeyCode::Home);
+            update(&mut app);
+            keys(&mut app).release(KeyCode::Home);
+            for _ in 0..3 {
+                update(&mut app);
+            }
+            assert_eq!(translation(&app, cam), Vec2::ZERO);
+        }
+    }
+
+    #[test]
+    fn grabs_between_fixed_steps_still_pan() {
+        for frames_before in 0..3 {
+            let mut app = fixed_app();
+            // Grabs that end on release only start on a fresh press
+            let cam = spawn_cam(
+                &mut app,
+                PanCam {
+                    release_ends_grab: true,
+                    ..default()
+                },
+            );
+            set_cursor(&mut app, vec2(50., 50.));
+            for _ in 0..=frames_before {
+                update(&mut app);
+            }
+
+            mouse(&mut app).press(MouseButton::Left);
+            for _ in 0..3 {
+                update(&mut app);
+            }
+            set_cursor(&mut app, vec2(60., 50.));
+            for _ in 0..3 {
+                update(&mut app);
+            }
+            assert_eq!(translation(&app, cam), vec2(-10., 0.));
+        }
+    }
+}
@@ -0,0 +1,665 @@
+//! Touch screen support
+
+use bevy::{input::touch::Touches, math::vec2, prelude::*, window::PrimaryWindow};
+
+use crate::{
+    camera_window, normalize_view_pos, pan_camera, view_pos_to_world, visible_area_size,
+    zoom_camera, FollowZoomMode, InertiaState, IntendedPan, IntendedZoom, PanCam, PanCamAction,
+    PanCamCommand, PanCamFollow, PanCamInputBlockers, PanCamStats, ZoomFocusState,
+};
+
+/// The two fingers of an ongoing pinch, as of the last frame
+pub struct Pinch {
+    ids: [u64; 2],
+    distance: f32,
+    midpoint: Vec2,
+}
+
+/// A touch gesture that ended, or is still going on
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct Tap {
+    /// When the last finger was lifted, in seconds since startup
+    time: f32,
+    /// Where the fingers were lifted, averaged, in logical window pixels
+    position: Vec2,
+    /// The most fingers down at once
+    fingers: usize,
+    /// The furthest any finger moved, in logical pixels
+    movement: f32,
+}
+
+/// The gesture in progress, and the last one that ended
+#[derive(Default)]
+pub struct TapTracker {
+    current: Tap,
+    /// The sum of the positions fingers were lifted at, and how many were
+    lifted: (Vec2, usize),
+    last: Option<Tap>,
+}
+
+impl TapTracker {
+    /// Tracks the fingers of the current gesture, returning the gesture and
+    /// the one before it once the last finger is lifted
+    fn update(&mut self, touches: &Touches, time: f32) -> Option<(Tap, Option<Tap>)> {
+        let pressed = touches.iter().count();
+        let released = touches.iter_just_released().count();
+        if pressed == 0 && released == 0 {
+            return None;
+        }
+
+        let current = &mut self.current;
+        current.fingers = current.fingers.max(pressed + released);
+        for touch in touches.iter().chain(touches.iter_just_released()) {
+            let movement = touch.start_position().distance(touch.position());
+            current.movement = current.movement.max(movement);
+        }
+        for touch in touches.iter_just_released() {
+            self.lifted.0 += touch.position();
+            self.lifted.1 += 1;
+        }
+        if pressed > 0 {
+            return None;
+        }
+
+        let tap = Tap {
+            time,
+            position: self.lifted.0 / self.lifted.1 as f32,
+            ..std::mem::take(&mut self.current)
+        };
+        self.lifted = (Vec2::ZERO, 0);
+        Some((tap, self.last.replace(tap)))
+    }
+
+    /// Forgets the last gesture, so a third tap doesn't double tap again
+    fn consume(&mut self) {
+        self.last = None;
+    }
+}
+
+/// Zooms cameras with `double_tap_zoom` in when double-tapping with one
+/// finger, and out when double-tapping with two
+///
+/// The tapped position is kept in place.
+#[allow(clippy::too_many_arguments)]
+pub fn do_camera_double_tap(
+    touches: Res<Touches>,
+    query: Query<(
+        Entity,
+        &PanCam,
+        &Camera,
+        &OrthographicProjection,
+        &Transform,
+    )>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    blockers: Res<PanCamInputBlockers>,
+    time: Res<Time>,
+    mut tracker: Local<TapTracker>,
+    mut pan_cam_commands: EventWriter<PanCamCommand>,
+) {
+    let Some((tap, Some(last))) = tracker.update(&touches, time.elapsed_secs()) else {
+        return;
+    };
+    let zoom_steps = match (tap.fingers, last.fingers) {
+        (1, 1) => 1,
+        (2, 2) => -1,
+        _ => return,
+    };
+
+    let primary_window = primary_window.get_single().ok();
+    let mut double_tapped = false;
+
+    for (entity, pan_cam, camera, proj, transform) in &query {
+        let Some(config) = pan_cam.double_tap_zoom else {
+            continue;
+        };
+        if !pan_cam.enabled
+            || !camera.is_active
+            || !pan_cam.zoom_enabled
+            || blockers.is_camera_blocked(entity)
+            || tap.time - last.time > config.max_interval
+            || tap.movement.max(last.movement) > config.max_distance
+            || tap.position.distance(last.position) > config.max_distance
+        {
+            continue;
+        }
+
+        let Some(window) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+        let view_rect = pan_cam.view_rect(camera, window);
+        if !view_rect.contains(tap.position)
+            || !pan_cam
+                .key_focus
+                .allows_at(camera, window, Some(tap.position))
+        {
+            continue;
+        }
+
+        let scale = match &pan_cam.zoom_steps {
+            Some(steps) => steps.step(proj.scale, zoom_steps),
+            None => proj.scale / config.factor.powi(zoom_steps),
+        };
+        let point = view_pos_to_world(tap.position, view_rect, proj, transform);
+        pan_cam_commands.send(PanCamCommand::new(
+            entity,
+            PanCamAction::ZoomAt { point, scale },
+            config.duration,
+        ));
+        double_tapped = true;
+    }
+
+    if double_tapped {
+        tracker.consume();
+    }
+}
+
+/// Pans cameras by dragging a single finger on a touch screen, like dragging
+/// with the mouse
+///
+/// Only cameras whose view the touch started in are panned. With
+/// `touch_inertia`, lifting the finger while moving keeps the camera gliding.
+#[allow(clippy::too_many_arguments)]
+pub fn do_camera_touch_pan(
+    touches: Res<Touches>,
+    mut query: Query<(
+        Entity,
+        &PanCam,
+        &Camera,
+        &mut Transform,
+        &OrthographicProjection,
+        &mut InertiaState,
+    )>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    blockers: Res<PanCamInputBlockers>,
+    mut stats: ResMut<PanCamStats>,
+    mut intended_pans: EventWriter<IntendedPan>,
+    time: Res<Time>,
+    mut last_touch: Local<Option<(u64, Vec2)>>,
+) {
+    let mut pressed = touches.iter();
+    let touch = match (pressed.next(), pressed.next()) {
+        (Some(touch), None) => Some(touch),
+        (None, _) => None,
+        // More fingers are handled as pinches
+        (Some(_), Some(_)) => {
+            *last_touch = None;
+            for (.., mut inertia_state) in &mut query {
+                if inertia_state.is_recording_touch() {
+                    inertia_state.stop();
+                }
+            }
+            return;
+        }
+    };
+
+    let Some(touch) = touch else {
+        *last_touch = None;
+        // The finger of a single finger drag was lifted
+        if touches.iter_just_released().next().is_some() {
+            for (.., mut inertia_state) in &mut query {
+                if inertia_state.is_recording_touch() {
+                    inertia_state.release();
+                }
+            }
+        }
+        return;
+    };
+    // A touch keeps its last delta on frames without touch events, so the
+    // movement is tracked here
+    let delta_pixels = match *last_touch {
+        Some((id, position)) if id == touch.id() => touch.position() - position,
+        _ => touch.delta(),
+    };
+    *last_touch = Some((touch.id(), touch.position()));
+
+    let primary_window = primary_window.get_single().ok();
+
+    for (entity, pan_cam, camera, mut transform, projection, mut inertia_state) in &mut query {
+        if !pan_cam.enabled
+            || !camera.is_active
+            || !pan_cam.pan_enabled
+            || blockers.is_camera_blocked(entity)
+        {
+            continue;
+        }
+
+        let Some(window) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+        let view_rect = pan_cam.view_rect(camera, window);
+        if !view_rect.contains(touch.start_position())
+            || !pan_cam
+                .key_focus
+                .allows_at(camera, window, Some(touch.start_position()))
+        {
+            continue;
+        }
+
+        // Window coordinates have y pointing down, and the content follows the
+        // finger, so the camera moves the opposite way
+        let proj_area_size = visible_area_size(projection, &transform);
+        let movement = vec2(-delta_pixels.x, delta_pixels.y) * proj_area_size / view_rect.size()
+            * pan_cam.pan_speed;
+        let movement = if pan_cam.invert_drag {
+            -movement
+        } else {
+            movement
+        };
+        let movement = pan_cam.limit_pan_speed(movement, time.delta_secs(), projection.scale);
+
+        // Frames where the finger rests count too, so that lifting it after
+        // stopping doesn't glide
+        if let Some(inertia) = pan_cam.touch_inertia {
+            inertia_state.record(time.delta_secs(), movement, inertia.sample_time, true);
+        }
+        if movement == Vec2::ZERO {
+            continue;
+        }
+
+        if pan_cam.passive {
+            intended_pans.send(IntendedPan {
+                entity,
+                delta: movement,
+            });
+            continue;
+        }
+
+        let distance = pan_camera(pan_cam, &mut transform, movement, proj_area_size);
+        if stats.enabled {
+            stats.total_pan_distance += distance;
+        }
+    }
+}
+
+/// Zooms cameras by pinching two fingers on a touch screen, anchored at the
+/// midpoint between them
+///
+/// Moving both fingers together also pans, so the content under the midpoint
+/// follows the fingers. Only cameras whose view contains the midpoint are
+/// affected.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn do_camera_touch_pinch(
+    touches: Res<Touches>,
+    mut query: Query<(
+        Entity,
+        &PanCam,
+        &Camera,
+        &mut OrthographicProjection,
+        &mut Transform,
+        &ZoomFocusState,
+        Has<PanCamFollow>,
+    )>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    blockers: Res<PanCamInputBlockers>,
+    mut pinch: Local<Option<Pinch>>,
+    mut stats: ResMut<PanCamStats>,
+    mut intended_pans: EventWriter<IntendedPan>,
+    mut intended_zooms: EventWriter<IntendedZoom>,
+) {
+    let mut pressed = touches.iter();
+    let (Some(a), Some(b), None) = (pressed.next(), pressed.next(), pressed.next()) else {
+        *pinch = None;
+        return;
+    };
+    let (a, b) = if a.id() < b.id() { (a, b) } else { (b, a) };
+    let ids = [a.id(), b.id()];
+    let distance = a.position().distance(b.position());
+    let midpoint = (a.position() + b.position()) / 2.;
+
+    // A pinch only zooms and pans relative to where its fingers were last
+    // frame, so it starts from scratch when the fingers change
+    let last = pinch.replace(Pinch {
+        ids,
+        distance,
+        midpoint,
+    });
+    let Some(last) = last.filter(|last| last.ids == ids) else {
+        return;
+    };
+    if distance <= 0. || last.distance <= 0. {
+        return;
+    }
+    let zoom_factor = last.distance / distance;
+    let midpoint_delta = midpoint - last.midpoint;
+    if zoom_factor == 1. && midpoint_delta == Vec2::ZERO {
+        return;
+    }
+
+    let primary_window = primary_window.get_single().ok();
+
+    for (entity, pan_cam, camera, mut proj, mut transform, focus, following) in &mut query {
+        if !pan_cam.enabled || !camera.is_active || blockers.is_camera_blocked(entity) {
+            continue;
+        }
+
+        let Some(window) = camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        else {
+            continue;
+        };
+        let view_rect = pan_cam.view_rect(camera, window);
+        if !view_rect.contains(midpoint)
+            || !pan_cam.key_focus.allows_at(camera, window, Some(midpoint))
+        {
+            continue;
+        }
+
+        if pan_cam.pan_enabled && midpoint_delta != Vec2::ZERO {
+            let proj_area_size = visible_area_size(&proj, &transform);
+            let movement =
+                vec2(-midpoint_delta.x, midpoint_delta.y) * proj_area_size / view_rect.size();
+            let movement = if pan_cam.invert_drag {
+                -movement
+            } else {
+                movement
+            };
+            if pan_cam.passive {
+                intended_pans.send(IntendedPan {
+                    entity,
+                    delta: movement,
+                });
+            } else {
+                let distance = pan_camera(pan_cam, &mut transform, movement, proj_area_size);
+                if stats.enabled {
+                    stats.total_pan_distance += distance;
+                }
+            }
+        }
+
+        if !pan_cam.zoom_enabled || zoom_factor == 1. {
+            continue;
+        }
+
+        let anchor = Some(normalize_view_pos(midpoint, view_rect))
+            .filter(|_| !following || pan_cam.zoom_while_following == FollowZoomMode::Cursor);
+
+        if pan_cam.passive {
+            intended_zooms.send(IntendedZoom::new(
+                entity,
+                pan_cam,
+                &proj,
+                &transform,
+                zoom_factor,
+                anchor,
+                focus,
+            ));
+            continue;
+        }
+
+        let zoom_change = zoom_camera(
+            pan_cam,
+            &mut proj,
+            &mut transform,
+            zoom_factor,
+            anchor,
+            focus,
+            view_rect.size(),
+        );
+        if stats.enabled {
+            stats.total_zoom_change += zoom_change;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{render::camera::CameraProjection, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, DoubleTapZoom, PanInertia};
+
+    fn touch(app: &mut App, id: u64, phase: bevy::input::touch::TouchPhase, position: Vec2) {
+        use bevy::input::touch::TouchInput;
+
+        let window = app
+            .world_mut()
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(app.world());
+        app.world_mut().send_event(TouchInput {
+            phase,
+            position,
+            window,
+            force: None,
+            id,
+        });
+    }
+
+    /// Adds touch input handling like the input plugin would
+    fn add_touch_input(app: &mut App) {
+        use bevy::input::touch::{touch_screen_input_system, TouchInput, Touches};
+
+        app.add_event::<TouchInput>()
+            .init_resource::<Touches>()
+            .add_systems(PreUpdate, touch_screen_input_system);
+    }
+
+    #[test]
+    fn double_tap_zooms_in_and_two_finger_double_tap_zooms_out() {
+        use bevy::input::touch::TouchPhase;
+
+        let mut app = test_app();
+        add_touch_input(&mut app);
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                double_tap_zoom: Some(DoubleTapZoom {
+                    duration: 0.,
+                    ..default()
+                }),
+                ..default()
+            },
+        );
+        let tap = |app: &mut App, fingers: &[(u64, Vec2)]| {
+            for phase in [TouchPhase::Started, TouchPhase::Ended] {
+                for &(id, position) in fingers {
+                    touch(app, id, phase, position);
+                }
+                update(app);
+            }
+        };
+
+        // the tapped position, world x 25, stays in place
+        tap(&mut app, &[(0, vec2(75., 50.))]);
+        assert_eq!(scale(&app, cam), 1.);
+        tap(&mut app, &[(0, vec2(75., 50.))]);
+        assert_eq!(scale(&app, cam), 0.5);
+        assert_eq!(translation(&app, cam), vec2(12.5, 0.));
+
+        // like the camera system would, update the projection's area
+        app.world_mut()
+            .get_mut::<OrthographicProjection>(cam)
+            .unwrap()
+            .update(100., 100.);
+        let fingers = [(0, vec2(70., 50.)), (1, vec2(80., 50.))];
+        tap(&mut app, &fingers);
+        tap(&mut app, &fingers);
+        assert_eq!(scale(&app, cam), 1.);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+    }
+
+    #[test]
+    fn single_finger_drag_pans_like_mouse_drag() {
+        use bevy::input::touch::TouchPhase;
+
+        let mut app = test_app();
+        add_touch_input(&mut app);
+        let cam = spawn_cam(&mut app, PanCam::default());
+        set_view(&mut app, cam, Vec2::ZERO, 2.);
+        // the projection area of a camera at scale 2
+        app.world_mut()
+            .get_mut::<OrthographicProjection>(cam)
+            .unwrap()
+            .update(100., 100.);
+
+        touch(&mut app, 0, TouchPhase::Started, vec2(50., 50.));
+        update(&mut app);
+        touch(&mut app, 0, TouchPhase::Moved, vec2(60., 45.));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(-20., -10.));
+
+        // a frame without touch movement doesn't pan
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(-20., -10.));
+
+        touch(&mut app, 0, TouchPhase::Ended, vec2(60., 45.));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(-20., -10.));
+    }
+
+    #[test]
+    fn touch_only_pans_cameras_in_focused_window_with_key_focus() {
+        use bevy::input::touch::TouchPhase;
+
+        let mut app = test_app();
+        add_touch_input(&mut app);
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                key_focus: crate::KeyFocus::FocusedWindow,
+                ..default()
+            },
+        );
+        app.world_mut()
+            .query::<&mut Window>()
+            .single_mut(app.world_mut())
+            .focused = false;
+
+        touch(&mut app, 0, TouchPhase::Started, vec2(50., 50.));
+        update(&mut app);
+        touch(&mut app, 0, TouchPhase::Moved, vec2(60., 45.));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+    }
+
+    #[test]
+    fn touch_fling_glides_with_touch_inertia() {
+        use bevy::input::touch::TouchPhase;
+
+        let mut app = test_app();
+        add_touch_input(&mut app);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                touch_inertia: Some(PanInertia::touch()),
+                ..default()
+            },
+        );
+
+        touch(&mut app, 0, TouchPhase::Started, vec2(50., 50.));
+        update(&mut app);
+        touch(&mut app, 0, TouchPhase::Moved, vec2(40., 50.));
+        update(&mut app);
+        touch(&mut app, 0, TouchPhase::Moved, vec2(30., 50.));
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(20., 0.));
+
+        touch(&mut app, 0, TouchPhase::Ended, vec2(30., 50.));
+        update(&mut app);
+        let mut last_step = f32::INFINITY;
+        for _ in 0..3 {
+            let before = translation(&app, cam);
+            update(&mut app);
+            let step = translation(&app, cam).x - before.x;
+            assert!(step > 0. && step < last_step);
+            last_step = step;
+        }
+
+        // mouse inertia doesn't apply to touch drags
+        let mut app = test_app();
+        add_touch_input(&mut app);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                inertia: Some(PanInertia::default()),
+                ..default()
+            },
+        );
+        touch(&mut app, 0, TouchPhase::Started, vec2(50., 50.));
+        update(&mut app);
+        touch(&mut app, 0, TouchPhase::Moved, vec2(40., 50.));
+        update(&mut app);
+        touch(&mut app, 0, TouchPhase::Ended, vec2(40., 50.));
+        update(&mut app);
+        update(&mut app);
+        assert_eq!(translation(&app, cam), vec2(10., 0.));
+    }
+
+    #[test]
+    fn two_finger_drag_pans_with_invert_drag() {
+        use bevy::input::touch::TouchPhase;
+
+        let two_finger_drag = |invert_drag: bool| {
+            let mut app = test_app();
+            add_touch_input(&mut app);
+            let cam = spawn_cam(
+                &mut app,
+                PanCam {
+                    invert_drag,
+                    ..default()
+                },
+            );
+            touch(&mut app, 0, TouchPhase::Started, vec2(40., 50.));
+            touch(&mut app, 1, TouchPhase::Started, vec2(60., 50.));
+            update(&mut app);
+            update(&mut app);
+            touch(&mut app, 0, TouchPhase::Moved, vec2(50., 50.));
+            touch(&mut app, 1, TouchPhase::Moved, vec2(70., 50.));
+            update(&mut app);
+            translation(&app, cam)
+        };
+
+        assert_eq!(two_finger_drag(false), vec2(-10., 0.));
+        assert_eq!(two_finger_drag(true), vec2(10., 0.));
+    }
+
+    #[test]
+    fn pinch_zooms_around_midpoint_within_scale_limits() {
+        use bevy::input::touch::TouchPhase;
+
+        let pinch_apart = |min_scale: f32| {
+            let mut app = test_app();
+            add_touch_input(&mut app);
+            let cam = spawn_cam(
+                &mut app,
+                PanCam {
+                    min_scale,
+                    ..default()
+                },
+            );
+            touch(&mut app, 0, TouchPhase::Started, vec2(60., 50.));
+            touch(&mut app, 1, TouchPhase::Started, vec2(80., 50.));
+            update(&mut app);
+            update(&mut app);
+            // twice the distance, around the same midpoint
+            touch(&mut app, 0, TouchPhase::Moved, vec2(50., 50.));
+            touch(&mut app, 1, TouchPhase::Moved, vec2(90., 50.));
+            update(&mut app);
+            (translation(&app, cam), scale(&app, cam))
+        };
+
+        let (translation, scale) = pinch_apart(0.1);
+        assert!((scale - 0.5).abs() < 1e-5);
+        // the world position under the midpoint stays in place
+        let world_under_midpoint = translation.x + 20. * scale;
+        assert!((world_under_midpoint - 20.).abs() < 1e-4);
+        assert_eq!(translation.y, 0.);
+
+        let (_, scale) = pinch_apart(0.8);
+        assert_eq!(scale, 0.8);
+    }
+}
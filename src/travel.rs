@@ -0,0 +1,176 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::{clamp_to_safe_zone, PanCam, PanCamSettings, PanCamSnapshot};
+
+/// How a camera gets to a new position, see
+/// [`PanCamCommandsExt::center_on`](crate::PanCamCommandsExt::center_on)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TravelMode {
+    /// Jump to the new position immediately
+    Instant,
+    /// Zoom out until both the start and the end are in view, pan across, then
+    /// zoom back in, like map apps do for long distance moves
+    FlyOut {
+        /// World units to keep visible around the start and the end at the
+        /// furthest zoomed out point
+        margin: f32,
+        /// How long the whole move takes, in seconds
+        duration: f32,
+    },
+}
+
+/// Moves a camera to a new position over time, zooming out on the way
+///
+/// Inserted by [`PanCamCommandsExt::center_on`](crate::PanCamCommandsExt::center_on),
+/// and removed once the camera arrives. The camera ends at the scale it had
+/// when the travel started. While traveling it drives the camera's view,
+/// overriding other changes.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Travel {
+    /// The world position to center on
+    pub target: Vec2,
+    /// World units kept visible around the start and the target
+    pub margin: f32,
+    /// Total length of the travel in seconds
+    pub duration: f32,
+    elapsed: f32,
+    path: Option<TravelPath>,
+}
+
+/// Where a travel starts and ends, decided on its first frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TravelPath {
+    start: PanCamSnapshot,
+    end: Vec2,
+    peak_scale: f32,
+}
+
+impl Travel {
+    /// Creates a travel to `target`
+    pub fn new(target: Vec2, margin: f32, duration: f32) -> Self {
+        Self {
+            target,
+            margin,
+            duration,
+            elapsed: 0.,
+            path: None,
+        }
+    }
+}
+
+/// The scale at which a view of `half_size` at scale 1 centered between `from`
+/// and `to` shows both, plus `margin`
+fn fly_out_scale(from: Vec2, to: Vec2, margin: f32, half_size: Vec2) -> f32 {
+    let needed = (to - from).abs() / 2. + Vec2::splat(margin);
+    (needed / half_size).max_element()
+}
+
+/// Plays `Travel` animations
+pub fn travel(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &PanCam,
+        &mut Travel,
+        &mut OrthographicProjection,
+        &mut Transform,
+    )>,
+    time: Res<Time>,
+    settings: Res<PanCamSettings>,
+) {
+    for (entity, pan_cam, mut travel, mut proj, mut transform) in &mut query {
+        let target = travel.target;
+        let margin = travel.margin;
+        let path = *travel.path.get_or_insert_with(|| {
+            let start = PanCamSnapshot::capture(&transform, &proj);
+            let from = start.translation.truncate();
+            let half_size = proj.area.size() / 2. / proj.scale;
+            let end = clamp_to_safe_zone(target, pan_cam.aabb(proj.area.size()), proj.area.size());
+            let peak_scale = fly_out_scale(from, end, margin, half_size)
+                .min(pan_cam.max_scale)
+                .max(start.scale);
+            TravelPath {
+                start,
+                end,
+                peak_scale,
+            }
+        });
+        travel.elapsed += time.delta_secs();
+
+        let t = if travel.duration > 0. && !settings.reduced_motion {
+            (travel.elapsed / travel.duration).min(1.)
+        } else {
+            1.
+        };
+
+        // Pan with smoothstep easing, while the scale peaks halfway
+        let pan_t = t * t * (3. - 2. * t);
+        let zoom_t = (PI * t).sin();
+        let start = path.start;
+        proj.scale = start.scale * (path.peak_scale / start.scale).powf(zoom_t);
+        transform.translation = start
+            .translation
+            .truncate()
+            .lerp(path.end, pan_t)
+            .extend(start.translation.z);
+
+        if t >= 1. {
+            proj.scale = start.scale;
+            commands.entity(entity).remove::<Travel>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, PanCamCommandsExt};
+
+    #[test]
+    fn fly_out_scale_fits_both_ends_and_margin() {
+        let scale = fly_out_scale(Vec2::ZERO, Vec2::new(400., 100.), 10., Vec2::splat(50.));
+        assert_eq!(scale, 210. / 50.);
+    }
+
+    #[test]
+    fn fly_out_travel_shows_both_ends_halfway() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+        let cam = spawn_cam(&mut app, PanCam::default());
+        let target = vec2(1000., -200.);
+        run_commands(&mut app, move |commands| {
+            commands.entity(cam).center_on(
+                target,
+                TravelMode::FlyOut {
+                    margin: 20.,
+                    duration: 1.,
+                },
+            );
+        });
+
+        let mut widest = (0., Vec2::ZERO);
+        for _ in 0..40 {
+            update(&mut app);
+            if scale(&app, cam) > widest.0 {
+                widest = (scale(&app, cam), translation(&app, cam));
+            }
+        }
+
+        let (widest_scale, widest_center) = widest;
+        let view = Rect::from_center_half_size(widest_center, Vec2::splat(50. * widest_scale));
+        assert!(view.contains(Vec2::ZERO));
+        assert!(view.contains(target));
+
+        assert!(app.world().get::<Travel>(cam).is_none());
+        assert_eq!(scale(&app, cam), 1.);
+        assert_eq!(translation(&app, cam), target);
+    }
+}
@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+
+/// Sent when the plugin moved a camera, by user input, animations, following,
+/// bounds or any other of its features
+///
+/// Unlike [`CameraPanDelta`](crate::CameraPanDelta), changes made by other
+/// systems aren't included, so systems that also move the camera can tell
+/// them apart from their own.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct PanCamMoved {
+    /// The camera entity
+    pub entity: Entity,
+    /// The camera position before the plugin moved it
+    pub old: Vec2,
+    /// The camera position after the plugin moved it
+    pub new: Vec2,
+}
+
+/// Sent when the plugin changed the projection scale of a camera
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct PanCamZoomed {
+    /// The camera entity
+    pub entity: Entity,
+    /// The projection scale before the plugin changed it
+    pub old: f32,
+    /// The projection scale after the plugin changed it
+    pub new: f32,
+}
+
+/// The view of a camera before the plugin's systems ran this schedule
+#[derive(Component, Default)]
+pub struct ViewChangeState {
+    before: Option<(Vec2, f32)>,
+}
+
+/// Records the view of cameras before the plugin's systems change it
+pub fn begin_view_changes(
+    mut query: Query<(&Transform, &OrthographicProjection, &mut ViewChangeState)>,
+) {
+    for (transform, proj, mut state) in &mut query {
+        state.before = Some((transform.translation.truncate(), proj.scale));
+    }
+}
+
+/// Sends [`PanCamMoved`] and [`PanCamZoomed`] for cameras whose view changed
+/// since [`begin_view_changes`]
+pub fn send_view_changes(
+    mut query: Query<(
+        Entity,
+        &Transform,
+        &OrthographicProjection,
+        &mut ViewChangeState,
+    )>,
+    mut moved: EventWriter<PanCamMoved>,
+    mut zoomed: EventWriter<PanCamZoomed>,
+) {
+    for (entity, transform, proj, mut state) in &mut query {
+        let Some((old_pos, old_scale)) = state.before.take() else {
+            continue;
+        };
+        let pos = transform.translation.truncate();
+        if pos != old_pos {
+            moved.send(PanCamMoved {
+                entity,
+                old: old_pos,
+                new: pos,
+            });
+        }
+        if proj.scale != old_scale {
+            zoomed.send(PanCamZoomed {
+                entity,
+                old: old_scale,
+                new: proj.scale,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+
+    use super::*;
+    use crate::{test_utils::*, PanCam};
+
+    #[test]
+    fn view_change_events_only_for_plugin_changes() {
+        let mut app = test_app();
+        let cam = start_grab(&mut app, PanCam::default(), MouseButton::Left);
+        let drain_events = |app: &mut App| {
+            let moved: Vec<_> = app
+                .world_mut()
+                .resource_mut::<Events<PanCamMoved>>()
+                .drain()
+                .collect();
+            let zoomed: Vec<_> = app
+                .world_mut()
+                .resource_mut::<Events<PanCamZoomed>>()
+                .drain()
+                .collect();
+            (moved, zoomed)
+        };
+        drain_events(&mut app);
+
+        drag_right(&mut app, cam);
+        let (moved, zoomed) = drain_events(&mut app);
+        assert_eq!(
+            moved,
+            [PanCamMoved {
+                entity: cam,
+                old: Vec2::ZERO,
+                new: vec2(-10., 0.)
+            }]
+        );
+        assert!(zoomed.is_empty());
+
+        mouse(&mut app).release(MouseButton::Left);
+        scroll(&mut app, 1.);
+        update(&mut app);
+        let (_, zoomed) = drain_events(&mut app);
+        assert_eq!(zoomed.len(), 1);
+        assert_eq!(zoomed[0].old, 1.);
+        assert!(zoomed[0].new < 1.);
+
+        // changes made outside the plugin aren't reported
+        set_view(&mut app, cam, vec2(5., 5.), 2.);
+        update(&mut app);
+        let (moved, zoomed) = drain_events(&mut app);
+        assert!(moved.is_empty() && zoomed.is_empty());
+    }
+}
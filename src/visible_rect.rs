@@ -0,0 +1,92 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{camera_window, world_rect, PanCam};
+
+/// The world area a `PanCam` camera currently shows, e.g. for tile streaming,
+/// chunk loading or culling
+///
+/// Updated every frame once the camera was panned and zoomed, so it already
+/// reflects zooming done that frame, before the projection is updated for
+/// rendering. Rotated views are covered by their bounding box.
+/// [`VisibleWorldRectChanged`] is sent when it changes.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Deref, Reflect)]
+#[reflect(Component)]
+pub struct VisibleWorldRect(pub Rect);
+
+/// Sent when a camera's [`VisibleWorldRect`] changes
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct VisibleWorldRectChanged {
+    /// The camera entity
+    pub entity: Entity,
+    /// The world area the camera now shows
+    pub rect: Rect,
+}
+
+/// Updates `VisibleWorldRect` for all `PanCam` cameras
+pub fn update_visible_world_rect(
+    mut query: Query<(
+        Entity,
+        &PanCam,
+        &Camera,
+        &OrthographicProjection,
+        &Transform,
+        &mut VisibleWorldRect,
+    )>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut changes: EventWriter<VisibleWorldRectChanged>,
+) {
+    let primary_window = primary_window.get_single().ok();
+    for (entity, pan_cam, camera, proj, transform, mut visible_rect) in &mut query {
+        let area = match camera_window(camera, primary_window)
+            .and_then(|window_entity| windows.get(window_entity).ok())
+        {
+            Some(window) => pan_cam.view_area(camera, proj, window),
+            None => proj.area,
+        };
+        let rect = world_rect(area, &GlobalTransform::from(*transform));
+
+        if visible_rect.set_if_neq(VisibleWorldRect(rect)) {
+            changes.send(VisibleWorldRectChanged { entity, rect });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn visible_world_rect_component_reflects_zoom_the_same_frame() {
+        let mut app = test_app();
+        let cam = spawn_cam(&mut app, PanCam::default());
+        set_cursor(&mut app, vec2(50., 50.));
+        update(&mut app);
+        let rect = |app: &App| **app.world().get::<VisibleWorldRect>(cam).unwrap();
+        assert_eq!(rect(&app), Rect::new(-50., -50., 50., 50.));
+        let mut cursor = app
+            .world()
+            .resource::<Events<VisibleWorldRectChanged>>()
+            .get_cursor_current();
+
+        // The projection's area isn't updated without a renderer
+        scroll(&mut app, 1.);
+        update(&mut app);
+        assert!((rect(&app).size() - Vec2::splat(90.)).length() < 1e-4);
+        let changes: Vec<_> = cursor
+            .read(app.world().resource::<Events<VisibleWorldRectChanged>>())
+            .map(|change| change.entity)
+            .collect();
+        assert_eq!(changes, vec![cam]);
+
+        // Rotated views are covered by their bounding box
+        app.world_mut().get_mut::<Transform>(cam).unwrap().rotation =
+            Quat::from_rotation_z(std::f32::consts::FRAC_PI_4);
+        update(&mut app);
+        let size = Vec2::splat(90. * std::f32::consts::SQRT_2);
+        assert!((rect(&app).size() - size).length() < 1e-3);
+    }
+}
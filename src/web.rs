@@ -0,0 +1,97 @@
+//! Browser integration for `wasm32` targets
+//!
+//! Browsers report pinching on a trackpad as scrolling with control held,
+//! without a key press that `ButtonInput<KeyCode>` would see, and zoom the
+//! whole page on it. The canvas's wheel events are watched so pinching does
+//! what control+scroll does, zooming the camera by default, and the page is
+//! kept from zooming on pinches and touch gestures over the canvas.
+//!
+//! Scrolling the page with the wheel is already prevented by Bevy while
+//! `Window::prevent_default_event_handling` is set, which is the default.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use bevy::{prelude::*, utils::HashSet};
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{AddEventListenerOptions, Event, HtmlElement, WheelEvent};
+
+/// The modifiers of the browser's latest wheel event over a canvas
+#[derive(Resource, Debug, Clone, Default)]
+pub struct WebWheelModifiers {
+    ctrl: Arc<AtomicBool>,
+}
+
+impl WebWheelModifiers {
+    /// Whether control was held, which is how browsers report pinching
+    pub fn ctrl(&self) -> bool {
+        self.ctrl.load(Ordering::Relaxed)
+    }
+}
+
+/// Watches the canvas of each window, keeping the page from zooming on it
+pub fn prevent_browser_zoom(
+    windows: Query<(Entity, &Window)>,
+    modifiers: Res<WebWheelModifiers>,
+    mut watched: Local<HashSet<Entity>>,
+) {
+    watched.retain(|entity| windows.contains(*entity));
+    for (entity, window) in &windows {
+        if watched.contains(&entity) {
+            continue;
+        }
+        // The canvas only exists once winit created the window
+        let Some(canvas) = find_canvas(window) else {
+            continue;
+        };
+        watch_canvas(&canvas, modifiers.ctrl.clone());
+        watched.insert(entity);
+    }
+}
+
+/// The window's canvas, or the page's first canvas when it has no selector
+fn find_canvas(window: &Window) -> Option<HtmlElement> {
+    let selector = window.canvas.as_deref().unwrap_or("canvas");
+    web_sys::window()?
+        .document()?
+        .query_selector(selector)
+        .ok()??
+        .dyn_into()
+        .ok()
+}
+
+fn watch_canvas(canvas: &HtmlElement, ctrl: Arc<AtomicBool>) {
+    // Touch pinches and swipes over the canvas move the camera, not the page
+    let _ = canvas.style().set_property("touch-action", "none");
+
+    // Listeners that prevent the default action can't be passive
+    let options = AddEventListenerOptions::new();
+    options.set_passive(false);
+
+    let on_wheel = Closure::<dyn FnMut(WheelEvent)>::new(move |event: WheelEvent| {
+        ctrl.store(event.ctrl_key(), Ordering::Relaxed);
+        if event.ctrl_key() {
+            event.prevent_default();
+        }
+    });
+    let _ = canvas.add_event_listener_with_callback_and_add_event_listener_options(
+        "wheel",
+        on_wheel.as_ref().unchecked_ref(),
+        &options,
+    );
+    // The listeners live as long as the page
+    on_wheel.forget();
+
+    // Safari reports trackpad pinches as gesture events instead
+    for event_type in ["gesturestart", "gesturechange"] {
+        let on_gesture = Closure::<dyn FnMut(Event)>::new(|event: Event| event.prevent_default());
+        let _ = canvas.add_event_listener_with_callback_and_add_event_listener_options(
+            event_type,
+            on_gesture.as_ref().unchecked_ref(),
+            &options,
+        );
+        on_gesture.forget();
+    }
+}
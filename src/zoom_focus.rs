@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+
+use crate::{scale_rect, world_to_normalized_view_pos, PanCam};
+
+/// What zooming keeps in place on screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZoomFocus {
+    /// The world position under the cursor
+    #[default]
+    Cursor,
+    /// The middle of the screen
+    ScreenCenter,
+    /// An entity, at wherever it currently is on screen, e.g. the selected
+    /// unit
+    ///
+    /// Zooming is towards the middle of the screen while the entity has no
+    /// `GlobalTransform`.
+    Entity(Entity),
+}
+
+/// Where the camera's focus entity is in its view
+#[derive(Component, Default)]
+pub struct ZoomFocusState {
+    /// The entity's normalized view position, see `normalize_view_pos`
+    pub(crate) entity_anchor: Option<Vec2>,
+}
+
+/// Finds where the focus entities of cameras with `ZoomFocus::Entity` are in
+/// their view
+pub fn track_zoom_focus(
+    mut cameras: Query<(
+        &PanCam,
+        &OrthographicProjection,
+        &Transform,
+        &mut ZoomFocusState,
+    )>,
+    entities: Query<&GlobalTransform>,
+) {
+    for (pan_cam, proj, transform, mut state) in &mut cameras {
+        let ZoomFocus::Entity(entity) = pan_cam.zoom_focus else {
+            if state.entity_anchor.is_some() {
+                state.entity_anchor = None;
+            }
+            continue;
+        };
+        let anchor = entities.get(entity).ok().map(|global_transform| {
+            // The offset from the camera along the axes of its view
+            let cam_pos = transform.translation.truncate();
+            let offset = transform.rotation.inverse()
+                * (global_transform.translation().truncate() - cam_pos).extend(0.);
+            world_to_normalized_view_pos(
+                cam_pos + offset.truncate(),
+                cam_pos,
+                scale_rect(proj.area, transform.scale.truncate()),
+            )
+        });
+        if state.entity_anchor != anchor {
+            state.entity_anchor = anchor;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::vec2;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn entity_zoom_focus_keeps_entity_in_place() {
+        let mut app = test_app();
+        let unit = app
+            .world_mut()
+            .spawn(GlobalTransform::from_xyz(20., 10., 0.))
+            .id();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_focus: ZoomFocus::Entity(unit),
+                ..default()
+            },
+        );
+        // the cursor is elsewhere
+        set_cursor(&mut app, vec2(10., 90.));
+        scroll(&mut app, 1.);
+        update(&mut app);
+
+        assert!(scale(&app, cam) < 1.);
+        let unit_view_offset = (vec2(20., 10.) - translation(&app, cam)) / scale(&app, cam);
+        assert!((unit_view_offset - vec2(20., 10.)).length() < 1e-4);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_zoom_to_cursor_zooms_to_the_screen_center() {
+        let mut app = test_app();
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_to_cursor: false,
+                ..default()
+            },
+        );
+        set_cursor(&mut app, vec2(10., 90.));
+        scroll(&mut app, 1.);
+        update(&mut app);
+
+        assert!(scale(&app, cam) < 1.);
+        assert_eq!(translation(&app, cam), Vec2::ZERO);
+        assert_eq!(
+            PanCam::builder().zoom_to_cursor(false).build().zoom_focus,
+            ZoomFocus::ScreenCenter
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+
+/// Predefined scales that scrolling snaps to, one step per mouse wheel notch,
+/// like in map applications
+///
+/// Combine with `zoom_smoothing` to animate each step. Scrolling during an
+/// animation steps on from the scale being animated to.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZoomSteps {
+    /// Steps are powers of this factor, e.g. `2.` for scales of ..., 0.5, 1,
+    /// 2, 4, ...
+    Geometric(f32),
+    /// Steps are these scales, in any order
+    Scales(Vec<f32>),
+}
+
+impl ZoomSteps {
+    /// Scales closer than this fraction count as the same step
+    const EPSILON: f32 = 1e-4;
+
+    /// The scale `steps` steps away from `scale`, zooming in for positive
+    /// `steps`
+    ///
+    /// A scale between two steps first snaps to the next step in the direction
+    /// of the zoom.
+    pub(crate) fn step(&self, scale: f32, steps: i32) -> f32 {
+        if steps == 0 {
+            return scale;
+        }
+        match self {
+            ZoomSteps::Geometric(factor) => {
+                if *factor <= 1. {
+                    return scale;
+                }
+                let level = scale.ln() / factor.ln();
+                let level = if steps > 0 {
+                    (level - Self::EPSILON).ceil() - steps as f32
+                } else {
+                    (level + Self::EPSILON).floor() - steps as f32
+                };
+                factor.powf(level)
+            }
+            ZoomSteps::Scales(scales) => {
+                let steps_away = steps.unsigned_abs() as usize;
+                let mut candidates: Vec<f32> = if steps > 0 {
+                    scales
+                        .iter()
+                        .copied()
+                        .filter(|s| *s < scale * (1. - Self::EPSILON))
+                        .collect()
+                } else {
+                    scales
+                        .iter()
+                        .copied()
+                        .filter(|s| *s > scale * (1. + Self::EPSILON))
+                        .collect()
+                };
+                // Order the candidates by distance from `scale`
+                candidates.sort_by(|a, b| (a - scale).abs().total_cmp(&(b - scale).abs()));
+                candidates
+                    .get(steps_away - 1)
+                    .or(candidates.last())
+                    .copied()
+                    .unwrap_or(scale)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{math::vec2, render::camera::CameraProjection, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{test_utils::*, PanCam};
+
+    #[test]
+    fn geometric_steps_snap_to_powers_of_factor() {
+        let steps = ZoomSteps::Geometric(2.);
+        assert!((steps.step(1., 1) - 0.5).abs() < 1e-5);
+        assert!((steps.step(1., -2) - 4.).abs() < 1e-5);
+        // between steps, the first step lands on the next power
+        assert!((steps.step(3., 1) - 2.).abs() < 1e-5);
+        assert!((steps.step(3., -1) - 4.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn scale_steps_stop_at_last_scale() {
+        let steps = ZoomSteps::Scales(vec![4., 1., 0.5, 2.]);
+        assert_eq!(steps.step(1., 1), 0.5);
+        assert_eq!(steps.step(1., 2), 0.5);
+        assert_eq!(steps.step(1.5, -1), 2.);
+        assert_eq!(steps.step(1., -2), 4.);
+        assert_eq!(steps.step(4., -1), 4.);
+    }
+
+    #[test]
+    fn zoom_steps_animate_to_each_step_around_cursor() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            10,
+        )));
+        let cam = spawn_cam(
+            &mut app,
+            PanCam {
+                zoom_steps: Some(ZoomSteps::Geometric(2.)),
+                zoom_smoothing: Some(0.2),
+                ..default()
+            },
+        );
+        // updates the projection's area like the camera system would
+        let update_and_refresh_area = |app: &mut App| {
+            update(app);
+            let mut proj = app
+                .world_mut()
+                .get_mut::<OrthographicProjection>(cam)
+                .unwrap();
+            proj.update(100., 100.);
+        };
+        let world_under_cursor = |app: &App| translation(app, cam).x + 25. * scale(app, cam);
+        set_cursor(&mut app, vec2(75., 50.));
+        update_and_refresh_area(&mut app);
+
+        // a second notch while the first step animates skips to the next step
+        scroll(&mut app, 1.);
+        update_and_refresh_area(&mut app);
+        scroll(&mut app, 1.);
+        update_and_refresh_area(&mut app);
+        assert!(scale(&app, cam) > 0.25);
+
+        for _ in 0..60 {
+            update_and_refresh_area(&mut app);
+        }
+        assert!((scale(&app, cam) - 0.25).abs() < 1e-6);
+        assert!((world_under_cursor(&app) - 25.).abs() < 1e-4);
+    }
+}
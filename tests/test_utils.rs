@@ -0,0 +1,139 @@
+use bevy::{math::vec2, prelude::*};
+use bevy_pancam::{test_utils::*, PanCam};
+
+#[test]
+fn zooming_keeps_world_position_under_cursor() {
+    let mut app = test_app();
+    let cam = spawn_cam(&mut app, PanCam::default());
+    set_cursor(&mut app, vec2(75., 25.));
+    update(&mut app);
+
+    scroll(&mut app, 1.);
+    update(&mut app);
+
+    // The cursor is 25 pixels right of and above the middle of the view, which
+    // is (25, 25) in world space before and after zooming
+    let scale = scale(&app, cam);
+    assert!(scale < 1.);
+    let under_cursor = translation(&app, cam) + vec2(25., 25.) * scale;
+    assert!((under_cursor - vec2(25., 25.)).length() < 1e-3);
+}
+
+#[test]
+fn dragging_stops_at_bounds() {
+    let mut app = test_app();
+    let cam = spawn_cam(
+        &mut app,
+        PanCam {
+            min_x: -60.,
+            ..default()
+        },
+    );
+    set_cursor(&mut app, vec2(50., 50.));
+    update(&mut app);
+    mouse(&mut app).press(MouseButton::Left);
+    update(&mut app);
+
+    set_cursor(&mut app, vec2(80., 50.));
+    update(&mut app);
+    assert_eq!(translation(&app, cam), vec2(-10., 0.));
+}
+
+#[test]
+fn scroll_zoom_stops_at_scale_limits() {
+    let mut app = test_app();
+    let cam = spawn_cam(
+        &mut app,
+        PanCam {
+            min_scale: 0.5,
+            max_scale: 3.,
+            ..default()
+        },
+    );
+    set_cursor(&mut app, vec2(50., 50.));
+    update(&mut app);
+
+    for _ in 0..10 {
+        scroll(&mut app, 5.);
+        update(&mut app);
+    }
+    assert_eq!(scale(&app, cam), 0.5);
+
+    for _ in 0..10 {
+        scroll(&mut app, -5.);
+        update(&mut app);
+    }
+    assert_eq!(scale(&app, cam), 3.);
+}
+
+#[test]
+fn zooming_out_stops_once_the_view_fills_the_bounds() {
+    let mut app = test_app();
+    let cam = spawn_cam(
+        &mut app,
+        PanCam {
+            min_x: -100.,
+            max_x: 100.,
+            min_y: -100.,
+            max_y: 100.,
+            ..default()
+        },
+    );
+    // Off center, so zooming out would move the view past the bounds
+    set_cursor(&mut app, vec2(90., 10.));
+    update(&mut app);
+
+    scroll(&mut app, -20.);
+    update(&mut app);
+    assert_eq!(scale(&app, cam), 2.);
+    assert_eq!(translation(&app, cam), Vec2::ZERO);
+}
+
+#[cfg(feature = "bevy_egui")]
+#[test]
+fn dragging_over_an_egui_window_doesnt_pan() {
+    use bevy_egui::egui;
+
+    let mut app = test_app();
+    let cam = spawn_cam(&mut app, PanCam::default());
+    set_cursor(&mut app, vec2(25., 25.));
+    let ctx = spawn_egui_context(&mut app, vec2(25., 25.), |ctx| {
+        egui::Window::new("window")
+            .fixed_pos(egui::Pos2::ZERO)
+            .fixed_size(egui::vec2(50., 50.))
+            .show(ctx, |_| {});
+    });
+    assert!(ctx.wants_pointer_input());
+    update(&mut app);
+
+    mouse(&mut app).press(MouseButton::Left);
+    update(&mut app);
+    set_cursor(&mut app, vec2(45., 25.));
+    update(&mut app);
+    assert_eq!(translation(&app, cam), Vec2::ZERO);
+}
+
+#[cfg(feature = "bevy_egui")]
+#[test]
+fn scrolling_over_an_egui_scroll_area_doesnt_zoom() {
+    use bevy_egui::egui;
+
+    let mut app = test_app();
+    let cam = spawn_cam(&mut app, PanCam::default());
+    set_cursor(&mut app, vec2(25., 50.));
+    spawn_egui_context(&mut app, vec2(25., 50.), |ctx| {
+        egui::SidePanel::left("panel")
+            .exact_width(50.)
+            .resizable(false)
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    ui.allocate_space(egui::vec2(40., 300.));
+                });
+            });
+    });
+    update(&mut app);
+
+    scroll(&mut app, 1.);
+    update(&mut app);
+    assert_eq!(scale(&app, cam), 1.);
+}